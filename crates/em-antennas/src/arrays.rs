@@ -1,10 +1,15 @@
-//! Antenna array analysis — uniform linear arrays (ULA).
+//! Antenna array analysis — uniform and non-uniform linear arrays.
 //!
-//! Array factor, beam steering, broadside/endfire configurations.
+//! Array factor, beam steering, broadside/endfire configurations, and
+//! arbitrary per-element excitations.
 
+use crate::pattern::{directivity_from_pattern, field_pattern_db};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+/// θ intervals used when integrating array patterns for directivity.
+const DIRECTIVITY_THETA_STEPS: usize = 4000;
+
 /// Uniform Linear Array (ULA) along the z-axis.
 ///
 /// Array factor: AF(θ) = sin(Nψ/2) / (N sin(ψ/2))
@@ -87,6 +92,12 @@ impl UniformLinearArray {
         2.0 * self.num_elements as f64 * self.spacing
     }
 
+    /// Directivity of the array of isotropic elements, by integrating |AF|²
+    /// over the sphere.
+    pub fn directivity(&self) -> f64 {
+        directivity_from_pattern(|t, _| self.array_factor(t).powi(2), DIRECTIVITY_THETA_STEPS, 1)
+    }
+
     /// Sample the array factor pattern.
     pub fn sample_pattern(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
         let dtheta = PI / (num_points - 1) as f64;
@@ -95,6 +106,12 @@ impl UniformLinearArray {
         (thetas, af)
     }
 
+    /// Normalized array-factor intensity in dB at each θ (see [`field_pattern_db`]).
+    pub fn pattern_db(&self, theta_values: &[f64]) -> Vec<f64> {
+        let af: Vec<f64> = theta_values.iter().map(|&t| self.array_factor(t)).collect();
+        field_pattern_db(&af)
+    }
+
     /// Sample the total pattern (element × array factor).
     ///
     /// Uses sin(θ) element pattern (short dipole).
@@ -108,6 +125,233 @@ impl UniformLinearArray {
     }
 }
 
+/// Linear array along the z-axis with arbitrary per-element excitation.
+///
+/// AF(θ) = |Σ aₙ·exp(j(n·kd·cos θ + φₙ))| / Σ|aₙ|, with θ measured from the
+/// array axis as for [`UniformLinearArray`]. Tapers (e.g. Dolph-Chebyshev)
+/// set `amplitudes`; steering sets `phases`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonUniformLinearArray {
+    /// Excitation amplitude aₙ of each element
+    pub amplitudes: Vec<f64>,
+    /// Excitation phase φₙ of each element (radians)
+    pub phases: Vec<f64>,
+    /// Element spacing in wavelengths (d/λ)
+    pub spacing: f64,
+}
+
+impl NonUniformLinearArray {
+    pub fn new(amplitudes: Vec<f64>, phases: Vec<f64>, spacing: f64) -> Self {
+        assert!(amplitudes.len() >= 2, "need at least 2 elements");
+        assert_eq!(amplitudes.len(), phases.len(), "one phase per amplitude");
+        Self {
+            amplitudes,
+            phases,
+            spacing,
+        }
+    }
+
+    /// Equal amplitudes and zero phases (broadside).
+    pub fn uniform(num_elements: usize, spacing: f64) -> Self {
+        Self::new(vec![1.0; num_elements], vec![0.0; num_elements], spacing)
+    }
+
+    /// Given amplitudes with a linear phase taper φₙ = -n·kd·cos θ₀ that
+    /// steers the main beam to θ₀.
+    pub fn steered(amplitudes: Vec<f64>, spacing: f64, theta_0: f64) -> Self {
+        let beta = -2.0 * PI * spacing * theta_0.cos();
+        let phases = (0..amplitudes.len()).map(|n| n as f64 * beta).collect();
+        Self::new(amplitudes, phases, spacing)
+    }
+
+    pub fn num_elements(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// Normalized array factor in [0, 1]; 1 where all elements add in phase.
+    pub fn array_factor(&self, theta: f64) -> f64 {
+        let positions: Vec<f64> = (0..self.num_elements())
+            .map(|n| n as f64 * self.spacing)
+            .collect();
+        array_factor_with_errors(&self.amplitudes, &positions, &self.phases, theta)
+    }
+
+    /// Sample the array factor pattern over θ ∈ [0, π].
+    pub fn sample_pattern(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
+        let dtheta = PI / (num_points - 1) as f64;
+        let thetas: Vec<f64> = (0..num_points).map(|i| i as f64 * dtheta).collect();
+        let af: Vec<f64> = thetas.iter().map(|&t| self.array_factor(t)).collect();
+        (thetas, af)
+    }
+}
+
+/// Array factor of a linear array with arbitrary element errors.
+///
+/// AF(θ) = |Σ aₙ·exp(j(2π·zₙ·cos θ + φₙ))| / Σ|aₙ|
+///
+/// Used for tolerance studies: pass the actual (perturbed) element positions
+/// and the total excitation phase of each element including any error.
+///
+/// # Arguments
+/// * `amplitudes` - Per-element excitation amplitude
+/// * `positions` - Actual element positions along z in wavelengths (z/λ)
+/// * `phases` - Per-element excitation phase (radians), nominal plus any error
+/// * `theta` - Observation angle from the array axis (radians)
+///
+/// # Returns
+/// Normalized array factor in [0, 1]. Returns 0 if all amplitudes are zero.
+pub fn array_factor_with_errors(
+    amplitudes: &[f64],
+    positions: &[f64],
+    phases: &[f64],
+    theta: f64,
+) -> f64 {
+    assert!(
+        amplitudes.len() == positions.len() && positions.len() == phases.len(),
+        "amplitudes, positions and phases must have equal length"
+    );
+    let cos_t = theta.cos();
+    let (mut re, mut im, mut total) = (0.0, 0.0, 0.0);
+    for ((&a, &z), &phi) in amplitudes.iter().zip(positions).zip(phases) {
+        let phase = 2.0 * PI * z * cos_t + phi;
+        re += a * phase.cos();
+        im += a * phase.sin();
+        total += a.abs();
+    }
+    if total == 0.0 {
+        return 0.0;
+    }
+    (re * re + im * im).sqrt() / total
+}
+
+/// Peak sidelobe level of a sampled pattern, in dB relative to the main-beam peak.
+///
+/// The main lobe is the monotonic region around the global maximum; the
+/// sidelobe level is the largest sample outside it.
+///
+/// # Returns
+/// Sidelobe level in dB (negative). Returns `f64::NEG_INFINITY` if the
+/// pattern has no sidelobes.
+pub fn peak_sidelobe_level_db(pattern: &[f64]) -> f64 {
+    let Some((peak_idx, &peak)) = pattern
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+    else {
+        return f64::NEG_INFINITY;
+    };
+
+    let mut left = peak_idx;
+    while left > 0 && pattern[left - 1] <= pattern[left] {
+        left -= 1;
+    }
+    let mut right = peak_idx;
+    while right + 1 < pattern.len() && pattern[right + 1] <= pattern[right] {
+        right += 1;
+    }
+
+    let sidelobe = pattern[..left]
+        .iter()
+        .chain(&pattern[right + 1..])
+        .cloned()
+        .fold(0.0, f64::max);
+    if sidelobe <= 0.0 || peak <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    20.0 * (sidelobe / peak).log10()
+}
+
+/// Summary of a Monte Carlo sidelobe tolerance study.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SidelobeStats {
+    /// Mean peak sidelobe level over all trials (dB)
+    pub mean_sidelobe_db: f64,
+    /// Worst (highest) peak sidelobe level over all trials (dB)
+    pub worst_sidelobe_db: f64,
+    /// Number of trials run
+    pub trials: usize,
+}
+
+/// Monte Carlo study of peak sidelobe level under random element errors.
+///
+/// Each trial perturbs every element position by a Gaussian error with
+/// standard deviation `position_sigma` (wavelengths) and every excitation
+/// phase by one with standard deviation `phase_sigma` (radians).
+/// A fixed-seed generator is used so results are reproducible.
+///
+/// # Arguments
+/// * `nominal_array` - Error-free uniform linear array
+/// * `position_sigma` - Position error standard deviation (wavelengths)
+/// * `phase_sigma` - Phase error standard deviation (radians)
+/// * `trials` - Number of random trials
+///
+/// # Panics
+/// Panics if `trials` is 0.
+pub fn monte_carlo_sidelobe_stats(
+    nominal_array: &UniformLinearArray,
+    position_sigma: f64,
+    phase_sigma: f64,
+    trials: usize,
+) -> SidelobeStats {
+    assert!(trials > 0, "need at least one trial");
+    const NUM_POINTS: usize = 721;
+    let n = nominal_array.num_elements;
+    let amplitudes = vec![1.0; n];
+    let dtheta = PI / (NUM_POINTS - 1) as f64;
+    let mut rng = GaussianRng::new(0x5EED_A77A_0000_0001);
+
+    let mut sum_db = 0.0;
+    let mut worst_db = f64::NEG_INFINITY;
+    for _ in 0..trials {
+        let positions: Vec<f64> = (0..n)
+            .map(|i| i as f64 * nominal_array.spacing + position_sigma * rng.next_gaussian())
+            .collect();
+        let phases: Vec<f64> = (0..n)
+            .map(|i| i as f64 * nominal_array.beta + phase_sigma * rng.next_gaussian())
+            .collect();
+        let pattern: Vec<f64> = (0..NUM_POINTS)
+            .map(|i| array_factor_with_errors(&amplitudes, &positions, &phases, i as f64 * dtheta))
+            .collect();
+        let sll = peak_sidelobe_level_db(&pattern);
+        sum_db += sll;
+        worst_db = worst_db.max(sll);
+    }
+
+    SidelobeStats {
+        mean_sidelobe_db: sum_db / trials as f64,
+        worst_sidelobe_db: worst_db,
+        trials,
+    }
+}
+
+/// Minimal deterministic normal-variate generator (SplitMix64 + Box-Muller).
+struct GaussianRng {
+    state: u64,
+}
+
+impl GaussianRng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Uniform sample in (0, 1].
+    fn next_uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal sample.
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_uniform();
+        let u2 = self.next_uniform();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,7 +398,7 @@ mod tests {
         let arr = UniformLinearArray::broadside(10, 0.5);
         let (_, af) = arr.sample_pattern(361);
         for &v in &af {
-            assert!(v >= 0.0 && v <= 1.001, "AF should be in [0, 1], got {v}");
+            assert!((0.0..=1.001).contains(&v), "AF should be in [0, 1], got {v}");
         }
     }
 
@@ -165,6 +409,22 @@ mod tests {
         assert_relative_eq!(arr.directivity_approx(), 10.0, epsilon = 1e-10);
     }
 
+    #[test]
+    fn numerical_directivity_broadside_half_wave() {
+        // Exact for isotropic elements at d = λ/2: D = N
+        let arr = UniformLinearArray::broadside(10, 0.5);
+        assert_relative_eq!(arr.directivity(), 10.0, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn numerical_directivity_two_element_quarter_wave() {
+        // N = 2, d = λ/4, β = 0: D = 2 / (1 + sin(kd)/(kd)) with kd = π/2
+        let arr = UniformLinearArray::broadside(2, 0.25);
+        let kd = PI / 2.0;
+        let expected = 2.0 / (1.0 + kd.sin() / kd);
+        assert_relative_eq!(arr.directivity(), expected, max_relative = 1e-4);
+    }
+
     #[test]
     fn sample_pattern_dimensions() {
         let arr = UniformLinearArray::broadside(8, 0.5);
@@ -181,6 +441,17 @@ mod tests {
         assert_eq!(p.len(), 181);
     }
 
+    #[test]
+    fn array_pattern_db_peak_and_null() {
+        let arr = UniformLinearArray::broadside(4, 0.5);
+        // First null of a 4-element λ/2 array: cos θ = 1/2
+        let thetas = [PI / 2.0, PI / 3.0, 1.2];
+        let db = arr.pattern_db(&thetas);
+        assert_eq!(db[0], 0.0);
+        assert_eq!(db[1], crate::pattern::PATTERN_DB_FLOOR);
+        assert_relative_eq!(db[2], 20.0 * arr.array_factor(1.2).log10(), max_relative = 1e-9);
+    }
+
     #[test]
     fn total_pattern_zero_on_axis() {
         // Element pattern sin(0) = 0, so total pattern at θ=0 is 0
@@ -194,4 +465,109 @@ mod tests {
     fn single_element_panics() {
         UniformLinearArray::new(1, 0.5, 0.0);
     }
+
+    // ================================================================
+    // Element errors and tolerance studies
+    // ================================================================
+
+    fn ideal_excitation(arr: &UniformLinearArray) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let n = arr.num_elements;
+        let amps = vec![1.0; n];
+        let pos = (0..n).map(|i| i as f64 * arr.spacing).collect();
+        let phases = (0..n).map(|i| i as f64 * arr.beta).collect();
+        (amps, pos, phases)
+    }
+
+    #[test]
+    fn zero_errors_reproduce_ideal_pattern() {
+        let arr = UniformLinearArray::scanned(8, 0.5, PI / 3.0);
+        let (amps, pos, phases) = ideal_excitation(&arr);
+        for i in 0..=180 {
+            let theta = (i as f64).to_radians();
+            assert_relative_eq!(
+                array_factor_with_errors(&amps, &pos, &phases, theta),
+                arr.array_factor(theta),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn ideal_uniform_array_sidelobe_near_minus_13_db() {
+        let arr = UniformLinearArray::broadside(16, 0.5);
+        let (_, af) = arr.sample_pattern(1441);
+        assert_relative_eq!(peak_sidelobe_level_db(&af), -13.2, epsilon = 0.3);
+    }
+
+    #[test]
+    fn monte_carlo_with_zero_sigma_matches_ideal() {
+        let arr = UniformLinearArray::broadside(10, 0.5);
+        let stats = monte_carlo_sidelobe_stats(&arr, 0.0, 0.0, 5);
+        let (_, af) = arr.sample_pattern(721);
+        let ideal = peak_sidelobe_level_db(&af);
+        assert_eq!(stats.trials, 5);
+        assert_relative_eq!(stats.mean_sidelobe_db, ideal, epsilon = 1e-9);
+        assert_relative_eq!(stats.worst_sidelobe_db, ideal, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn phase_errors_raise_average_sidelobe_level() {
+        let arr = UniformLinearArray::broadside(16, 0.5);
+        let ideal = monte_carlo_sidelobe_stats(&arr, 0.0, 0.0, 1);
+        let noisy = monte_carlo_sidelobe_stats(&arr, 0.0, 0.5, 50);
+        assert!(
+            noisy.mean_sidelobe_db > ideal.mean_sidelobe_db,
+            "mean SLL {} should exceed ideal {}",
+            noisy.mean_sidelobe_db,
+            ideal.mean_sidelobe_db
+        );
+        assert!(noisy.worst_sidelobe_db >= noisy.mean_sidelobe_db);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one trial")]
+    fn monte_carlo_rejects_zero_trials() {
+        monte_carlo_sidelobe_stats(&UniformLinearArray::broadside(8, 0.5), 0.0, 0.1, 0);
+    }
+
+    // ================================================================
+    // Non-uniform excitation
+    // ================================================================
+
+    #[test]
+    fn uniform_excitation_reproduces_broadside_factor() {
+        let ula = UniformLinearArray::broadside(8, 0.5);
+        let arr = NonUniformLinearArray::uniform(8, 0.5);
+        for i in 0..=180 {
+            let theta = (i as f64).to_radians();
+            assert_relative_eq!(arr.array_factor(theta), ula.array_factor(theta), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear_phase_taper_steers_beam() {
+        let theta_0 = 50.0_f64.to_radians();
+        let arr = NonUniformLinearArray::steered(vec![0.5, 1.0, 1.0, 1.0, 0.5], 0.5, theta_0);
+        let (thetas, af) = arr.sample_pattern(1801);
+        let peak = (0..af.len()).max_by(|&a, &b| af[a].total_cmp(&af[b])).unwrap();
+        assert_relative_eq!(thetas[peak], theta_0, epsilon = 1e-3);
+        assert_relative_eq!(arr.array_factor(theta_0), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn steered_uniform_matches_scanned_ula() {
+        let theta_0 = PI / 3.0;
+        let ula = UniformLinearArray::scanned(6, 0.4, theta_0);
+        let arr = NonUniformLinearArray::steered(vec![1.0; 6], 0.4, theta_0);
+        for i in 0..=90 {
+            let theta = (2.0 * i as f64).to_radians();
+            assert_relative_eq!(arr.array_factor(theta), ula.array_factor(theta), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_phase_count_panics() {
+        NonUniformLinearArray::new(vec![1.0; 4], vec![0.0; 3], 0.5);
+    }
 }
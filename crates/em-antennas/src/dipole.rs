@@ -1,8 +1,12 @@
 //! Dipole antenna radiation patterns and parameters.
 //!
-//! Hertzian (infinitesimal) dipole and half-wave dipole.
+//! Hertzian (infinitesimal) dipole, half-wave dipole, and small-loop
+//! (magnetic dipole) antenna, plus the induced-EMF input impedance of a
+//! finite-length dipole.
 
-use em_core::constants::C_0;
+use crate::pattern::field_pattern_db;
+use em_core::Complex64;
+use em_core::constants::{C_0, ETA_0, MU_0, skin_depth};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -17,6 +21,9 @@ pub struct HertzianDipole {
     pub current: f64,
     /// Operating frequency (Hz)
     pub frequency: f64,
+    /// Ohmic loss resistance (Ω); zero for a perfect conductor
+    #[serde(default)]
+    pub r_loss: f64,
 }
 
 impl HertzianDipole {
@@ -25,9 +32,16 @@ impl HertzianDipole {
             length,
             current,
             frequency,
+            r_loss: 0.0,
         }
     }
 
+    /// Set the ohmic loss from the wire radius and conductivity at the operating frequency.
+    pub fn with_wire(mut self, wire_radius: f64, conductivity: f64) -> Self {
+        self.r_loss = self.loss_resistance(wire_radius, conductivity, self.frequency);
+        self
+    }
+
     /// Wavelength λ = c/f
     pub fn wavelength(&self) -> f64 {
         C_0 / self.frequency
@@ -50,6 +64,9 @@ impl HertzianDipole {
     }
 
     /// Directivity: D = 1.5 (3/2) for Hertzian dipole.
+    ///
+    /// Exact closed form; [`directivity_from_pattern`](crate::pattern::directivity_from_pattern)
+    /// on [`Self::pattern`] reproduces it only to quadrature accuracy.
     pub fn directivity(&self) -> f64 {
         1.5
     }
@@ -70,6 +87,25 @@ impl HertzianDipole {
         0.5 * self.current * self.current * self.radiation_resistance()
     }
 
+    /// Ohmic loss resistance of the wire: R_loss = (dl/(2πa))·R_s, R_s = 1/(σδ).
+    ///
+    /// Assumes uniform current and a ≫ δ, so loss grows as √f and falls as 1/a.
+    pub fn loss_resistance(&self, wire_radius: f64, conductivity: f64, frequency: f64) -> f64 {
+        let r_s = 1.0 / (conductivity * skin_depth(frequency, MU_0, conductivity));
+        self.length / (2.0 * PI * wire_radius) * r_s
+    }
+
+    /// Radiation efficiency: e = R_rad/(R_rad + R_loss).
+    pub fn radiation_efficiency(&self) -> f64 {
+        let r_rad = self.radiation_resistance();
+        r_rad / (r_rad + self.r_loss)
+    }
+
+    /// Gain: G = e·D.
+    pub fn gain(&self) -> f64 {
+        self.radiation_efficiency() * self.directivity()
+    }
+
     /// Sample radiation pattern in E-plane (φ=0, vary θ).
     pub fn sample_pattern(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
         let dtheta = PI / (num_points - 1) as f64;
@@ -77,6 +113,12 @@ impl HertzianDipole {
         let pattern: Vec<f64> = thetas.iter().map(|&t| self.pattern(t)).collect();
         (thetas, pattern)
     }
+
+    /// Normalized radiation intensity in dB at each θ (see [`field_pattern_db`]).
+    pub fn pattern_db(&self, theta_values: &[f64]) -> Vec<f64> {
+        let field: Vec<f64> = theta_values.iter().map(|&t| self.pattern(t)).collect();
+        field_pattern_db(&field)
+    }
 }
 
 /// Half-wave dipole antenna along the z-axis.
@@ -126,6 +168,10 @@ impl HalfWaveDipole {
     }
 
     /// Directivity ≈ 1.643 (2.15 dBi)
+    ///
+    /// Tabulated value, kept so existing results do not shift; integrating
+    /// [`Self::pattern`] with
+    /// [`directivity_from_pattern`](crate::pattern::directivity_from_pattern) gives 1.641.
     pub fn directivity(&self) -> f64 {
         1.643
     }
@@ -153,6 +199,200 @@ impl HalfWaveDipole {
         let pattern: Vec<f64> = thetas.iter().map(|&t| self.pattern(t)).collect();
         (thetas, pattern)
     }
+
+    /// Normalized radiation intensity in dB at each θ (see [`field_pattern_db`]).
+    pub fn pattern_db(&self, theta_values: &[f64]) -> Vec<f64> {
+        let field: Vec<f64> = theta_values.iter().map(|&t| self.pattern(t)).collect();
+        field_pattern_db(&field)
+    }
+}
+
+/// Electrically small loop (magnetic dipole) in the xy-plane, axis along z.
+///
+/// Far-field pattern: E_φ ∝ sin(θ), the dual of the Hertzian dipole.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SmallLoop {
+    /// Loop radius (m) — circumference must be << λ
+    pub radius: f64,
+    /// Current amplitude (A)
+    pub current: f64,
+    /// Operating frequency (Hz)
+    pub frequency: f64,
+}
+
+impl SmallLoop {
+    pub fn new(radius: f64, current: f64, frequency: f64) -> Self {
+        Self {
+            radius,
+            current,
+            frequency,
+        }
+    }
+
+    /// Wavelength λ = c/f
+    pub fn wavelength(&self) -> f64 {
+        C_0 / self.frequency
+    }
+
+    /// Circumference C = 2πa
+    pub fn circumference(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    /// Loop area A = πa²
+    pub fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    /// Normalized radiation pattern F(θ) = sin(θ); nulls along the loop axis.
+    pub fn pattern(&self, theta: f64) -> f64 {
+        theta.sin().abs()
+    }
+
+    /// Radiation resistance: R_rad = 20π²(C/λ)⁴ = 320π⁴(A/λ²)²
+    pub fn radiation_resistance(&self) -> f64 {
+        let ratio = self.circumference() / self.wavelength();
+        20.0 * PI * PI * ratio.powi(4)
+    }
+
+    /// Directivity: D = 1.5, same as the Hertzian dipole.
+    ///
+    /// Exact closed form, as for [`HertzianDipole::directivity`].
+    pub fn directivity(&self) -> f64 {
+        1.5
+    }
+
+    /// Directivity in dBi.
+    pub fn directivity_dbi(&self) -> f64 {
+        10.0 * self.directivity().log10()
+    }
+
+    /// Maximum effective area: A_e = λ²·D/(4π)
+    pub fn effective_area(&self) -> f64 {
+        let lambda = self.wavelength();
+        lambda * lambda * self.directivity() / (4.0 * PI)
+    }
+
+    /// Radiated power: P_rad = ½ I² R_rad
+    pub fn radiated_power(&self) -> f64 {
+        0.5 * self.current * self.current * self.radiation_resistance()
+    }
+
+    /// Sample radiation pattern in an elevation plane (vary θ).
+    pub fn sample_pattern(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
+        let dtheta = PI / (num_points - 1) as f64;
+        let thetas: Vec<f64> = (0..num_points).map(|i| i as f64 * dtheta).collect();
+        let pattern: Vec<f64> = thetas.iter().map(|&t| self.pattern(t)).collect();
+        (thetas, pattern)
+    }
+
+    /// Normalized radiation intensity in dB at each θ (see [`field_pattern_db`]).
+    pub fn pattern_db(&self, theta_values: &[f64]) -> Vec<f64> {
+        let field: Vec<f64> = theta_values.iter().map(|&t| self.pattern(t)).collect();
+        field_pattern_db(&field)
+    }
+}
+
+/// Euler-Mascheroni constant γ.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Sine and cosine integrals (Si(x), Ci(x)) for x > 0.
+///
+/// Power series below x = 2, otherwise the continued fraction for E₁(jx)
+/// (Numerical Recipes `cisi`), both to near machine precision.
+fn sine_cosine_integrals(x: f64) -> (f64, f64) {
+    const EPS: f64 = 1e-15;
+    if x < 2.0 {
+        let (mut si, mut ci) = (0.0, 0.0);
+        // term = (-1)^k x^n / n!, n = 2k+1 for Si and 2k for Ci
+        let mut term = 1.0;
+        let mut n = 1;
+        loop {
+            term *= x / n as f64;
+            let contribution = term / n as f64;
+            if n % 2 == 1 {
+                si += contribution;
+            } else {
+                ci += contribution;
+                term = -term;
+            }
+            if contribution.abs() < EPS * (si.abs() + ci.abs()).max(EPS) {
+                break;
+            }
+            n += 1;
+        }
+        // Ci series alternates starting with -x²/(2·2!)
+        return (si, EULER_GAMMA + x.ln() - ci);
+    }
+
+    let mut b = Complex64::new(1.0, x);
+    let mut c = Complex64::new(1e300, 0.0);
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 2..200 {
+        let a = -((i - 1) * (i - 1)) as f64;
+        b += 2.0;
+        d = 1.0 / (a * d + b);
+        c = b + a / c;
+        let del = c * d;
+        h *= del;
+        if (del - 1.0).norm() < EPS {
+            break;
+        }
+    }
+    h *= Complex64::new(x.cos(), -x.sin());
+    (PI / 2.0 + h.im, -h.re)
+}
+
+/// Input impedance of a centre-fed thin dipole by the induced-EMF method (Ω).
+///
+/// Radiation resistance and reactance referred to the current maximum
+/// (Balanis 8.60-8.61), then transferred to the feed by 1/sin²(kl/2).
+/// Valid for lengths away from integer wavelengths, where the feed current
+/// vanishes and Z_in diverges.
+///
+/// # Arguments
+/// * `length` - Total dipole length (m)
+/// * `frequency` - Frequency (Hz)
+/// * `wire_radius` - Wire radius (m), much smaller than the length
+pub fn dipole_input_impedance(length: f64, frequency: f64, wire_radius: f64) -> Complex64 {
+    let k = 2.0 * PI * frequency / C_0;
+    let kl = k * length;
+    let (si1, ci1) = sine_cosine_integrals(kl);
+    let (si2, ci2) = sine_cosine_integrals(2.0 * kl);
+    let (_, ci_a) = sine_cosine_integrals(2.0 * k * wire_radius * wire_radius / length);
+    let (sin, cos) = kl.sin_cos();
+
+    let r_m = ETA_0 / (2.0 * PI)
+        * (EULER_GAMMA + kl.ln() - ci1
+            + 0.5 * sin * (si2 - 2.0 * si1)
+            + 0.5 * cos * (EULER_GAMMA + (kl / 2.0).ln() + ci2 - 2.0 * ci1));
+    let x_m = ETA_0 / (4.0 * PI)
+        * (2.0 * si1 + cos * (2.0 * si1 - si2) - sin * (2.0 * ci1 - ci2 - ci_a));
+
+    let transfer = (kl / 2.0).sin().powi(2);
+    Complex64::new(r_m, x_m) / transfer
+}
+
+/// Induced-EMF input impedance over a sweep of dipole lengths (Ω).
+///
+/// Resonance (X = 0) falls slightly below 0.5λ, where R ≈ 70 Ω; short
+/// dipoles are strongly capacitive.
+///
+/// # Arguments
+/// * `length_wavelengths` - Total lengths in wavelengths
+/// * `frequency` - Frequency (Hz)
+/// * `wire_radius` - Wire radius (m)
+pub fn dipole_impedance_sweep(
+    length_wavelengths: &[f64],
+    frequency: f64,
+    wire_radius: f64,
+) -> Vec<Complex64> {
+    let lambda = C_0 / frequency;
+    length_wavelengths
+        .iter()
+        .map(|&l| dipole_input_impedance(l * lambda, frequency, wire_radius))
+        .collect()
 }
 
 #[cfg(test)]
@@ -205,6 +445,47 @@ mod tests {
         assert_eq!(p.len(), 181);
     }
 
+    #[test]
+    fn hertzian_pattern_db_peak_and_floor() {
+        let d = HertzianDipole::new(0.01, 1.0, 1e9);
+        let thetas: Vec<f64> = (0..=180).map(|i| (i as f64).to_radians()).collect();
+        let db = d.pattern_db(&thetas);
+        assert_eq!(db[90], 0.0);
+        // Nulls on axis are clamped, not -∞
+        assert_eq!(db[0], crate::pattern::PATTERN_DB_FLOOR);
+        assert_eq!(db[180], crate::pattern::PATTERN_DB_FLOOR);
+        // Away from nulls: 10·log10(sin²θ)
+        assert_relative_eq!(db[30], 10.0 * (0.5_f64).powi(2).log10(), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn lossless_hertzian_has_unit_efficiency() {
+        let d = HertzianDipole::new(0.01, 1.0, 1e9);
+        assert_relative_eq!(d.radiation_efficiency(), 1.0, epsilon = 1e-15);
+        assert_relative_eq!(d.gain(), d.directivity(), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn hertzian_loss_reduces_efficiency_and_gain() {
+        // 1 cm copper dipole at 1 GHz: R_rad ≈ 0.088 Ω, comparable to R_loss
+        let d = HertzianDipole::new(0.01, 1.0, 1e9).with_wire(0.5e-3, 5.8e7);
+        assert!(d.r_loss > 0.0);
+        assert!(d.radiation_efficiency() < 1.0 && d.radiation_efficiency() > 0.0);
+        assert!(d.gain() < d.directivity());
+        assert_relative_eq!(d.gain(), d.radiation_efficiency() * 1.5, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn hertzian_loss_resistance_scaling() {
+        let d = HertzianDipole::new(0.01, 1.0, 1e9);
+        let r_1g = d.loss_resistance(0.5e-3, 5.8e7, 1e9);
+        let r_4g = d.loss_resistance(0.5e-3, 5.8e7, 4e9);
+        let r_thick = d.loss_resistance(1.0e-3, 5.8e7, 1e9);
+        // Skin effect: R ∝ √f; thicker wire: R ∝ 1/a
+        assert_relative_eq!(r_4g / r_1g, 2.0, max_relative = 1e-12);
+        assert_relative_eq!(r_thick / r_1g, 0.5, max_relative = 1e-12);
+    }
+
     // Half-wave dipole
 
     #[test]
@@ -231,6 +512,19 @@ mod tests {
         assert_relative_eq!(d.directivity(), 1.643, epsilon = 0.001);
     }
 
+    #[test]
+    fn halfwave_pattern_db_matches_linear_pattern() {
+        let d = HalfWaveDipole::new(1e9, 1.0);
+        let thetas = [0.0, PI / 6.0, PI / 3.0, PI / 2.0];
+        let db = d.pattern_db(&thetas);
+        assert_eq!(db[3], 0.0);
+        assert_eq!(db[0], crate::pattern::PATTERN_DB_FLOOR);
+        for i in 1..3 {
+            let expected = 10.0 * d.pattern(thetas[i]).powi(2).log10();
+            assert_relative_eq!(db[i], expected, max_relative = 1e-9);
+        }
+    }
+
     #[test]
     fn halfwave_directivity_dbi() {
         let d = HalfWaveDipole::new(1e9, 1.0);
@@ -250,4 +544,99 @@ mod tests {
         assert_eq!(t.len(), 91);
         assert_eq!(p.len(), 91);
     }
+
+    // Small loop
+
+    #[test]
+    fn small_loop_radiation_resistance_closed_forms_agree() {
+        let lambda = C_0 / 1e9;
+        let l = SmallLoop::new(0.01 * lambda, 1.0, 1e9);
+        let area_form = 320.0 * PI.powi(4) * (l.area() / (lambda * lambda)).powi(2);
+        assert_relative_eq!(l.radiation_resistance(), area_form, max_relative = 1e-12);
+        // C/λ = 0.02π: R = 20π²(0.02π)⁴ ≈ 0.0307 Ω
+        assert_relative_eq!(
+            l.radiation_resistance(),
+            20.0 * PI * PI * (0.02 * PI).powi(4),
+            max_relative = 1e-10
+        );
+    }
+
+    #[test]
+    fn small_loop_resistance_scales_as_fourth_power() {
+        let l1 = SmallLoop::new(0.005, 1.0, 1e9);
+        let l2 = SmallLoop::new(0.010, 1.0, 1e9);
+        let l3 = SmallLoop::new(0.005, 1.0, 2e9);
+        let r1 = l1.radiation_resistance();
+        assert_relative_eq!(l2.radiation_resistance() / r1, 16.0, max_relative = 1e-12);
+        assert_relative_eq!(l3.radiation_resistance() / r1, 16.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn small_loop_pattern_nulls_on_axis() {
+        let l = SmallLoop::new(0.01, 1.0, 1e9);
+        assert_relative_eq!(l.pattern(0.0), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(l.pattern(PI), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(l.pattern(PI / 2.0), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn small_loop_directivity() {
+        let l = SmallLoop::new(0.01, 1.0, 1e9);
+        assert_relative_eq!(l.directivity(), 1.5, epsilon = 1e-12);
+        // Numerically: D = 2 / ∫₀^π sin³θ dθ = 2 / (4/3)
+        let n = 10_000;
+        let dt = PI / n as f64;
+        let integral: f64 = (0..n)
+            .map(|i| {
+                let t = (i as f64 + 0.5) * dt;
+                l.pattern(t).powi(2) * t.sin() * dt
+            })
+            .sum();
+        assert_relative_eq!(2.0 / integral, l.directivity(), max_relative = 1e-6);
+    }
+
+    // Induced-EMF impedance
+
+    #[test]
+    fn sine_cosine_integrals_reference_values() {
+        // Abramowitz & Stegun table 5.1
+        for (x, si, ci) in [
+            (0.5, 0.493_107_418_043_066_6, -0.177_784_078_806_612_1),
+            (1.0, 0.946_083_070_367_183, 0.337_403_922_900_968_1),
+            (5.0, 1.549_931_244_944_674, -0.190_029_749_656_643_9),
+            (20.0, 1.548_241_701_043_439_7, 0.044_419_820_845_353_3),
+        ] {
+            let (s, c) = sine_cosine_integrals(x);
+            assert_relative_eq!(s, si, max_relative = 1e-12);
+            assert_relative_eq!(c, ci, max_relative = 1e-10);
+        }
+    }
+
+    #[test]
+    fn half_wave_dipole_impedance_is_73_plus_j42() {
+        let f = 300e6;
+        let z = dipole_impedance_sweep(&[0.5], f, 1e-4 * C_0 / f)[0];
+        assert_relative_eq!(z.re, 73.08, epsilon = 0.05);
+        assert_relative_eq!(z.im, 42.51, epsilon = 0.05);
+    }
+
+    #[test]
+    fn dipole_resonates_slightly_below_half_wave() {
+        let f = 300e6;
+        let lengths: Vec<f64> = (0..=200).map(|i| 0.40 + i as f64 * 0.0005).collect();
+        let z = dipole_impedance_sweep(&lengths, f, 1e-4 * C_0 / f);
+        let i_res = (1..z.len()).find(|&i| z[i - 1].im < 0.0 && z[i].im >= 0.0).unwrap();
+        assert!(lengths[i_res] > 0.45 && lengths[i_res] < 0.5, "l = {}", lengths[i_res]);
+        assert!(z[i_res].re > 60.0 && z[i_res].re < 73.0, "R = {}", z[i_res].re);
+    }
+
+    #[test]
+    fn short_dipole_is_strongly_capacitive() {
+        let f = 300e6;
+        let z = dipole_impedance_sweep(&[0.05, 0.1], f, 1e-4 * C_0 / f);
+        assert!(z[0].im < -1000.0, "X = {}", z[0].im);
+        assert!(z[0].im < z[1].im);
+        // Radiation resistance approaches 20π²(l/λ)²
+        assert_relative_eq!(z[0].re, 20.0 * PI * PI * 0.05 * 0.05, max_relative = 0.02);
+    }
 }
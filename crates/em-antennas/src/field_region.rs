@@ -0,0 +1,88 @@
+//! Field regions around an antenna of largest dimension D.
+//!
+//! - Reactive near field: R < 0.62·√(D³/λ)
+//! - Radiating near field (Fresnel): up to the Fraunhofer distance 2D²/λ
+//! - Far field (Fraunhofer): R ≥ 2D²/λ, where patterns and Friis apply
+
+use em_core::constants::C_0;
+use serde::{Deserialize, Serialize};
+
+/// Region of the field at a given distance from the antenna.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldRegion {
+    /// Reactive near field, dominated by stored energy
+    Reactive,
+    /// Radiating near field; the pattern still depends on distance
+    Fresnel,
+    /// Far field; the angular pattern no longer depends on distance
+    FarField,
+}
+
+/// Fraunhofer (far-field) distance 2D²/λ (m).
+///
+/// # Arguments
+/// * `max_dimension` - Largest antenna dimension D (m)
+/// * `frequency` - Operating frequency (Hz)
+pub fn fraunhofer_distance(max_dimension: f64, frequency: f64) -> f64 {
+    let lambda = C_0 / frequency;
+    2.0 * max_dimension * max_dimension / lambda
+}
+
+/// Outer edge of the reactive near field, 0.62·√(D³/λ) (m).
+pub fn reactive_near_field_distance(max_dimension: f64, frequency: f64) -> f64 {
+    let lambda = C_0 / frequency;
+    0.62 * (max_dimension.powi(3) / lambda).sqrt()
+}
+
+/// Classify a distance (m) from an antenna of largest dimension D (m).
+///
+/// For electrically small antennas (D ≲ 0.1λ) the reactive boundary lies
+/// beyond 2D²/λ and the Fresnel region vanishes; the reactive test wins.
+pub fn field_region(distance: f64, max_dimension: f64, frequency: f64) -> FieldRegion {
+    if distance < reactive_near_field_distance(max_dimension, frequency) {
+        FieldRegion::Reactive
+    } else if distance < fraunhofer_distance(max_dimension, frequency) {
+        FieldRegion::Fresnel
+    } else {
+        FieldRegion::FarField
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn fraunhofer_distance_is_two_d_squared_over_lambda() {
+        // 1 m dish at 10 GHz: λ ≈ 3 cm → 2D²/λ ≈ 66.7 m
+        let lambda = C_0 / 10e9;
+        assert_relative_eq!(fraunhofer_distance(1.0, 10e9), 2.0 / lambda, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn beyond_fraunhofer_distance_is_far_field() {
+        let r_ff = fraunhofer_distance(1.0, 10e9);
+        assert_eq!(field_region(r_ff, 1.0, 10e9), FieldRegion::FarField);
+        assert_eq!(field_region(1.5 * r_ff, 1.0, 10e9), FieldRegion::FarField);
+        assert_eq!(field_region(0.5 * r_ff, 1.0, 10e9), FieldRegion::Fresnel);
+    }
+
+    #[test]
+    fn inside_reactive_boundary_is_reactive() {
+        let r_nf = reactive_near_field_distance(1.0, 10e9);
+        assert_relative_eq!(r_nf, 0.62 * (1.0 / (C_0 / 10e9)).sqrt(), max_relative = 1e-12);
+        assert_eq!(field_region(0.9 * r_nf, 1.0, 10e9), FieldRegion::Reactive);
+        assert_eq!(field_region(1.1 * r_nf, 1.0, 10e9), FieldRegion::Fresnel);
+    }
+
+    #[test]
+    fn small_antenna_has_no_fresnel_region() {
+        // D = λ/20: reactive edge exceeds 2D²/λ
+        let f = 1e9;
+        let d = C_0 / f / 20.0;
+        assert!(reactive_near_field_distance(d, f) > fraunhofer_distance(d, f));
+        let r = 0.5 * (reactive_near_field_distance(d, f) + fraunhofer_distance(d, f));
+        assert_eq!(field_region(r, d, f), FieldRegion::Reactive);
+    }
+}
@@ -0,0 +1,175 @@
+//! Pyramidal horn antenna fed by a rectangular waveguide.
+//!
+//! Gain and beamwidth estimates from aperture dimensions, using the
+//! aperture-efficiency formula G = ε_ap·4π·A·B/λ² with ε_ap ≈ 0.51
+//! (optimum-gain horn).
+
+use em_core::constants::C_0;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Aperture efficiency of an optimum-gain pyramidal horn.
+pub const HORN_APERTURE_EFFICIENCY: f64 = 0.51;
+
+/// Pyramidal horn flared from an a × b waveguide to an A × B aperture.
+///
+/// The H-plane (A) dimension sees a cosine field taper, the E-plane (B)
+/// dimension a near-uniform one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PyramidalHorn {
+    /// Feed waveguide broad wall (m)
+    pub a: f64,
+    /// Feed waveguide narrow wall (m)
+    pub b: f64,
+    /// Aperture width in the H-plane, A (m)
+    pub aperture_a: f64,
+    /// Aperture height in the E-plane, B (m)
+    pub aperture_b: f64,
+    /// Axial length from waveguide throat to aperture (m)
+    pub axial_length: f64,
+}
+
+impl PyramidalHorn {
+    pub fn new(a: f64, b: f64, aperture_a: f64, aperture_b: f64, axial_length: f64) -> Self {
+        Self {
+            a,
+            b,
+            aperture_a,
+            aperture_b,
+            axial_length,
+        }
+    }
+
+    /// Aperture area A·B (m²).
+    pub fn aperture_area(&self) -> f64 {
+        self.aperture_a * self.aperture_b
+    }
+
+    /// H-plane flare half-angle: atan((A - a)/(2L)) (rad).
+    pub fn h_plane_flare_angle(&self) -> f64 {
+        ((self.aperture_a - self.a) / (2.0 * self.axial_length)).atan()
+    }
+
+    /// E-plane flare half-angle: atan((B - b)/(2L)) (rad).
+    pub fn e_plane_flare_angle(&self) -> f64 {
+        ((self.aperture_b - self.b) / (2.0 * self.axial_length)).atan()
+    }
+
+    /// Approximate gain: G = ε_ap·4π·A·B/λ².
+    pub fn gain(&self, frequency: f64) -> f64 {
+        aperture_gain(self.aperture_area(), frequency)
+    }
+
+    /// Approximate gain in dBi.
+    pub fn gain_dbi(&self, frequency: f64) -> f64 {
+        10.0 * self.gain(frequency).log10()
+    }
+
+    /// Gain of the bare a × b waveguide mouth under the same model —
+    /// the zero-flare limit of [`PyramidalHorn::gain`].
+    pub fn open_waveguide_gain(&self, frequency: f64) -> f64 {
+        aperture_gain(self.a * self.b, frequency)
+    }
+
+    /// E-plane half-power beamwidth ≈ 50.8°·λ/B (uniform aperture), in degrees.
+    pub fn e_plane_beamwidth_deg(&self, frequency: f64) -> f64 {
+        50.8 * C_0 / frequency / self.aperture_b
+    }
+
+    /// H-plane half-power beamwidth ≈ 68.8°·λ/A (cosine-tapered aperture), in degrees.
+    pub fn h_plane_beamwidth_deg(&self, frequency: f64) -> f64 {
+        68.8 * C_0 / frequency / self.aperture_a
+    }
+}
+
+fn aperture_gain(area: f64, frequency: f64) -> f64 {
+    let lambda = C_0 / frequency;
+    HORN_APERTURE_EFFICIENCY * 4.0 * PI * area / (lambda * lambda)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// WR-90 feed flared to a standard-gain-horn aperture.
+    fn x_band_horn() -> PyramidalHorn {
+        PyramidalHorn::new(0.02286, 0.01016, 0.14, 0.10, 0.25)
+    }
+
+    #[test]
+    fn x_band_horn_gain_is_realistic() {
+        // A 14 × 10 cm aperture at 10 GHz is a ~20 dBi standard-gain horn
+        let g = x_band_horn().gain_dbi(10e9);
+        assert!((g - 20.0).abs() < 1.0, "gain = {g} dBi");
+    }
+
+    #[test]
+    fn gain_increases_with_aperture_area() {
+        let small = x_band_horn();
+        let big = PyramidalHorn {
+            aperture_a: 0.20,
+            aperture_b: 0.15,
+            ..small
+        };
+        assert!(big.gain(10e9) > small.gain(10e9));
+        assert_relative_eq!(
+            big.gain(10e9) / small.gain(10e9),
+            big.aperture_area() / small.aperture_area(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn beamwidths_scale_as_lambda_over_aperture() {
+        let h = x_band_horn();
+        let lambda = C_0 / 10e9;
+        assert_relative_eq!(
+            h.e_plane_beamwidth_deg(10e9),
+            50.8 * lambda / h.aperture_b,
+            max_relative = 1e-12
+        );
+        // Doubling frequency halves λ and both beamwidths
+        assert_relative_eq!(
+            h.h_plane_beamwidth_deg(20e9) / h.h_plane_beamwidth_deg(10e9),
+            0.5,
+            max_relative = 1e-12
+        );
+        let wide = PyramidalHorn {
+            aperture_a: 2.0 * h.aperture_a,
+            ..h
+        };
+        assert_relative_eq!(
+            wide.h_plane_beamwidth_deg(10e9) / h.h_plane_beamwidth_deg(10e9),
+            0.5,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn gain_falls_toward_open_waveguide_as_flare_shrinks() {
+        let h = x_band_horn();
+        let g_open = h.open_waveguide_gain(10e9);
+        let mut previous = f64::INFINITY;
+        for i in 0..=10 {
+            let t = 1.0 - i as f64 / 10.0;
+            let horn = PyramidalHorn {
+                aperture_a: h.a + t * (h.aperture_a - h.a),
+                aperture_b: h.b + t * (h.aperture_b - h.b),
+                ..h
+            };
+            let g = horn.gain(10e9);
+            assert!(g < previous && g >= g_open);
+            previous = g;
+        }
+        assert_relative_eq!(previous, g_open, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn flare_angles_vanish_without_flare() {
+        let h = PyramidalHorn::new(0.02286, 0.01016, 0.02286, 0.01016, 0.1);
+        assert_relative_eq!(h.h_plane_flare_angle(), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(h.e_plane_flare_angle(), 0.0, epsilon = 1e-15);
+        assert!(x_band_horn().h_plane_flare_angle() > 0.0);
+    }
+}
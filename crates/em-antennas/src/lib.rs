@@ -4,7 +4,13 @@
 //! - Module 8.2: Half-wave dipole
 //! - Module 8.3: Antenna arrays (uniform linear, broadside/endfire)
 //! - Module 8.4: Friis transmission equation and link budget
+//! - Module 8.5: Pyramidal horn gain and beamwidth
+//! - Numerical directivity of arbitrary radiation patterns
+//! - Field regions (reactive, Fresnel, far field) and the Fraunhofer distance
 
 pub mod dipole;
 pub mod arrays;
 pub mod link_budget;
+pub mod horn;
+pub mod pattern;
+pub mod field_region;
@@ -2,6 +2,7 @@
 //!
 //! P_r/P_t = G_t · G_r · (λ/(4πR))²
 
+use em_core::Complex64;
 use em_core::constants::C_0;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -19,6 +20,10 @@ pub struct FriisLink {
     pub frequency: f64,
     /// Distance between antennas (m)
     pub distance: f64,
+    /// Measured or modeled S₂₁ of an extra two-port in the chain (cable,
+    /// filter, channel); `None` for a plain Friis link
+    #[serde(default)]
+    pub s21: Option<Complex64>,
 }
 
 impl FriisLink {
@@ -29,9 +34,29 @@ impl FriisLink {
             g_rx,
             frequency,
             distance,
+            s21: None,
         }
     }
 
+    /// Cascade a two-port with transmission coefficient S₂₁ into the link.
+    ///
+    /// Received power is scaled by [`link_gain_from_s21`]; the phase is kept
+    /// for [`FriisLink::s21_phase_delay`].
+    pub fn with_s21(mut self, s21: Complex64) -> Self {
+        self.s21 = Some(s21);
+        self
+    }
+
+    /// Power gain of the cascaded two-port (linear); 1 if none is set.
+    pub fn s21_gain(&self) -> f64 {
+        self.s21.map_or(1.0, link_gain_from_s21)
+    }
+
+    /// Phase delay -∠S₂₁/ω (s) of the cascaded two-port; 0 if none is set.
+    pub fn s21_phase_delay(&self) -> f64 {
+        self.s21.map_or(0.0, |s21| -s21.arg() / (2.0 * PI * self.frequency))
+    }
+
     /// Wavelength λ = c/f.
     pub fn wavelength(&self) -> f64 {
         C_0 / self.frequency
@@ -50,9 +75,9 @@ impl FriisLink {
 
     /// Received power (W).
     ///
-    /// P_r = P_t · G_t · G_r / L
+    /// P_r = P_t · G_t · G_r · |S₂₁|² / L, with |S₂₁|² = 1 when no two-port is set.
     pub fn received_power(&self) -> f64 {
-        self.p_tx * self.g_tx * self.g_rx / self.path_loss()
+        self.p_tx * self.g_tx * self.g_rx * self.s21_gain() / self.path_loss()
     }
 
     /// Received power in dBW.
@@ -91,13 +116,43 @@ impl FriisLink {
         let dr = (r_max - r_min) / (num_points - 1) as f64;
         let distances: Vec<f64> = (0..num_points).map(|i| r_min + i as f64 * dr).collect();
         let powers: Vec<f64> = distances.iter().map(|&r| {
-            let link = FriisLink::new(self.p_tx, self.g_tx, self.g_rx, self.frequency, r);
+            let link = FriisLink { distance: r, ..*self };
             link.received_power_dbm()
         }).collect();
         (distances, powers)
     }
 }
 
+/// Power gain |S₂₁|² of a two-port (linear).
+///
+/// This is the transducer gain when the two-port is driven and terminated in
+/// its S-parameter reference impedance (typically 50 Ω).
+pub fn link_gain_from_s21(s21: Complex64) -> f64 {
+    s21.norm_sqr()
+}
+
+/// Received signal strength (dBm) for a free-space link.
+///
+/// RSSI = P_t + G_t + G_r - FSPL - L_extra, with FSPL = 20·log10(4πR/λ).
+///
+/// # Arguments
+/// * `tx_power_dbm` - Transmit power (dBm)
+/// * `tx_gain_dbi`, `rx_gain_dbi` - Antenna gains (dBi)
+/// * `frequency` - Frequency (Hz)
+/// * `distance` - Link distance (m)
+/// * `extra_losses_db` - Cable, connector, fade margin etc. (dB, positive = loss)
+pub fn rssi_dbm(
+    tx_power_dbm: f64,
+    tx_gain_dbi: f64,
+    rx_gain_dbi: f64,
+    frequency: f64,
+    distance: f64,
+    extra_losses_db: f64,
+) -> f64 {
+    let fspl_db = 20.0 * (4.0 * PI * distance * frequency / C_0).log10();
+    tx_power_dbm + tx_gain_dbi + rx_gain_dbi - fspl_db - extra_losses_db
+}
+
 /// Convert linear gain to dB.
 pub fn to_db(linear: f64) -> f64 {
     10.0 * linear.log10()
@@ -203,4 +258,61 @@ mod tests {
             assert!(ps[i] <= ps[i - 1], "power should decrease with distance");
         }
     }
+
+    #[test]
+    fn unit_s21_adds_zero_db() {
+        let link = FriisLink::new(1.0, 10.0, 10.0, 1e9, 1000.0);
+        let with = link.with_s21(Complex64::from_polar(1.0, 0.7));
+        assert_relative_eq!(to_db(link_gain_from_s21(Complex64::new(1.0, 0.0))), 0.0);
+        assert_relative_eq!(with.received_power_dbm(), link.received_power_dbm(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn minus_3db_s21_reduces_received_power_by_3db() {
+        let link = FriisLink::new(1.0, 10.0, 10.0, 1e9, 1000.0);
+        let s21 = Complex64::from_polar(from_db(-3.0).sqrt(), -1.2);
+        let with = link.with_s21(s21);
+        let drop = link.received_power_dbm() - with.received_power_dbm();
+        assert_relative_eq!(drop, 3.0, max_relative = 1e-12);
+        // The reduction carries through distance sweeps
+        let (_, a) = link.sample_vs_distance(100.0, 1000.0, 5);
+        let (_, b) = with.sample_vs_distance(100.0, 1000.0, 5);
+        for (pa, pb) in a.iter().zip(&b) {
+            assert_relative_eq!(pa - pb, 3.0, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn s21_phase_changes_delay_not_power() {
+        let link = FriisLink::new(1.0, 10.0, 10.0, 1e9, 1000.0);
+        let a = link.with_s21(Complex64::from_polar(0.5, 0.0));
+        let b = link.with_s21(Complex64::from_polar(0.5, -PI / 2.0));
+        assert_relative_eq!(a.received_power(), b.received_power(), max_relative = 1e-12);
+        assert_eq!(b.s21.unwrap().arg(), -PI / 2.0);
+        // -90° at 1 GHz is a quarter period: 0.25 ns
+        assert_relative_eq!(b.s21_phase_delay(), 0.25e-9, max_relative = 1e-12);
+        assert_eq!(link.s21_phase_delay(), 0.0);
+    }
+
+    #[test]
+    fn rssi_drops_6_db_per_distance_doubling() {
+        let near = rssi_dbm(20.0, 2.0, 2.0, 2.4e9, 100.0, 0.0);
+        let far = rssi_dbm(20.0, 2.0, 2.0, 2.4e9, 200.0, 0.0);
+        assert_relative_eq!(near - far, 20.0 * 2.0_f64.log10(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn rssi_subtracts_extra_losses() {
+        let clean = rssi_dbm(20.0, 2.0, 2.0, 2.4e9, 100.0, 0.0);
+        let lossy = rssi_dbm(20.0, 2.0, 2.0, 2.4e9, 100.0, 3.0);
+        assert_relative_eq!(clean - lossy, 3.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn rssi_matches_friis_link() {
+        let link = FriisLink::new(dbm_to_watts(20.0), from_db(6.0), from_db(3.0), 915e6, 2500.0);
+        let rssi = rssi_dbm(20.0, 6.0, 3.0, 915e6, 2500.0, 0.0);
+        assert_relative_eq!(rssi, link.received_power_dbm(), max_relative = 1e-12);
+        assert_relative_eq!(rssi, 29.0 - link.path_loss_db(), max_relative = 1e-12);
+    }
 }
@@ -0,0 +1,223 @@
+//! Numerical directivity, dB conversion and 3D meshing of radiation patterns.
+//!
+//! D = 4π·U_max / ∫∫ U(θ, φ) sin θ dθ dφ, integrated over the full sphere.
+
+use em_core::coordinates::Cartesian;
+use std::f64::consts::PI;
+
+/// Directivity of a power pattern U(θ, φ) by numerical integration.
+///
+/// θ is sampled at `num_theta + 1` nodes over [0, π] (trapezoidal rule with
+/// sin θ weighting, so the poles contribute nothing to the integral) and φ at
+/// `num_phi` equally spaced nodes over [0, 2π), which is exact for patterns
+/// periodic in φ. U_max is taken over the same grid, so use an even
+/// `num_theta` when the peak lies at broadside. Pass `num_phi = 1` for
+/// azimuthally symmetric patterns.
+///
+/// # Arguments
+/// * `pattern` - Power pattern U(θ, φ) ≥ 0 (any scale; it cancels)
+/// * `num_theta` - Number of θ intervals (≥ 2)
+/// * `num_phi` - Number of φ samples (≥ 1)
+///
+/// # Returns
+/// Directivity (linear). Returns 0 if the pattern is identically zero.
+pub fn directivity_from_pattern(
+    pattern: impl Fn(f64, f64) -> f64,
+    num_theta: usize,
+    num_phi: usize,
+) -> f64 {
+    assert!(num_theta >= 2, "need at least 2 theta intervals");
+    assert!(num_phi >= 1, "need at least 1 phi sample");
+    let dtheta = PI / num_theta as f64;
+    let dphi = 2.0 * PI / num_phi as f64;
+
+    let mut u_max = 0.0_f64;
+    let mut integral = 0.0;
+    for i in 0..=num_theta {
+        let theta = i as f64 * dtheta;
+        let weight = if i == 0 || i == num_theta { 0.5 } else { 1.0 };
+        for j in 0..num_phi {
+            let u = pattern(theta, j as f64 * dphi);
+            u_max = u_max.max(u);
+            integral += weight * u * theta.sin() * dtheta * dphi;
+        }
+    }
+
+    if integral <= 0.0 {
+        return 0.0;
+    }
+    4.0 * PI * u_max / integral
+}
+
+/// Floor (dB) applied by [`field_pattern_db`] so nulls stay finite on a polar plot.
+pub const PATTERN_DB_FLOOR: f64 = -60.0;
+
+/// Convert field-pattern samples F(θ) to normalized radiation intensity in dB.
+///
+/// U ∝ F², so each value is 10·log10(F²/F²_max), with the largest sample at
+/// exactly 0 dB and nulls clamped to [`PATTERN_DB_FLOOR`]. An all-zero input
+/// maps entirely to the floor.
+pub fn field_pattern_db(field: &[f64]) -> Vec<f64> {
+    let peak = field.iter().fold(0.0_f64, |m, &f| m.max(f.abs()));
+    field
+        .iter()
+        .map(|&f| {
+            if peak == 0.0 {
+                return PATTERN_DB_FLOOR;
+            }
+            (10.0 * (f / peak).powi(2).log10()).max(PATTERN_DB_FLOOR)
+        })
+        .collect()
+}
+
+/// Vertices of a 3D pattern "balloon" for rendering.
+///
+/// θ is sampled at `num_theta` nodes over [0, π] (both poles included) and φ
+/// at `num_phi` equally spaced nodes over [0, 2π). Each vertex lies in the
+/// (θ, φ) direction at radius |pattern|/max|pattern|, so the peak touches the
+/// unit sphere. Vertices are θ-major: index `i * num_phi + j` is (θ_i, φ_j),
+/// which lets neighbouring rows be stitched into quads.
+///
+/// # Arguments
+/// * `pattern` - Field or power pattern (θ, φ), any scale
+/// * `num_theta` - Number of θ nodes (≥ 2)
+/// * `num_phi` - Number of φ nodes (≥ 1)
+///
+/// An identically zero pattern collapses every vertex to the origin.
+pub fn pattern_mesh(
+    pattern: impl Fn(f64, f64) -> f64,
+    num_theta: usize,
+    num_phi: usize,
+) -> Vec<Cartesian> {
+    assert!(num_theta >= 2, "need at least 2 theta nodes");
+    assert!(num_phi >= 1, "need at least 1 phi node");
+    let dtheta = PI / (num_theta - 1) as f64;
+    let dphi = 2.0 * PI / num_phi as f64;
+
+    let samples: Vec<(f64, f64, f64)> = (0..num_theta)
+        .flat_map(|i| (0..num_phi).map(move |j| (i as f64 * dtheta, j as f64 * dphi)))
+        .map(|(theta, phi)| (theta, phi, pattern(theta, phi).abs()))
+        .collect();
+    let peak = samples.iter().fold(0.0_f64, |m, &(_, _, r)| m.max(r));
+    let scale = if peak > 0.0 { 1.0 / peak } else { 0.0 };
+
+    samples
+        .into_iter()
+        .map(|(theta, phi, r)| {
+            let r = r * scale;
+            Cartesian::new(
+                r * theta.sin() * phi.cos(),
+                r * theta.sin() * phi.sin(),
+                r * theta.cos(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn isotropic_pattern_has_unit_directivity() {
+        let d = directivity_from_pattern(|_, _| 1.0, 180, 8);
+        assert_relative_eq!(d, 1.0, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn cos_squared_pattern() {
+        // ∫∫ cos²θ dΩ = 4π/3  →  D = 3
+        let d = directivity_from_pattern(|t, _| t.cos().powi(2), 360, 1);
+        assert_relative_eq!(d, 3.0, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn hertzian_sin_squared_pattern() {
+        let d = directivity_from_pattern(|t, _| t.sin().powi(2), 360, 1);
+        assert_relative_eq!(d, 1.5, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn phi_dependent_pattern() {
+        // U = sin²θ·cos²φ, peaked along ±x: ∫∫ U dΩ = 4π/3  →  D = 3
+        let d = directivity_from_pattern(|t, p| (t.sin() * p.cos()).powi(2), 360, 72);
+        assert_relative_eq!(d, 3.0, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn matches_closed_form_dipole_directivities() {
+        use crate::dipole::{HalfWaveDipole, HertzianDipole, SmallLoop};
+
+        let d = HertzianDipole::new(0.01, 1.0, 1e9);
+        let numeric = directivity_from_pattern(|t, _| d.pattern(t).powi(2), 2000, 1);
+        assert_relative_eq!(numeric, d.directivity(), max_relative = 1e-6);
+
+        let l = SmallLoop::new(0.01, 1.0, 1e9);
+        let numeric = directivity_from_pattern(|t, _| l.pattern(t).powi(2), 2000, 1);
+        assert_relative_eq!(numeric, l.directivity(), max_relative = 1e-6);
+
+        // Pattern integral gives 1.641 against the tabulated 1.643
+        let h = HalfWaveDipole::new(1e9, 1.0);
+        let numeric = directivity_from_pattern(|t, _| h.pattern(t).powi(2), 2000, 1);
+        assert_relative_eq!(numeric, h.directivity(), max_relative = 2e-3);
+    }
+
+    #[test]
+    fn zero_pattern_gives_zero() {
+        assert_eq!(directivity_from_pattern(|_, _| 0.0, 10, 1), 0.0);
+    }
+
+    #[test]
+    fn field_pattern_db_peak_is_zero_and_nulls_hit_floor() {
+        let db = field_pattern_db(&[0.0, 0.5, 2.0, 1.0]);
+        assert_eq!(db[2], 0.0);
+        assert_eq!(db[0], PATTERN_DB_FLOOR);
+        assert_relative_eq!(db[3], 10.0 * 0.25_f64.log10(), max_relative = 1e-12);
+    }
+
+    fn radius(p: &Cartesian) -> f64 {
+        (p.x * p.x + p.y * p.y + p.z * p.z).sqrt()
+    }
+
+    #[test]
+    fn isotropic_mesh_is_unit_sphere() {
+        let mesh = pattern_mesh(|_, _| 3.0, 19, 36);
+        assert_eq!(mesh.len(), 19 * 36);
+        for p in &mesh {
+            assert_relative_eq!(radius(p), 1.0, max_relative = 1e-12);
+        }
+        // First row is the +z pole, last row the -z pole
+        assert_relative_eq!(mesh[0].z, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(mesh[mesh.len() - 1].z, -1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn dipole_mesh_is_toroid_with_axial_nulls() {
+        let (num_theta, num_phi) = (37, 24);
+        let mesh = pattern_mesh(|t, _| t.sin().powi(2), num_theta, num_phi);
+        assert_eq!(mesh.len(), num_theta * num_phi);
+        for j in 0..num_phi {
+            assert!(radius(&mesh[j]) < 1e-12);
+            assert!(radius(&mesh[(num_theta - 1) * num_phi + j]) < 1e-12);
+            // Equatorial ring is the peak, in the xy-plane
+            let p = &mesh[(num_theta / 2) * num_phi + j];
+            assert_relative_eq!(radius(p), 1.0, max_relative = 1e-12);
+            assert!(p.z.abs() < 1e-12);
+        }
+        // Radius follows sin²θ in between
+        let p = &mesh[6 * num_phi + 5];
+        let theta = 6.0 * PI / 36.0;
+        assert_relative_eq!(radius(p), theta.sin().powi(2), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn zero_pattern_mesh_collapses_to_origin() {
+        assert!(pattern_mesh(|_, _| 0.0, 5, 4).iter().all(|p| radius(p) == 0.0));
+    }
+
+    #[test]
+    fn all_zero_field_maps_to_floor() {
+        assert!(field_pattern_db(&[0.0; 4]).iter().all(|&v| v == PATTERN_DB_FLOOR));
+    }
+}
@@ -5,6 +5,7 @@
 //! - Reflection coefficient ↔ impedance mapping
 //! - Phasor arithmetic
 //! - Complex propagation constant decomposition (α + jβ)
+//! - Square roots on the physical branch (α ≥ 0, Re(η) ≥ 0)
 
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
@@ -54,6 +55,19 @@ impl Phasor {
     pub fn phase_deg(&self) -> f64 {
         self.phase_rad.to_degrees()
     }
+
+    /// Compact `[magnitude, phase_rad]` form for bulk transfer.
+    pub fn to_array(self) -> [f64; 2] {
+        [self.magnitude, self.phase_rad]
+    }
+
+    /// Rebuild from `[magnitude, phase_rad]` exactly as stored (no normalization).
+    pub fn from_array(a: [f64; 2]) -> Self {
+        Self {
+            magnitude: a[0],
+            phase_rad: a[1],
+        }
+    }
 }
 
 /// Normalize an angle to the range (-π, π].
@@ -102,6 +116,39 @@ pub fn vswr(gamma: Complex64) -> f64 {
     (1.0 + mag) / (1.0 - mag)
 }
 
+/// Express a reflection coefficient as S11 in dB: 20·log10(|Γ|).
+///
+/// # Returns
+/// 0 dB for total reflection; `f64::NEG_INFINITY` for a perfect match.
+pub fn s11_db(gamma: Complex64) -> f64 {
+    20.0 * gamma.norm().log10()
+}
+
+/// Apply [`s11_db`] to each point of a reflection-coefficient sweep.
+pub fn sweep_s11_db(gammas: &[Complex64]) -> Vec<f64> {
+    gammas.iter().map(|&g| s11_db(g)).collect()
+}
+
+/// Square root on the physically meaningful branch.
+///
+/// With `positive_real` the root with Re ≥ 0 is returned (α ≥ 0 for a
+/// propagation constant, Re(η) ≥ 0 for a passive impedance); otherwise the
+/// root with Im ≥ 0. A zero component is broken in favour of the other one
+/// being non-negative, so a lossless γ = jβ always has β ≥ 0.
+pub fn physical_sqrt(z: Complex64, positive_real: bool) -> Complex64 {
+    let root = z.sqrt();
+    let (lead, tie) = if positive_real {
+        (root.re, root.im)
+    } else {
+        (root.im, root.re)
+    };
+    if lead < 0.0 || (lead == 0.0 && tie < 0.0) {
+        -root
+    } else {
+        root
+    }
+}
+
 /// Decompose a complex propagation constant γ into attenuation and phase constants.
 ///
 /// γ = α + jβ where α is the attenuation constant (Np/m) and β is the phase constant (rad/m).
@@ -137,12 +184,10 @@ impl PropagationConstant {
     /// * `epsilon` - Permittivity (F/m)
     /// * `sigma` - Conductivity (S/m)
     pub fn for_lossy_medium(omega: f64, mu: f64, epsilon: f64, sigma: f64) -> Self {
-        let j = Complex64::new(0.0, 1.0);
         let complex_eps = Complex64::new(epsilon, -sigma / omega);
-        let gamma = j * omega * (mu * complex_eps).sqrt();
-        // Ensure α ≥ 0 (wave decays in propagation direction)
-        let result = if gamma.re < 0.0 { -gamma } else { gamma };
-        Self::from_complex(result)
+        // γ² = -ω²μ·ε_c; take α ≥ 0 (wave decays in propagation direction)
+        let gamma = physical_sqrt(-omega * omega * mu * complex_eps, true);
+        Self::from_complex(gamma)
     }
 
     /// Wavelength in the medium: λ = 2π/β
@@ -201,6 +246,16 @@ pub fn phasor_multiply(a: Phasor, b: Phasor) -> Phasor {
     Phasor::new(a.magnitude * b.magnitude, a.phase_rad + b.phase_rad)
 }
 
+/// Compact `[re, im]` form of a complex number for bulk transfer.
+pub fn to_ri(z: Complex64) -> [f64; 2] {
+    [z.re, z.im]
+}
+
+/// Rebuild a complex number from `[re, im]`.
+pub fn from_ri(ri: [f64; 2]) -> Complex64 {
+    Complex64::new(ri[0], ri[1])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +295,19 @@ mod tests {
         assert_relative_eq!(p.phase_deg(), 60.0, epsilon = 1e-10);
     }
 
+    #[test]
+    fn phasor_array_roundtrip_is_exact() {
+        let p = Phasor::new(2.75, -1.234_567_891);
+        assert_eq!(Phasor::from_array(p.to_array()), p);
+    }
+
+    #[test]
+    fn complex_ri_roundtrip_is_exact() {
+        let z = Complex64::new(-0.123_456_789, 9.876_543_21e-7);
+        assert_eq!(to_ri(z), [z.re, z.im]);
+        assert_eq!(from_ri(to_ri(z)), z);
+    }
+
     // ================================================================
     // Normalize angle tests
     // ================================================================
@@ -337,6 +405,43 @@ mod tests {
         assert_relative_eq!(vswr(gamma), 3.0, epsilon = 1e-12);
     }
 
+    // ================================================================
+    // S11 (dB) tests
+    // ================================================================
+
+    #[test]
+    fn s11_db_total_reflection_is_0_db() {
+        assert_relative_eq!(s11_db(Complex64::new(-1.0, 0.0)), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(s11_db(Complex64::new(0.0, 1.0)), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn s11_db_tenth_reflection_is_minus_20_db() {
+        let gamma = Complex64::from_polar(0.1, 0.7);
+        assert_relative_eq!(s11_db(gamma), -20.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn s11_db_perfect_match_is_neg_infinity() {
+        assert_eq!(s11_db(Complex64::new(0.0, 0.0)), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn sweep_s11_db_maps_element_wise() {
+        let gammas = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, -0.1),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.3, 0.4),
+        ];
+        let db = sweep_s11_db(&gammas);
+        assert_eq!(db.len(), gammas.len());
+        for (d, &g) in db.iter().zip(&gammas) {
+            assert_eq!(*d, s11_db(g));
+        }
+        assert_relative_eq!(db[3], 20.0 * 0.5_f64.log10(), epsilon = 1e-12);
+    }
+
     // ================================================================
     // Propagation constant tests
     // ================================================================
@@ -364,6 +469,46 @@ mod tests {
         assert!(pc.beta > 0.0, "phase constant must be positive");
     }
 
+    #[test]
+    fn physical_sqrt_keeps_principal_root_when_already_physical() {
+        let z = Complex64::new(3.0, 4.0);
+        let r = physical_sqrt(z, true);
+        assert_eq!(r, z.sqrt());
+        assert_relative_eq!(r.re, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(r.im, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn physical_sqrt_flips_to_requested_branch() {
+        // Principal √(3 - 4j) = 2 - j has Im < 0
+        let z = Complex64::new(3.0, -4.0);
+        let r = physical_sqrt(z, false);
+        assert_relative_eq!(r.re, -2.0, epsilon = 1e-12);
+        assert_relative_eq!(r.im, 1.0, epsilon = 1e-12);
+        assert_relative_eq!((r * r - z).norm(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn physical_sqrt_of_lossless_gamma_squared_gives_positive_beta() {
+        // γ² = -β² with either sign of zero imaginary part
+        for im in [0.0, -0.0] {
+            let r = physical_sqrt(Complex64::new(-4.0, im), true);
+            assert_eq!(r.re, 0.0);
+            assert_relative_eq!(r.im, 2.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn physical_sqrt_of_lossy_gamma_squared_has_positive_alpha() {
+        // γ = 0.3 + 2j  →  γ² = -3.91 + 1.2j
+        let gamma = Complex64::new(0.3, 2.0);
+        let r = physical_sqrt(gamma * gamma, true);
+        assert_relative_eq!(r.re, 0.3, epsilon = 1e-12);
+        assert_relative_eq!(r.im, 2.0, epsilon = 1e-12);
+        let neg = physical_sqrt((-gamma) * (-gamma), true);
+        assert!(neg.re >= 0.0);
+    }
+
     #[test]
     fn propagation_constant_wavelength() {
         let pc = PropagationConstant {
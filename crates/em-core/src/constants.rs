@@ -80,6 +80,35 @@ pub fn skin_depth(frequency_hz: f64, mu: f64, sigma: f64) -> f64 {
     (2.0 / denominator).sqrt()
 }
 
+/// Reference temperature (°C) at which conductivities are tabulated.
+pub const REFERENCE_TEMPERATURE_C: f64 = 20.0;
+
+/// Compute the skin depth of a conductor whose conductivity varies with temperature.
+///
+/// Uses the linear resistivity model σ(T) = σ_ref / (1 + α(T − 20 °C)), so a
+/// positive coefficient (copper: α ≈ 0.00393 /°C) makes the skin depth grow
+/// as the conductor heats up.
+///
+/// # Arguments
+/// * `frequency_hz` - Frequency in Hz
+/// * `mu` - Permeability (H/m)
+/// * `sigma_ref` - Conductivity at 20 °C (S/m)
+/// * `temp_coeff` - Temperature coefficient of resistivity α (1/°C)
+/// * `temp_c` - Conductor temperature (°C)
+///
+/// # Returns
+/// Skin depth in meters, as for [`skin_depth`].
+pub fn skin_depth_at_temperature(
+    frequency_hz: f64,
+    mu: f64,
+    sigma_ref: f64,
+    temp_coeff: f64,
+    temp_c: f64,
+) -> f64 {
+    let sigma = sigma_ref / (1.0 + temp_coeff * (temp_c - REFERENCE_TEMPERATURE_C));
+    skin_depth(frequency_hz, mu, sigma)
+}
+
 /// Compute the intrinsic impedance η = √(μ/ε) for a lossless medium.
 ///
 /// # Arguments
@@ -92,6 +121,18 @@ pub fn intrinsic_impedance(mu: f64, epsilon: f64) -> f64 {
     (mu / epsilon).sqrt()
 }
 
+/// Compute the intrinsic impedance from relative material parameters: η = η₀·√(μᵣ/εᵣ).
+///
+/// # Arguments
+/// * `epsilon_r` - Relative permittivity
+/// * `mu_r` - Relative permeability
+///
+/// # Returns
+/// Intrinsic impedance in Ohms; exactly `ETA_0` for vacuum.
+pub fn intrinsic_impedance_relative(epsilon_r: f64, mu_r: f64) -> f64 {
+    ETA_0 * (mu_r / epsilon_r).sqrt()
+}
+
 /// Compute the phase velocity in a medium: v_p = 1/√(με)
 pub fn phase_velocity(mu: f64, epsilon: f64) -> f64 {
     1.0 / (mu * epsilon).sqrt()
@@ -142,6 +183,12 @@ mod tests {
         assert_relative_eq!(ETA_0, computed, max_relative = 1e-6);
     }
 
+    #[test]
+    fn eta_0_matches_mu_0_and_epsilon_0_to_1e_9() {
+        let computed = (MU_0 / EPSILON_0).sqrt();
+        assert_relative_eq!(ETA_0, computed, max_relative = 1e-9);
+    }
+
     #[test]
     fn eta_0_approximately_377_ohms() {
         assert_relative_eq!(ETA_0, 376.73, max_relative = 1e-4);
@@ -207,12 +254,46 @@ mod tests {
         assert!(skin_depth(1.0e9, MU_0, 0.0).is_infinite());
     }
 
+    #[test]
+    fn skin_depth_at_reference_temperature_matches_base() {
+        let delta = skin_depth_at_temperature(1.0e6, MU_0, 5.8e7, 0.00393, 20.0);
+        assert_relative_eq!(delta, skin_depth(1.0e6, MU_0, 5.8e7), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn skin_depth_grows_with_temperature() {
+        let cold = skin_depth_at_temperature(1.0e6, MU_0, 5.8e7, 0.00393, 20.0);
+        let hot = skin_depth_at_temperature(1.0e6, MU_0, 5.8e7, 0.00393, 100.0);
+        assert!(hot > cold);
+        // δ ∝ 1/√σ ∝ √(1 + αΔT)
+        assert_relative_eq!(hot / cold, (1.0 + 0.00393 * 80.0_f64).sqrt(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn skin_depth_without_temp_coeff_is_base() {
+        let delta = skin_depth_at_temperature(1.0e9, MU_0, 5.8e7, 0.0, 150.0);
+        assert_relative_eq!(delta, skin_depth(1.0e9, MU_0, 5.8e7), max_relative = 1e-12);
+    }
+
     #[test]
     fn intrinsic_impedance_free_space() {
         let eta = intrinsic_impedance(MU_0, EPSILON_0);
         assert_relative_eq!(eta, ETA_0, max_relative = 1e-6);
     }
 
+    #[test]
+    fn intrinsic_impedance_relative_vacuum_is_exactly_eta_0() {
+        assert_eq!(intrinsic_impedance_relative(1.0, 1.0), ETA_0);
+    }
+
+    #[test]
+    fn intrinsic_impedance_relative_matches_absolute_form() {
+        let eta = intrinsic_impedance_relative(4.0, 2.0);
+        let absolute = intrinsic_impedance(2.0 * MU_0, 4.0 * EPSILON_0);
+        assert_relative_eq!(eta, ETA_0 / 2.0_f64.sqrt(), max_relative = 1e-12);
+        assert_relative_eq!(eta, absolute, max_relative = 1e-9);
+    }
+
     #[test]
     fn intrinsic_impedance_dielectric() {
         // For εr = 4 (glass-like), η = η₀/√εr ≈ 188.4 Ω
@@ -118,6 +118,56 @@ impl Vector3 {
             z: self.z - other.z,
         }
     }
+
+    /// Component-wise minimum with another vector.
+    pub fn component_min(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Component-wise maximum with another vector.
+    pub fn component_max(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Clamp each component into the box [`lo`, `hi`].
+    ///
+    /// Panics if any component of `lo` exceeds that of `hi`.
+    pub fn clamp(&self, lo: &Self, hi: &Self) -> Self {
+        Self {
+            x: self.x.clamp(lo.x, hi.x),
+            y: self.y.clamp(lo.y, hi.y),
+            z: self.z.clamp(lo.z, hi.z),
+        }
+    }
+
+    /// Compact `[x, y, z]` form for bulk transfer.
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Rebuild from `[x, y, z]`.
+    pub fn from_array(a: [f64; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+
+    /// Rotate by `angle` (radians, right-handed) about `axis` using Rodrigues' formula.
+    ///
+    /// v' = v·cos θ + (k̂ × v)·sin θ + k̂(k̂·v)(1 - cos θ). The axis need not be unit length.
+    pub fn rotate_about(&self, axis: &Self, angle: f64) -> Self {
+        let k = axis.normalized();
+        let (sin, cos) = angle.sin_cos();
+        self.scale(cos)
+            .add(&k.cross(self).scale(sin))
+            .add(&k.scale(k.dot(self) * (1.0 - cos)))
+    }
 }
 
 impl std::ops::Add for Vector3 {
@@ -316,6 +366,93 @@ pub fn cylindrical_to_cartesian_vector(v_rho: f64, v_phi: f64, v_z: f64, phi: f6
     }
 }
 
+/// A plane for sampling fields, spanned by two in-plane vectors from an origin.
+///
+/// Grid point (s, t) maps to origin + s·u + t·v. `u` and `v` need not be
+/// unit length or orthogonal, but are usually orthonormal.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ObservationPlane {
+    /// Point at in-plane coordinates (s, t) = (0, 0)
+    pub origin: Cartesian,
+    /// Span vector for s: one unit of s moves by `u` (not normalized)
+    pub u: Vector3,
+    /// Span vector for t: one unit of t moves by `v` (not normalized)
+    pub v: Vector3,
+}
+
+impl ObservationPlane {
+    pub fn new(origin: Cartesian, u: Vector3, v: Vector3) -> Self {
+        Self { origin, u, v }
+    }
+
+    /// The xy-plane at height z, with (s, t) = (x, y).
+    pub fn xy(z: f64) -> Self {
+        Self::new(
+            Cartesian::new(0.0, 0.0, z),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        )
+    }
+
+    /// The xz-plane at offset y, with (s, t) = (x, z).
+    pub fn xz(y: f64) -> Self {
+        Self::new(
+            Cartesian::new(0.0, y, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Unit normal u × v / |u × v|.
+    pub fn normal(&self) -> Vector3 {
+        self.u.cross(&self.v).normalized()
+    }
+
+    /// 3D point at in-plane coordinates (s, t).
+    pub fn point_at(&self, s: f64, t: f64) -> Cartesian {
+        Cartesian::new(
+            self.origin.x + s * self.u.x + t * self.v.x,
+            self.origin.y + s * self.u.y + t * self.v.y,
+            self.origin.z + s * self.u.z + t * self.v.z,
+        )
+    }
+
+    /// Regular grid of points over `s_range` × `t_range`.
+    ///
+    /// # Returns
+    /// (s_values, t_values, points), with points ordered row by row (s varies
+    /// fastest), matching the 2D samplers.
+    pub fn grid(
+        &self,
+        s_range: (f64, f64),
+        t_range: (f64, f64),
+        ns: usize,
+        nt: usize,
+    ) -> (Vec<f64>, Vec<f64>, Vec<Cartesian>) {
+        assert!(ns >= 2 && nt >= 2);
+        let ds = (s_range.1 - s_range.0) / (ns - 1) as f64;
+        let dt = (t_range.1 - t_range.0) / (nt - 1) as f64;
+        let s_vals: Vec<f64> = (0..ns).map(|i| s_range.0 + i as f64 * ds).collect();
+        let t_vals: Vec<f64> = (0..nt).map(|j| t_range.0 + j as f64 * dt).collect();
+        let points = t_vals
+            .iter()
+            .flat_map(|&t| s_vals.iter().map(move |&s| self.point_at(s, t)))
+            .collect();
+        (s_vals, t_vals, points)
+    }
+}
+
+/// Flatten vectors into `[x₀, y₀, z₀, x₁, y₁, z₁, …]`.
+pub fn flatten_vectors(vectors: &[Vector3]) -> Vec<f64> {
+    vectors.iter().flat_map(|v| v.to_array()).collect()
+}
+
+/// Inverse of [`flatten_vectors`]. Panics if the length is not a multiple of 3.
+pub fn unflatten_vectors(flat: &[f64]) -> Vec<Vector3> {
+    assert!(flat.len().is_multiple_of(3), "flat length must be a multiple of 3");
+    flat.chunks_exact(3).map(|c| Vector3::new(c[0], c[1], c[2])).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -428,6 +565,72 @@ mod tests {
         assert_relative_eq!(v.z, -3.0, epsilon = 1e-12);
     }
 
+    #[test]
+    fn vector3_array_roundtrip_is_exact() {
+        let v = Vector3::new(1.0 / 3.0, -2.5e-9, 7.0e12);
+        assert_eq!(v.to_array(), [v.x, v.y, v.z]);
+        assert_eq!(Vector3::from_array(v.to_array()), v);
+    }
+
+    #[test]
+    fn vector3_flatten_roundtrip_preserves_components() {
+        let vs: Vec<Vector3> = (0..5)
+            .map(|i| Vector3::new(i as f64 * 0.1, -(i as f64).sqrt(), (i as f64).exp()))
+            .collect();
+        let flat = flatten_vectors(&vs);
+        assert_eq!(flat.len(), 15);
+        assert_eq!(flat[3..6], vs[1].to_array());
+        assert_eq!(unflatten_vectors(&flat), vs);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unflatten_rejects_ragged_input() {
+        unflatten_vectors(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn vector3_rotate_x_about_z_quarter_turn_gives_y() {
+        let v = Vector3::new(1.0, 0.0, 0.0).rotate_about(&Vector3::new(0.0, 0.0, 2.0), PI / 2.0);
+        assert_relative_eq!(v.x, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(v.y, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(v.z, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn vector3_rotate_preserves_magnitude_and_axis_component() {
+        let v = Vector3::new(1.0, -2.0, 3.0);
+        let axis = Vector3::new(1.0, 1.0, 0.0);
+        let r = v.rotate_about(&axis, 0.7);
+        assert_relative_eq!(r.magnitude(), v.magnitude(), epsilon = 1e-12);
+        let k = axis.normalized();
+        assert_relative_eq!(r.dot(&k), v.dot(&k), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn vector3_component_min_max() {
+        let a = Vector3::new(1.0, -5.0, 3.0);
+        let b = Vector3::new(-2.0, 4.0, 3.5);
+        assert_eq!(a.component_min(&b), Vector3::new(-2.0, -5.0, 3.0));
+        assert_eq!(a.component_max(&b), Vector3::new(1.0, 4.0, 3.5));
+    }
+
+    #[test]
+    fn vector3_clamp_outside_point_to_nearest_bound() {
+        let lo = Vector3::new(-1.0, -1.0, 0.0);
+        let hi = Vector3::new(1.0, 2.0, 5.0);
+        let p = Vector3::new(3.0, -4.0, 2.0).clamp(&lo, &hi);
+        assert_eq!(p, Vector3::new(1.0, -1.0, 2.0));
+    }
+
+    #[test]
+    fn vector3_clamp_inside_point_unchanged() {
+        let lo = Vector3::new(-1.0, -1.0, -1.0);
+        let hi = Vector3::new(1.0, 1.0, 1.0);
+        let p = Vector3::new(0.25, -0.5, 0.9);
+        assert_eq!(p.clamp(&lo, &hi), p);
+    }
+
     // ================================================================
     // Cartesian ↔ Cylindrical
     // ================================================================
@@ -604,4 +807,39 @@ mod tests {
         assert_relative_eq!(v.y, 0.0, epsilon = 1e-12);
         assert_relative_eq!(v.z, 7.0, epsilon = 1e-12);
     }
+
+    // ================================================================
+    // Observation planes
+    // ================================================================
+
+    #[test]
+    fn xy_plane_maps_s_t_to_x_y() {
+        let p = ObservationPlane::xy(2.5).point_at(1.5, -0.5);
+        assert_eq!(p, Cartesian::new(1.5, -0.5, 2.5));
+        let n = ObservationPlane::xy(0.0).normal();
+        assert_relative_eq!(n.z, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn xz_plane_normal_is_minus_y() {
+        let plane = ObservationPlane::xz(0.3);
+        assert_eq!(plane.point_at(1.0, 2.0), Cartesian::new(1.0, 0.3, 2.0));
+        assert_relative_eq!(plane.normal().y, -1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn tilted_plane_points_lie_in_plane() {
+        let u = Vector3::new(1.0, 1.0, 0.0).normalized();
+        let v = Vector3::new(0.0, 0.0, 1.0);
+        let plane = ObservationPlane::new(Cartesian::new(1.0, 2.0, 3.0), u, v);
+        let (s, t, pts) = plane.grid((-1.0, 1.0), (0.0, 2.0), 4, 3);
+        assert_eq!((s.len(), t.len(), pts.len()), (4, 3, 12));
+        let n = plane.normal();
+        for p in &pts {
+            let offset = p.to_vector3() - plane.origin.to_vector3();
+            assert_relative_eq!(offset.dot(&n), 0.0, epsilon = 1e-12);
+        }
+        assert_eq!(pts[1], plane.point_at(s[1], t[0]));
+        assert_eq!(pts[4], plane.point_at(s[0], t[1]));
+    }
 }
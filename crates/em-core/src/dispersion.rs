@@ -0,0 +1,156 @@
+//! Frequency-dependent permittivity of dispersive dielectrics.
+//!
+//! Relaxation models returning the complex relative permittivity
+//! εᵣ(ω) = ε' - jε'' (e^(jωt) convention, ε'' ≥ 0 for a lossy material):
+//! - Debye: single relaxation time
+//! - Cole-Cole: Debye with a broadened relaxation (exponent α)
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Single-pole Debye relaxation.
+///
+/// εᵣ(ω) = ε∞ + (εₛ - ε∞) / (1 + jωτ)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DebyeModel {
+    /// High-frequency (optical) relative permittivity ε∞
+    pub eps_inf: f64,
+    /// Static (DC) relative permittivity εₛ
+    pub eps_static: f64,
+    /// Relaxation time τ (s)
+    pub relaxation_time: f64,
+}
+
+impl DebyeModel {
+    pub fn new(eps_inf: f64, eps_static: f64, relaxation_time: f64) -> Self {
+        Self {
+            eps_inf,
+            eps_static,
+            relaxation_time,
+        }
+    }
+
+    /// Relaxation frequency f_r = 1/(2πτ) (Hz), where ε'' peaks.
+    pub fn relaxation_frequency(&self) -> f64 {
+        1.0 / (2.0 * PI * self.relaxation_time)
+    }
+
+    /// Complex relative permittivity at `frequency` (Hz).
+    pub fn permittivity(&self, frequency: f64) -> Complex64 {
+        let jwt = Complex64::new(0.0, 2.0 * PI * frequency * self.relaxation_time);
+        self.eps_inf + (self.eps_static - self.eps_inf) / (1.0 + jwt)
+    }
+
+    /// Loss tangent tan δ = ε''/ε'.
+    pub fn loss_tangent(&self, frequency: f64) -> f64 {
+        let eps = self.permittivity(frequency);
+        -eps.im / eps.re
+    }
+}
+
+/// Cole-Cole relaxation: a Debye pole broadened by 0 ≤ α < 1.
+///
+/// εᵣ(ω) = ε∞ + (εₛ - ε∞) / (1 + (jωτ)^(1-α)); α = 0 is Debye.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColeColeModel {
+    /// High-frequency (optical) relative permittivity ε∞
+    pub eps_inf: f64,
+    /// Static (DC) relative permittivity εₛ
+    pub eps_static: f64,
+    /// Relaxation time τ (s)
+    pub relaxation_time: f64,
+    /// Broadening exponent α
+    pub alpha: f64,
+}
+
+impl ColeColeModel {
+    pub fn new(eps_inf: f64, eps_static: f64, relaxation_time: f64, alpha: f64) -> Self {
+        Self {
+            eps_inf,
+            eps_static,
+            relaxation_time,
+            alpha,
+        }
+    }
+
+    /// Relaxation frequency f_r = 1/(2πτ) (Hz).
+    pub fn relaxation_frequency(&self) -> f64 {
+        1.0 / (2.0 * PI * self.relaxation_time)
+    }
+
+    /// Complex relative permittivity at `frequency` (Hz).
+    pub fn permittivity(&self, frequency: f64) -> Complex64 {
+        let jwt = Complex64::new(0.0, 2.0 * PI * frequency * self.relaxation_time);
+        let term = if frequency == 0.0 {
+            Complex64::new(0.0, 0.0)
+        } else {
+            jwt.powf(1.0 - self.alpha)
+        };
+        self.eps_inf + (self.eps_static - self.eps_inf) / (1.0 + term)
+    }
+
+    /// Loss tangent tan δ = ε''/ε'.
+    pub fn loss_tangent(&self, frequency: f64) -> f64 {
+        let eps = self.permittivity(frequency);
+        -eps.im / eps.re
+    }
+}
+
+impl From<DebyeModel> for ColeColeModel {
+    fn from(d: DebyeModel) -> Self {
+        Self::new(d.eps_inf, d.eps_static, d.relaxation_time, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Water-like relaxation near 20 °C.
+    fn water() -> DebyeModel {
+        DebyeModel::new(5.2, 80.1, 9.4e-12)
+    }
+
+    #[test]
+    fn debye_real_part_goes_from_static_to_inf() {
+        let d = water();
+        assert_relative_eq!(d.permittivity(0.0).re, 80.1, max_relative = 1e-12);
+        assert_relative_eq!(d.permittivity(1e3).re, 80.1, max_relative = 1e-9);
+        assert_relative_eq!(d.permittivity(1e16).re, 5.2, max_relative = 1e-6);
+        let mid = d.permittivity(d.relaxation_frequency()).re;
+        assert_relative_eq!(mid, (80.1 + 5.2) / 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn debye_loss_peaks_at_relaxation_frequency() {
+        let d = water();
+        let f_r = d.relaxation_frequency();
+        let loss = |f: f64| -d.permittivity(f).im;
+        assert_relative_eq!(loss(f_r), (80.1 - 5.2) / 2.0, max_relative = 1e-12);
+        assert!(loss(f_r) > loss(0.9 * f_r));
+        assert!(loss(f_r) > loss(1.1 * f_r));
+        assert!(loss(0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cole_cole_with_zero_alpha_is_debye() {
+        let d = water();
+        let cc = ColeColeModel::from(d);
+        for &f in &[0.0, 1e8, 1.7e10, 3e11, 1e14] {
+            let (a, b) = (cc.permittivity(f), d.permittivity(f));
+            assert_relative_eq!(a.re, b.re, max_relative = 1e-12);
+            assert_relative_eq!(a.im, b.im, epsilon = 1e-12 * b.norm());
+        }
+    }
+
+    #[test]
+    fn cole_cole_broadening_flattens_loss_peak() {
+        let d = water();
+        let cc = ColeColeModel::new(d.eps_inf, d.eps_static, d.relaxation_time, 0.3);
+        let f_r = d.relaxation_frequency();
+        assert!(-cc.permittivity(f_r).im < -d.permittivity(f_r).im);
+        assert!(-cc.permittivity(100.0 * f_r).im > -d.permittivity(100.0 * f_r).im);
+    }
+}
@@ -0,0 +1,215 @@
+//! Signal-processing helpers shared across the simulation modules.
+//!
+//! Provides spatial spectra of sampled fields for dispersion and
+//! diffraction demos, and phase unwrapping and group delay for swept phase
+//! data.
+
+use num_complex::Complex64;
+use rustfft::FftPlanner;
+use std::f64::consts::PI;
+
+/// Spatial spectrum of a uniformly sampled real field.
+///
+/// Computes the FFT of `values` and returns the two-sided spectrum sorted by
+/// ascending wavenumber. Bin i maps to k = 2π·m/(N·dx) with m running from
+/// -⌊N/2⌋ to ⌈N/2⌉ - 1. Magnitudes are normalized by N, so a sinusoid of
+/// amplitude A on an exact bin shows A/2 at ±k₀.
+///
+/// # Arguments
+/// * `values` - Field samples
+/// * `dx` - Sample spacing (m)
+///
+/// # Returns
+/// (wavenumbers in rad/m, magnitudes), each of length `values.len()`
+pub fn spatial_spectrum(values: &[f64], dx: f64) -> (Vec<f64>, Vec<f64>) {
+    let n = values.len();
+    if n == 0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut buffer: Vec<Complex64> = values.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+
+    let dk = 2.0 * PI / (n as f64 * dx);
+    let half = n / 2;
+    (0..n)
+        .map(|i| {
+            // Shift so the most negative frequency comes first
+            let bin = (i + n - half) % n;
+            let m = if bin >= n.div_ceil(2) {
+                bin as f64 - n as f64
+            } else {
+                bin as f64
+            };
+            (m * dk, buffer[bin].norm() / n as f64)
+        })
+        .unzip()
+}
+
+/// Remove 2π jumps from a sequence of wrapped phases (radians).
+///
+/// Whenever consecutive samples differ by more than π, a multiple of 2π is
+/// added so the step falls within ±π. The first sample is left unchanged.
+pub fn unwrap_phase(phases: &[f64]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(phases.len());
+    let mut offset = 0.0;
+    for (i, &p) in phases.iter().enumerate() {
+        if i > 0 {
+            let step = p - phases[i - 1];
+            offset -= 2.0 * PI * ((step + PI) / (2.0 * PI)).floor();
+        }
+        out.push(p + offset);
+    }
+    out
+}
+
+/// Group delay τ_g = -dφ/dω from a phase-vs-frequency sweep (s).
+///
+/// The phases are unwrapped first, then differentiated with central
+/// differences in ω = 2πf (one-sided at the two ends). Frequencies need not
+/// be uniformly spaced but must be strictly increasing.
+///
+/// # Arguments
+/// * `freqs` - Sweep frequencies (Hz)
+/// * `phases_rad` - Phase at each frequency, possibly wrapped (radians)
+///
+/// # Returns
+/// Group delay at each frequency; empty if fewer than two samples.
+pub fn group_delay(freqs: &[f64], phases_rad: &[f64]) -> Vec<f64> {
+    assert_eq!(freqs.len(), phases_rad.len(), "one phase per frequency");
+    let n = freqs.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let phi = unwrap_phase(phases_rad);
+    (0..n)
+        .map(|i| {
+            let (lo, hi) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            -(phi[hi] - phi[lo]) / (2.0 * PI * (freqs[hi] - freqs[lo]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bin_count_matches_input_length() {
+        for n in [1, 7, 64, 101] {
+            let (k, mag) = spatial_spectrum(&vec![1.0; n], 0.01);
+            assert_eq!(k.len(), n);
+            assert_eq!(mag.len(), n);
+        }
+    }
+
+    #[test]
+    fn wavenumbers_are_ascending_and_centered() {
+        let (k, _) = spatial_spectrum(&[0.0; 8], 0.5);
+        let dk = 2.0 * PI / 4.0;
+        assert!(k.windows(2).all(|w| w[1] > w[0]));
+        assert_relative_eq!(k[0], -4.0 * dk, epsilon = 1e-12);
+        assert_relative_eq!(k[4], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(k[7], 3.0 * dk, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn constant_field_has_only_dc() {
+        let (k, mag) = spatial_spectrum(&[2.5; 16], 0.1);
+        for (ki, m) in k.iter().zip(&mag) {
+            let expected = if *ki == 0.0 { 2.5 } else { 0.0 };
+            assert_relative_eq!(*m, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn pure_sinusoid_peaks_at_its_wavenumber() {
+        let n = 128;
+        let dx = 1e-3;
+        let k0 = 2.0 * PI * 10.0 / (n as f64 * dx);
+        let values: Vec<f64> = (0..n).map(|i| 3.0 * (k0 * i as f64 * dx).cos()).collect();
+        let (k, mag) = spatial_spectrum(&values, dx);
+
+        let positive: Vec<(f64, f64)> = k
+            .iter()
+            .zip(&mag)
+            .filter(|(ki, _)| **ki > 0.0)
+            .map(|(a, b)| (*a, *b))
+            .collect();
+        let (k_peak, m_peak) = positive
+            .iter()
+            .copied()
+            .fold((0.0, 0.0), |best, p| if p.1 > best.1 { p } else { best });
+        assert_relative_eq!(k_peak, k0, max_relative = 1e-12);
+        assert_relative_eq!(m_peak, 1.5, epsilon = 1e-10);
+        for &(ki, m) in &positive {
+            if ki != k_peak {
+                assert!(m < 1e-10, "leakage at k = {ki}: {m}");
+            }
+        }
+    }
+
+    #[test]
+    fn unwrap_linear_ramp_is_monotone_line() {
+        // 0.4 rad/sample over 60 samples crosses ±π several times when wrapped
+        let truth: Vec<f64> = (0..60).map(|i| -1.0 + 0.4 * i as f64).collect();
+        let wrapped: Vec<f64> = truth
+            .iter()
+            .map(|&p| Complex64::from_polar(1.0, p).arg())
+            .collect();
+        assert!(
+            wrapped.windows(2).any(|w| w[1] < w[0]),
+            "input should contain wraps"
+        );
+
+        let unwrapped = unwrap_phase(&wrapped);
+        for (u, t) in unwrapped.iter().zip(&truth) {
+            assert_relative_eq!(*u, *t, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn unwrap_decreasing_phase() {
+        let truth: Vec<f64> = (0..40).map(|i| 2.0 - 0.9 * i as f64).collect();
+        let wrapped: Vec<f64> = truth
+            .iter()
+            .map(|&p| Complex64::from_polar(1.0, p).arg())
+            .collect();
+        let unwrapped = unwrap_phase(&wrapped);
+        for (u, t) in unwrapped.iter().zip(&truth) {
+            assert_relative_eq!(*u, *t, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn unwrap_constant_phase_is_unchanged() {
+        let phases = [2.5; 10];
+        assert_eq!(unwrap_phase(&phases), phases.to_vec());
+        assert!(unwrap_phase(&[]).is_empty());
+    }
+
+    #[test]
+    fn delay_line_has_constant_group_delay() {
+        // φ = -ωτ wraps many times over 1-2 GHz for τ = 3.7 ns
+        let tau = 3.7e-9;
+        let freqs: Vec<f64> = (0..101).map(|i| 1e9 + i as f64 * 1e7).collect();
+        let phases: Vec<f64> = freqs
+            .iter()
+            .map(|&f| Complex64::from_polar(1.0, -2.0 * PI * f * tau).arg())
+            .collect();
+        let tg = group_delay(&freqs, &phases);
+        assert_eq!(tg.len(), freqs.len());
+        for t in tg {
+            assert_relative_eq!(t, tau, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn flat_phase_has_zero_group_delay() {
+        let freqs = [1e6, 2e6, 3.5e6, 7e6];
+        let tg = group_delay(&freqs, &[0.8; 4]);
+        assert!(tg.iter().all(|&t| t == 0.0));
+        assert!(group_delay(&[1e6], &[0.0]).is_empty());
+    }
+}
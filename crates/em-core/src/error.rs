@@ -19,7 +19,44 @@ pub enum EmCoreError {
 
     #[error("numerical convergence failed after {iterations} iterations")]
     ConvergenceFailed { iterations: usize },
+
+    #[error("invalid frequency: {frequency} Hz, expected > 0")]
+    InvalidFrequency { frequency: f64 },
 }
 
 /// Convenience result type for em-core operations.
 pub type EmCoreResult<T> = Result<T, EmCoreError>;
+
+/// Check that a frequency (Hz) is strictly positive and finite.
+///
+/// Rejects zero, negative, NaN and infinite values with
+/// [`EmCoreError::InvalidFrequency`] carrying the offending value.
+pub fn validate_frequency(frequency: f64) -> EmCoreResult<f64> {
+    if frequency > 0.0 && frequency.is_finite() {
+        Ok(frequency)
+    } else {
+        Err(EmCoreError::InvalidFrequency { frequency })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positive_frequency_is_valid() {
+        assert_eq!(validate_frequency(1e9).unwrap(), 1e9);
+    }
+
+    #[test]
+    fn non_positive_frequency_is_rejected() {
+        for f in [0.0, -50.0, f64::NAN, f64::INFINITY] {
+            let err = validate_frequency(f).unwrap_err();
+            assert!(matches!(err, EmCoreError::InvalidFrequency { .. }));
+        }
+        assert_eq!(
+            validate_frequency(-3.0).unwrap_err().to_string(),
+            "invalid frequency: -3 Hz, expected > 0"
+        );
+    }
+}
@@ -2,7 +2,9 @@ pub mod constants;
 pub mod complex;
 pub mod coordinates;
 pub mod units;
+pub mod dsp;
+pub mod dispersion;
 pub mod error;
 
-pub use error::{EmCoreError, EmCoreResult};
+pub use error::{EmCoreError, EmCoreResult, validate_frequency};
 pub use num_complex::Complex64;
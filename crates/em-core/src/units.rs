@@ -102,6 +102,46 @@ pub fn mhz_to_hz(mhz: f64) -> f64 {
     mhz * 1e6
 }
 
+// ============================================================================
+// Frequency / wavelength / wavenumber conversions
+// ============================================================================
+
+/// Wavelength in a medium with phase velocity v_p: λ = v_p / f.
+#[inline]
+pub fn frequency_to_wavelength(frequency_hz: f64, phase_velocity: f64) -> f64 {
+    phase_velocity / frequency_hz
+}
+
+/// Frequency from wavelength in a medium with phase velocity v_p: f = v_p / λ.
+#[inline]
+pub fn wavelength_to_frequency(wavelength: f64, phase_velocity: f64) -> f64 {
+    phase_velocity / wavelength
+}
+
+/// Wavenumber in a medium with phase velocity v_p: k = 2πf / v_p.
+#[inline]
+pub fn frequency_to_wavenumber(frequency_hz: f64, phase_velocity: f64) -> f64 {
+    2.0 * PI * frequency_hz / phase_velocity
+}
+
+/// Frequency from wavenumber in a medium with phase velocity v_p: f = k·v_p / (2π).
+#[inline]
+pub fn wavenumber_to_frequency(wavenumber: f64, phase_velocity: f64) -> f64 {
+    wavenumber * phase_velocity / (2.0 * PI)
+}
+
+/// Angular frequency ω = 2πf.
+#[inline]
+pub fn frequency_to_omega(frequency_hz: f64) -> f64 {
+    2.0 * PI * frequency_hz
+}
+
+/// Frequency f = ω / (2π).
+#[inline]
+pub fn omega_to_frequency(omega: f64) -> f64 {
+    omega / (2.0 * PI)
+}
+
 // ============================================================================
 // Length conversions
 // ============================================================================
@@ -164,6 +204,7 @@ pub fn radians_to_wavelengths(radians: f64) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::C_0;
     use approx::assert_relative_eq;
     use std::f64::consts::PI;
 
@@ -281,6 +322,39 @@ mod tests {
         assert_relative_eq!(hz_to_mhz(100.0e6), 100.0, epsilon = 1e-12);
     }
 
+    // ================================================================
+    // Frequency / wavelength / wavenumber tests
+    // ================================================================
+
+    #[test]
+    fn one_ghz_free_space_wavelength() {
+        let lambda = frequency_to_wavelength(1.0e9, C_0);
+        assert_relative_eq!(lambda, 0.2998, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn wavenumber_is_two_pi_over_wavelength() {
+        let f = 1.0e9;
+        let k = frequency_to_wavenumber(f, C_0);
+        assert_relative_eq!(k, 2.0 * PI / frequency_to_wavelength(f, C_0), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn omega_is_two_pi_f() {
+        assert_relative_eq!(frequency_to_omega(1.0e9), 2.0 * PI * 1.0e9, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn spectrum_roundtrips() {
+        let f = 2.45e9;
+        let vp = C_0 / 1.5;
+        let lambda = frequency_to_wavelength(f, vp);
+        assert_relative_eq!(wavelength_to_frequency(lambda, vp), f, max_relative = 1e-12);
+        let k = frequency_to_wavenumber(f, vp);
+        assert_relative_eq!(wavenumber_to_frequency(k, vp), f, max_relative = 1e-12);
+        assert_relative_eq!(omega_to_frequency(frequency_to_omega(f)), f, max_relative = 1e-12);
+    }
+
     // ================================================================
     // Length conversion tests
     // ================================================================
@@ -5,8 +5,10 @@
 //! - Module 4.2: Charges in adjacent dielectrics
 //! - Module 4.3: Charges above conducting plane (method of images)
 //! - Module 4.4: Charges near conducting sphere (method of images)
+//! - Finite-difference Laplace solver for line cross sections
 
 pub mod point_charges;
 pub mod method_of_images;
 pub mod capacitance;
 pub mod gauss;
+pub mod laplace;
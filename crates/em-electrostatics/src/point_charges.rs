@@ -1,8 +1,10 @@
 //! Electric field and potential computation for systems of point charges.
 //!
-//! Uses Coulomb's law superposition for N point charges in free space.
+//! Uses Coulomb's law superposition for N point charges in free space, plus
+//! the uniform field of an infinite charged plane.
 
-use em_core::coordinates::{Cartesian, Vector3};
+use em_core::coordinates::{Cartesian, ObservationPlane, Vector3};
+use em_vectors::differential_ops::gradient;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -24,6 +26,125 @@ impl PointCharge {
     }
 }
 
+/// An infinite plane carrying a uniform surface charge.
+///
+/// E = σ/(2ε) directed away from the plane on both sides (for σ > 0),
+/// independent of distance.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InfinitePlaneCharge {
+    /// Surface charge density σ (C/m²)
+    pub surface_density: f64,
+    /// Unit normal to the plane (normalized on construction)
+    pub normal: Vector3,
+    /// Any point lying in the plane
+    pub point_on_plane: Cartesian,
+}
+
+impl InfinitePlaneCharge {
+    pub fn new(surface_density: f64, normal: Vector3, point_on_plane: Cartesian) -> Self {
+        Self {
+            surface_density,
+            normal: normal.normalized(),
+            point_on_plane,
+        }
+    }
+
+    /// Signed distance of `point` from the plane along the normal (m).
+    pub fn signed_distance(&self, point: &Cartesian) -> f64 {
+        (point.to_vector3() - self.point_on_plane.to_vector3()).dot(&self.normal)
+    }
+
+    /// Electric field (V/m) at `point` in a medium of permittivity ε (F/m).
+    ///
+    /// Zero on the plane itself, where the field is discontinuous.
+    pub fn field_at(&self, point: &Cartesian, epsilon: f64) -> Vector3 {
+        let side = self.signed_distance(point);
+        if side == 0.0 {
+            return Vector3::zero();
+        }
+        self.normal * (side.signum() * self.surface_density / (2.0 * epsilon))
+    }
+}
+
+/// Builder for point-charge configurations, with presets for common demos.
+///
+/// Presets are centered on the origin and can be extended with
+/// [`ChargeConfig::with_charge`] before calling [`ChargeConfig::build`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChargeConfig {
+    /// Charges in the configuration
+    pub charges: Vec<PointCharge>,
+}
+
+impl ChargeConfig {
+    /// Empty configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a charge q (C) at (x, y, z).
+    pub fn with_charge(mut self, x: f64, y: f64, z: f64, charge: f64) -> Self {
+        self.charges.push(PointCharge::new(x, y, z, charge));
+        self
+    }
+
+    /// Electric dipole: +q at (+s/2, 0, 0) and -q at (-s/2, 0, 0).
+    ///
+    /// Dipole moment p = q·s in the +x direction.
+    pub fn dipole(q: f64, separation: f64) -> Self {
+        let h = separation / 2.0;
+        Self::new().with_charge(h, 0.0, 0.0, q).with_charge(-h, 0.0, 0.0, -q)
+    }
+
+    /// Square quadrupole in the xy-plane.
+    ///
+    /// Alternating ±q on the corners of a square of side d, so both the net
+    /// charge and the dipole moment vanish.
+    pub fn quadrupole(q: f64, d: f64) -> Self {
+        let h = d / 2.0;
+        Self::new()
+            .with_charge(h, h, 0.0, q)
+            .with_charge(-h, h, 0.0, -q)
+            .with_charge(-h, -h, 0.0, q)
+            .with_charge(h, -h, 0.0, -q)
+    }
+
+    /// N equal charges q along the x-axis with the given spacing, centered on the origin.
+    pub fn linear_array(q: f64, n: usize, spacing: f64) -> Self {
+        let x0 = -spacing * (n as f64 - 1.0) / 2.0;
+        (0..n).fold(Self::new(), |cfg, i| {
+            cfg.with_charge(x0 + i as f64 * spacing, 0.0, 0.0, q)
+        })
+    }
+
+    /// N equal charges q equally spaced on a circle of given radius in the xy-plane.
+    ///
+    /// The first charge sits on the +x axis.
+    pub fn ring(q: f64, n: usize, radius: f64) -> Self {
+        (0..n).fold(Self::new(), |cfg, i| {
+            let angle = 2.0 * PI * i as f64 / n as f64;
+            cfg.with_charge(radius * angle.cos(), radius * angle.sin(), 0.0, q)
+        })
+    }
+
+    /// Total charge Σqᵢ (C).
+    pub fn net_charge(&self) -> f64 {
+        self.charges.iter().map(|c| c.charge).sum()
+    }
+
+    /// Dipole moment p = Σqᵢ·rᵢ (C·m) about the origin.
+    pub fn dipole_moment(&self) -> Vector3 {
+        self.charges.iter().fold(Vector3::zero(), |p, c| {
+            p + c.position.to_vector3() * c.charge
+        })
+    }
+
+    /// Finish and return the charges.
+    pub fn build(self) -> Vec<PointCharge> {
+        self.charges
+    }
+}
+
 /// Compute the electric field at a point due to a set of point charges.
 ///
 /// E(r) = Σ (q_i / (4πε₀)) · (r - r_i) / |r - r_i|³
@@ -75,6 +196,23 @@ pub fn electric_potential(charges: &[PointCharge], point: &Cartesian, epsilon: f
     v_total
 }
 
+/// Electric field as -∇V, from central differences of [`electric_potential`].
+///
+/// A numerical cross-check on [`electric_field`]; the two agree to O(h²) at
+/// points much farther than `h` from every charge.
+///
+/// # Arguments
+/// * `h` - Finite-difference step (m)
+pub fn field_from_potential_gradient(
+    charges: &[PointCharge],
+    point: &Cartesian,
+    epsilon: f64,
+    h: f64,
+) -> Vector3 {
+    let potential = |x, y, z| electric_potential(charges, &Cartesian::new(x, y, z), epsilon);
+    -gradient(&potential, point.x, point.y, point.z, h)
+}
+
 /// Sample electric field on a 2D grid at fixed z.
 ///
 /// # Returns
@@ -109,6 +247,31 @@ pub fn sample_field_2d(
     (x_vals, y_vals, fields, potentials)
 }
 
+/// Sample electric field on a grid over an arbitrary plane.
+///
+/// Generalizes [`sample_field_2d`] to xz- or tilted slices; fields are full
+/// 3D vectors at each point of `plane`.
+///
+/// # Returns
+/// (s_values, t_values, field_vectors, potential_values), s varying fastest.
+pub fn sample_field_plane(
+    charges: &[PointCharge],
+    epsilon: f64,
+    plane: &ObservationPlane,
+    s_range: (f64, f64),
+    t_range: (f64, f64),
+    ns: usize,
+    nt: usize,
+) -> (Vec<f64>, Vec<f64>, Vec<Vector3>, Vec<f64>) {
+    let (s_vals, t_vals, points) = plane.grid(s_range, t_range, ns, nt);
+    let fields = points.iter().map(|pt| electric_field(charges, pt, epsilon)).collect();
+    let potentials = points
+        .iter()
+        .map(|pt| electric_potential(charges, pt, epsilon))
+        .collect();
+    (s_vals, t_vals, fields, potentials)
+}
+
 /// Compute electric field lines starting from a charge using streamline tracing.
 ///
 /// # Arguments
@@ -282,6 +445,33 @@ mod tests {
         assert_eq!(vs.len(), 80);
     }
 
+    #[test]
+    fn sample_field_plane_xy_matches_sample_field_2d() {
+        let charges = vec![
+            PointCharge::new(0.2, 0.0, 0.1, 1e-9),
+            PointCharge::new(-0.3, 0.4, -0.2, -2e-9),
+        ];
+        let (xr, yr, z) = ((-1.0, 1.0), (-0.5, 1.5), 0.25);
+        let a = sample_field_2d(&charges, EPSILON_0, xr, yr, z, 7, 5);
+        let plane = ObservationPlane::xy(z);
+        let b = sample_field_plane(&charges, EPSILON_0, &plane, xr, yr, 7, 5);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn sample_field_plane_xz_of_point_charge_is_radial() {
+        let charges = vec![PointCharge::new(0.0, 0.0, 0.0, 1e-9)];
+        let plane = ObservationPlane::xz(0.0);
+        let (xs, zs, fs, _) =
+            sample_field_plane(&charges, EPSILON_0, &plane, (0.5, 1.0), (-1.0, 1.0), 3, 4);
+        for (k, f) in fs.iter().enumerate() {
+            let (x, z) = (xs[k % 3], zs[k / 3]);
+            assert_relative_eq!(f.y, 0.0, epsilon = 1e-9);
+            // E ∥ r̂: E_x·z = E_z·x
+            assert_relative_eq!(f.x * z, f.z * x, max_relative = 1e-9);
+        }
+    }
+
     #[test]
     fn trace_field_lines_from_positive_charge() {
         let charges = vec![PointCharge::new(0.0, 0.0, 0.0, 1e-9)];
@@ -312,4 +502,140 @@ mod tests {
             let _ = dist_to_neg; // just ensure it computes
         }
     }
+
+    // ================================================================
+    // ChargeConfig presets
+    // ================================================================
+
+    #[test]
+    fn dipole_preset_has_zero_net_charge_and_moment_qs() {
+        let (q, s) = (2e-9, 0.04);
+        let cfg = ChargeConfig::dipole(q, s);
+        assert_eq!(cfg.charges.len(), 2);
+        assert_relative_eq!(cfg.net_charge(), 0.0, epsilon = 1e-24);
+        let p = cfg.dipole_moment();
+        assert_relative_eq!(p.x, q * s, max_relative = 1e-12);
+        assert_relative_eq!(p.y, 0.0, epsilon = 1e-24);
+        assert_relative_eq!(p.z, 0.0, epsilon = 1e-24);
+    }
+
+    #[test]
+    fn quadrupole_preset_has_no_monopole_or_dipole() {
+        let cfg = ChargeConfig::quadrupole(1e-9, 0.1);
+        assert_eq!(cfg.charges.len(), 4);
+        assert_relative_eq!(cfg.net_charge(), 0.0, epsilon = 1e-24);
+        assert_relative_eq!(cfg.dipole_moment().magnitude(), 0.0, epsilon = 1e-24);
+    }
+
+    #[test]
+    fn ring_preset_places_charges_at_equal_angles() {
+        let (n, radius) = (6, 0.2);
+        let charges = ChargeConfig::ring(1e-9, n, radius).build();
+        assert_eq!(charges.len(), n);
+        for (i, c) in charges.iter().enumerate() {
+            let cyl = c.position.to_cylindrical();
+            assert_relative_eq!(cyl.rho, radius, max_relative = 1e-12);
+            let expected = em_core::complex::normalize_angle(2.0 * PI * i as f64 / n as f64);
+            assert_relative_eq!(cyl.phi, expected, epsilon = 1e-12);
+        }
+        // Symmetric ring: field at the center cancels
+        let e = electric_field(&charges, &Cartesian::new(0.0, 0.0, 0.0), EPSILON_0);
+        assert_relative_eq!(e.magnitude(), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn linear_array_preset_spacing_is_correct() {
+        let (n, spacing) = (5, 0.03);
+        let charges = ChargeConfig::linear_array(1e-9, n, spacing).build();
+        assert_eq!(charges.len(), n);
+        for pair in charges.windows(2) {
+            let gap = pair[0].position.distance_to(&pair[1].position);
+            assert_relative_eq!(gap, spacing, max_relative = 1e-12);
+        }
+        assert_relative_eq!(charges[2].position.x, 0.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn builder_extends_preset() {
+        let charges = ChargeConfig::dipole(1e-9, 0.1)
+            .with_charge(0.0, 0.5, 0.0, 3e-9)
+            .build();
+        assert_eq!(charges.len(), 3);
+        assert_relative_eq!(charges[2].charge, 3e-9);
+    }
+
+    #[test]
+    fn charged_plane_field_is_uniform() {
+        let sigma = 1e-6;
+        let sheet = InfinitePlaneCharge::new(
+            sigma,
+            Vector3::new(0.0, 0.0, 2.0),
+            Cartesian::new(0.0, 0.0, 0.5),
+        );
+        for p in [
+            Cartesian::new(0.0, 0.0, 0.6),
+            Cartesian::new(3.0, -2.0, 100.0),
+            Cartesian::new(-1.0, 4.0, -7.0),
+        ] {
+            let e = sheet.field_at(&p, EPSILON_0);
+            assert_relative_eq!(e.magnitude(), sigma / (2.0 * EPSILON_0), max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn charged_plane_field_points_away_on_both_sides() {
+        let n = Vector3::new(1.0, 1.0, 0.0).normalized();
+        let sheet = InfinitePlaneCharge::new(1e-6, n, Cartesian::new(0.0, 0.0, 0.0));
+        let above = sheet.field_at(&Cartesian::new(1.0, 1.0, 3.0), EPSILON_0);
+        let below = sheet.field_at(&Cartesian::new(-2.0, 0.5, -1.0), EPSILON_0);
+        assert!(above.dot(&n) > 0.0);
+        assert!(below.dot(&n) < 0.0);
+        let negative = InfinitePlaneCharge::new(-1e-6, n, Cartesian::new(0.0, 0.0, 0.0));
+        assert!(negative.field_at(&Cartesian::new(1.0, 1.0, 3.0), EPSILON_0).dot(&n) < 0.0);
+    }
+
+    #[test]
+    fn opposite_sheets_form_a_capacitor() {
+        let sigma = 2e-7;
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        let plus = InfinitePlaneCharge::new(sigma, z, Cartesian::new(0.0, 0.0, 0.0));
+        let minus = InfinitePlaneCharge::new(-sigma, z, Cartesian::new(0.0, 0.0, 0.01));
+        let total = |p: Cartesian| plus.field_at(&p, EPSILON_0) + minus.field_at(&p, EPSILON_0);
+        let inside = total(Cartesian::new(0.3, 0.2, 0.005));
+        assert_relative_eq!(inside.z, sigma / EPSILON_0, max_relative = 1e-12);
+        assert_relative_eq!(inside.x, 0.0, epsilon = 1e-12);
+        for p in [Cartesian::new(0.0, 0.0, -1.0), Cartesian::new(5.0, 0.0, 0.5)] {
+            assert_relative_eq!(total(p).magnitude(), 0.0, epsilon = 1e-6);
+        }
+    }
+
+    // ================================================================
+    // Field from the potential gradient
+    // ================================================================
+
+    #[test]
+    fn potential_gradient_matches_analytic_field() {
+        let charges = ChargeConfig::new()
+            .with_charge(0.0, 0.0, 0.0, 2e-9)
+            .with_charge(0.5, 0.0, 0.0, -1e-9)
+            .with_charge(0.0, 0.4, -0.3, 3e-9)
+            .with_charge(-0.6, -0.2, 0.1, -2e-9)
+            .build();
+        let points = [
+            Cartesian::new(1.0, 1.0, 1.0),
+            Cartesian::new(0.25, -0.3, 0.2),
+            Cartesian::new(-1.5, 0.7, -0.4),
+            Cartesian::new(0.2, 0.2, 0.0),
+        ];
+        for p in &points {
+            let analytic = electric_field(&charges, p, EPSILON_0);
+            let numeric = field_from_potential_gradient(&charges, p, EPSILON_0, 1e-4);
+            let error = (numeric - analytic).magnitude();
+            assert!(
+                error < 1e-6 * analytic.magnitude(),
+                "at {p:?}: error {error}, |E| {}",
+                analytic.magnitude()
+            );
+        }
+    }
 }
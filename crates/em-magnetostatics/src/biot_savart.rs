@@ -1,10 +1,11 @@
 //! Biot-Savart law for magnetic field computation.
 //!
 //! B(r) = (μ₀/4π) ∫ I dl' × r̂ / r² along a current path.
-//! Implemented via numerical integration over discrete current segments.
+//! Implemented via numerical integration over discrete current segments,
+//! with field energy and inductance from a sampled volume.
 
 use em_core::constants::MU_0;
-use em_core::coordinates::{Cartesian, Vector3};
+use em_core::coordinates::{Cartesian, ObservationPlane, Vector3};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -78,6 +79,84 @@ pub fn b_field_total(segments: &[CurrentSegment], point: &Cartesian) -> Vector3
     total
 }
 
+/// Uniform box of sample cells for volume integrals of the field.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeGrid {
+    /// Minimum corner of the box
+    pub min: Cartesian,
+    /// Maximum corner of the box
+    pub max: Cartesian,
+    /// Number of cells along x, y and z
+    pub cells: [usize; 3],
+}
+
+impl VolumeGrid {
+    pub fn new(min: Cartesian, max: Cartesian, cells: [usize; 3]) -> Self {
+        assert!(cells.iter().all(|&n| n > 0), "need at least one cell per axis");
+        Self { min, max, cells }
+    }
+
+    /// Cell edge lengths (dx, dy, dz) (m).
+    pub fn spacing(&self) -> (f64, f64, f64) {
+        let [nx, ny, nz] = self.cells;
+        (
+            (self.max.x - self.min.x) / nx as f64,
+            (self.max.y - self.min.y) / ny as f64,
+            (self.max.z - self.min.z) / nz as f64,
+        )
+    }
+
+    /// Volume of one cell (m³).
+    pub fn cell_volume(&self) -> f64 {
+        let (dx, dy, dz) = self.spacing();
+        dx * dy * dz
+    }
+
+    /// Centres of all cells, x varying fastest.
+    pub fn cell_centers(&self) -> Vec<Cartesian> {
+        let [nx, ny, nz] = self.cells;
+        let (dx, dy, dz) = self.spacing();
+        let mut points = Vec::with_capacity(nx * ny * nz);
+        for k in 0..nz {
+            for j in 0..ny {
+                for i in 0..nx {
+                    points.push(Cartesian::new(
+                        self.min.x + (i as f64 + 0.5) * dx,
+                        self.min.y + (j as f64 + 0.5) * dy,
+                        self.min.z + (k as f64 + 0.5) * dz,
+                    ));
+                }
+            }
+        }
+        points
+    }
+}
+
+/// Magnetic energy W = ∫ B²/(2μ₀) dV over the grid (J), midpoint rule.
+///
+/// Field energy outside the box is not counted. Filamentary currents make
+/// B² grow as 1/ρ² next to a wire, so cells should not be much finer than
+/// the spacing between neighbouring conductors.
+pub fn magnetic_energy(segments: &[CurrentSegment], grid: &VolumeGrid) -> f64 {
+    let sum_b2: f64 = grid
+        .cell_centers()
+        .iter()
+        .map(|p| {
+            let b = b_field_total(segments, p);
+            b.dot(&b)
+        })
+        .sum();
+    sum_b2 * grid.cell_volume() / (2.0 * MU_0)
+}
+
+/// Inductance L = 2W/I² from the field energy sampled on `grid` (H).
+///
+/// `current` is the current the segments were built with; useful for wire
+/// shapes that have no closed-form inductance.
+pub fn estimated_inductance(segments: &[CurrentSegment], current: f64, grid: &VolumeGrid) -> f64 {
+    2.0 * magnetic_energy(segments, grid) / (current * current)
+}
+
 /// Magnetic field of an infinite straight wire carrying current I
 /// at perpendicular distance ρ from the wire.
 ///
@@ -142,9 +221,30 @@ pub fn sample_b_field_2d(
     (x_vals, y_vals, fields)
 }
 
+/// Sample the B-field on a grid over an arbitrary plane.
+///
+/// Generalizes [`sample_b_field_2d`] to xz- or tilted slices.
+///
+/// # Returns
+/// (s_values, t_values, field_vectors), s varying fastest.
+pub fn sample_b_field_plane(
+    segments: &[CurrentSegment],
+    plane: &ObservationPlane,
+    s_range: (f64, f64),
+    t_range: (f64, f64),
+    ns: usize,
+    nt: usize,
+) -> (Vec<f64>, Vec<f64>, Vec<Vector3>) {
+    let (s_vals, t_vals, points) = plane.grid(s_range, t_range, ns, nt);
+    let fields = points.iter().map(|pt| b_field_total(segments, pt)).collect();
+    (s_vals, t_vals, fields)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::current_loops::CurrentLoop;
+    use crate::solenoid::Solenoid;
     use approx::assert_relative_eq;
 
     #[test]
@@ -243,6 +343,92 @@ mod tests {
         assert_eq!(fs.len(), 25);
     }
 
+    #[test]
+    fn sample_b_field_plane_xy_matches_sample_b_field_2d() {
+        let segs = discretize_wire_z(1.0, 5.0, 100);
+        let a = sample_b_field_2d(&segs, (-1.0, 1.0), (-0.5, 1.0), 0.3, 6, 4);
+        let plane = ObservationPlane::xy(0.3);
+        let b = sample_b_field_plane(&segs, &plane, (-1.0, 1.0), (-0.5, 1.0), 6, 4);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn wire_b_field_in_xz_plane_is_azimuthal() {
+        // In y = 0, φ̂ = ±ŷ: B points along +y for x > 0, -y for x < 0
+        let segs = discretize_wire_z(1.0, 100.0, 2000);
+        let plane = ObservationPlane::xz(0.0);
+        let (xs, _, fs) = sample_b_field_plane(&segs, &plane, (-0.4, 0.4), (-0.5, 0.5), 4, 3);
+        for (k, b) in fs.iter().enumerate() {
+            let x: f64 = xs[k % xs.len()];
+            assert!(b.x.abs() < 1e-6 * b.magnitude());
+            assert!(b.z.abs() < 1e-6 * b.magnitude());
+            assert_eq!(b.y.signum(), x.signum());
+            assert_relative_eq!(b.y.abs(), b_infinite_wire(1.0, x.abs()), max_relative = 1e-2);
+        }
+    }
+
+    fn solenoid_segments(solenoid: &Solenoid) -> Vec<CurrentSegment> {
+        let pitch = solenoid.length / solenoid.turns as f64;
+        (0..solenoid.turns)
+            .flat_map(|i| {
+                let z = -solenoid.length / 2.0 + (i as f64 + 0.5) * pitch;
+                CurrentLoop::at_z(solenoid.radius, solenoid.current, z).discretize(24)
+            })
+            .collect()
+    }
+
+    fn solenoid_grid(solenoid: &Solenoid, cells_xy: usize, cells_z: usize) -> VolumeGrid {
+        let (a, h) = (solenoid.radius, solenoid.length / 2.0);
+        VolumeGrid::new(
+            Cartesian::new(-a, -a, -h),
+            Cartesian::new(a, a, h),
+            [cells_xy, cells_xy, cells_z],
+        )
+    }
+
+    #[test]
+    fn volume_grid_cells_tile_the_box() {
+        let grid = VolumeGrid::new(
+            Cartesian::new(0.0, -1.0, 2.0),
+            Cartesian::new(2.0, 1.0, 3.0),
+            [4, 2, 5],
+        );
+        let centers = grid.cell_centers();
+        assert_eq!(centers.len(), 40);
+        assert_relative_eq!(grid.cell_volume() * 40.0, 4.0, epsilon = 1e-12);
+        assert_relative_eq!(centers[0].x, 0.25, epsilon = 1e-12);
+        assert_relative_eq!(centers[39].z, 2.9, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn solenoid_inductance_from_field_energy() {
+        let solenoid = Solenoid::new(60, 0.3, 1.0, 0.01);
+        let segments = solenoid_segments(&solenoid);
+        let exact = solenoid.inductance();
+        let error = |cells_xy, cells_z| {
+            let grid = solenoid_grid(&solenoid, cells_xy, cells_z);
+            let l = estimated_inductance(&segments, solenoid.current, &grid);
+            (l - exact).abs() / exact
+        };
+        let coarse = error(3, 8);
+        let fine = error(8, 24);
+        assert!(fine < 0.03, "fine-grid error {fine}");
+        assert!(fine < coarse, "coarse {coarse}, fine {fine}");
+    }
+
+    #[test]
+    fn magnetic_energy_scales_as_current_squared() {
+        let grid = VolumeGrid::new(
+            Cartesian::new(-0.5, -0.5, -0.5),
+            Cartesian::new(0.5, 0.5, 0.5),
+            [6, 6, 6],
+        );
+        let w1 = magnetic_energy(&CurrentLoop::new(0.3, 1.0).discretize(32), &grid);
+        let w3 = magnetic_energy(&CurrentLoop::new(0.3, 3.0).discretize(32), &grid);
+        assert!(w1 > 0.0);
+        assert_relative_eq!(w3, 9.0 * w1, max_relative = 1e-12);
+    }
+
     #[test]
     #[should_panic]
     fn b_infinite_wire_zero_distance_panics() {
@@ -1,6 +1,7 @@
 //! Magnetic field of circular current loops.
 //!
-//! Exact on-axis formula plus numerical Biot-Savart for off-axis points.
+//! Exact on-axis formula, exact off-axis field via complete elliptic
+//! integrals, and numerical Biot-Savart for comparison.
 
 use em_core::constants::MU_0;
 use em_core::coordinates::{Cartesian, Vector3};
@@ -53,6 +54,24 @@ impl CurrentLoop {
         self.current * PI * self.radius * self.radius
     }
 
+    /// Magnetic moment vector m = I·π·a²·ẑ.
+    ///
+    /// The normal follows the current by the right-hand rule, so a negative
+    /// (clockwise) current points m along -z.
+    pub fn moment_vector(&self) -> Vector3 {
+        Vector3::new(0.0, 0.0, self.magnetic_moment())
+    }
+
+    /// Torque in a uniform external field: τ = m × B (N·m).
+    pub fn torque_in_field(&self, b_external: Vector3) -> Vector3 {
+        self.moment_vector().cross(&b_external)
+    }
+
+    /// Potential energy in a uniform external field: U = -m·B (J).
+    pub fn potential_energy_in_field(&self, b_external: Vector3) -> f64 {
+        -self.moment_vector().dot(&b_external)
+    }
+
     /// Discretize the loop into segments for numerical Biot-Savart computation.
     pub fn discretize(&self, num_segments: usize) -> Vec<CurrentSegment> {
         assert!(num_segments >= 3);
@@ -78,6 +97,103 @@ impl CurrentLoop {
         let segments = self.discretize(num_segments);
         b_field_total(&segments, point)
     }
+
+    /// Exact B-field at any point off the wire, via complete elliptic integrals.
+    ///
+    /// With α² = a² + ρ² + z² - 2aρ, β² = a² + ρ² + z² + 2aρ, k² = 1 - α²/β²:
+    /// - B_ρ = μ₀I·z / (2πα²βρ) · [(a² + ρ² + z²)·E(k²) - α²·K(k²)]
+    /// - B_z = μ₀I / (2πα²β) · [(a² - ρ² - z²)·E(k²) + α²·K(k²)]
+    ///
+    /// z is measured from the loop plane. Returns zero on the wire itself.
+    pub fn b_field_exact(&self, point: &Cartesian) -> Vector3 {
+        let a = self.radius;
+        let rho = point.x.hypot(point.y);
+        let z = point.z - self.center_z;
+        let r_sq = rho * rho + z * z;
+        let alpha_sq = a * a + r_sq - 2.0 * a * rho;
+        if alpha_sq <= 1e-30 * a * a {
+            return Vector3::zero();
+        }
+        let beta = (a * a + r_sq + 2.0 * a * rho).sqrt();
+        let (k, e) = complete_elliptic_integrals(1.0 - alpha_sq / (beta * beta));
+        let c = MU_0 * self.current / (2.0 * PI * alpha_sq * beta);
+
+        let b_z = c * ((a * a - r_sq) * e + alpha_sq * k);
+        if rho < 1e-12 * a {
+            return Vector3::new(0.0, 0.0, b_z);
+        }
+        let b_rho = c * z / rho * ((a * a + r_sq) * e - alpha_sq * k);
+        Vector3::new(b_rho * point.x / rho, b_rho * point.y / rho, b_z)
+    }
+
+    /// Distance from a point to the nearest point of the wire.
+    fn distance_to_wire(&self, point: &Cartesian) -> f64 {
+        (point.x.hypot(point.y) - self.radius).hypot(point.z - self.center_z)
+    }
+}
+
+/// Complete elliptic integrals (K(m), E(m)) of parameter m = k², 0 ≤ m < 1.
+///
+/// Arithmetic-geometric mean: K = π/(2·AGM(1, √(1-m))) and
+/// E = K·(1 - Σ 2^(n-1)·cₙ²) with c₀² = m.
+fn complete_elliptic_integrals(m: f64) -> (f64, f64) {
+    let (mut a, mut b) = (1.0, (1.0 - m).sqrt());
+    let mut sum = m / 2.0;
+    let mut weight = 0.5;
+    while (a - b).abs() > 1e-15 * a {
+        let c = (a - b) / 2.0;
+        (a, b) = ((a + b) / 2.0, (a * b).sqrt());
+        weight *= 2.0;
+        sum += weight * c * c;
+    }
+    let k = PI / (2.0 * a);
+    (k, k * (1.0 - sum))
+}
+
+/// Trace magnetic field lines of a current loop from seed points.
+///
+/// Uses the exact field ([`CurrentLoop::b_field_exact`]) and a midpoint
+/// (second-order Runge-Kutta) step of fixed length along B̂. A line stops
+/// early if it would come within two steps of the wire or the field vanishes.
+///
+/// # Returns
+/// One polyline per seed, starting at the seed point.
+pub fn trace_loop_field_lines(
+    current_loop: &CurrentLoop,
+    seed_points: &[Cartesian],
+    num_steps: usize,
+    step_size: f64,
+) -> Vec<Vec<Cartesian>> {
+    let direction = |p: &Cartesian| {
+        let b = current_loop.b_field_exact(p);
+        let mag = b.magnitude();
+        (mag > 1e-30 && mag.is_finite()).then(|| b * (1.0 / mag))
+    };
+    let advance = |p: &Cartesian, d: Vector3, h: f64| {
+        Cartesian::new(p.x + h * d.x, p.y + h * d.y, p.z + h * d.z)
+    };
+
+    seed_points
+        .iter()
+        .map(|&seed| {
+            let mut line = Vec::with_capacity(num_steps + 1);
+            line.push(seed);
+            let mut pos = seed;
+            for _ in 0..num_steps {
+                let Some(d1) = direction(&pos) else { break };
+                let Some(d2) = direction(&advance(&pos, d1, step_size / 2.0)) else {
+                    break;
+                };
+                let next = advance(&pos, d2, step_size);
+                if current_loop.distance_to_wire(&next) < 2.0 * step_size {
+                    break;
+                }
+                pos = next;
+                line.push(pos);
+            }
+            line
+        })
+        .collect()
 }
 
 /// Helmholtz coil: two identical coaxial loops separated by their radius.
@@ -185,6 +301,50 @@ mod tests {
         assert_relative_eq!(loop1.magnetic_moment(), expected, max_relative = 1e-10);
     }
 
+    #[test]
+    fn torque_zero_when_aligned_and_max_when_perpendicular() {
+        let loop1 = CurrentLoop::new(0.05, 2.0);
+        let m = loop1.magnetic_moment();
+        let aligned = loop1.torque_in_field(Vector3::new(0.0, 0.0, 0.3));
+        assert_relative_eq!(aligned.magnitude(), 0.0, epsilon = 1e-15);
+        // ẑ × x̂ = ŷ
+        let perp = loop1.torque_in_field(Vector3::new(0.3, 0.0, 0.0));
+        assert_relative_eq!(perp.y, m * 0.3, max_relative = 1e-12);
+        assert_relative_eq!(perp.magnitude(), m * 0.3, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn torque_magnitude_is_m_b_sin_theta() {
+        let loop1 = CurrentLoop::new(0.05, 2.0);
+        let b = 0.3;
+        for theta in [0.2_f64, 0.7, 1.3, 2.5] {
+            let field = Vector3::new(b * theta.sin(), 0.0, b * theta.cos());
+            let tau = loop1.torque_in_field(field).magnitude();
+            let expected = loop1.magnetic_moment() * b * theta.sin();
+            assert_relative_eq!(tau, expected, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn energy_minimal_aligned_and_maximal_antiparallel() {
+        let loop1 = CurrentLoop::new(0.05, 2.0);
+        let mb = loop1.magnetic_moment() * 0.3;
+        let u = |theta: f64| {
+            loop1.potential_energy_in_field(Vector3::new(0.3 * theta.sin(), 0.0, 0.3 * theta.cos()))
+        };
+        assert_relative_eq!(u(0.0), -mb, max_relative = 1e-12);
+        assert_relative_eq!(u(PI), mb, max_relative = 1e-12);
+        assert_relative_eq!(u(PI / 2.0), 0.0, epsilon = 1e-15);
+        assert!([0.3, 1.0, 2.0, 3.0].iter().all(|&t| u(t) > u(0.0) && u(t) < u(PI)));
+    }
+
+    #[test]
+    fn clockwise_current_flips_moment() {
+        let loop1 = CurrentLoop::new(0.05, -1.0);
+        assert!(loop1.moment_vector().z < 0.0);
+        assert!(loop1.potential_energy_in_field(Vector3::new(0.0, 0.0, 1.0)) > 0.0);
+    }
+
     #[test]
     fn numerical_matches_on_axis_formula() {
         let loop1 = CurrentLoop::new(0.1, 1.0);
@@ -195,6 +355,74 @@ mod tests {
         assert_relative_eq!(b_num.x, 0.0, epsilon = 1e-10);
     }
 
+    #[test]
+    fn exact_field_matches_on_axis_formula() {
+        let loop1 = CurrentLoop::at_z(0.1, 2.0, 0.05);
+        for &z in &[-0.3, 0.0, 0.05, 0.12, 0.4] {
+            let b = loop1.b_field_exact(&Cartesian::new(0.0, 0.0, z));
+            assert_relative_eq!(b.z, loop1.b_on_axis(z), max_relative = 1e-12);
+            assert_relative_eq!(b.x, 0.0, epsilon = 1e-20);
+        }
+    }
+
+    #[test]
+    fn exact_field_matches_numerical_off_axis() {
+        let loop1 = CurrentLoop::new(0.1, 1.0);
+        for p in [
+            Cartesian::new(0.05, 0.02, 0.03),
+            Cartesian::new(0.15, -0.1, -0.04),
+            Cartesian::new(-0.3, 0.0, 0.2),
+        ] {
+            let exact = loop1.b_field_exact(&p);
+            let numeric = loop1.b_field_at(&p, 2000);
+            let err = (exact - numeric).magnitude();
+            assert_relative_eq!(err, 0.0, epsilon = 1e-4 * exact.magnitude());
+        }
+    }
+
+    #[test]
+    fn field_line_from_loop_plane_returns_through_loop() {
+        let a = 0.1;
+        let loop1 = CurrentLoop::new(a, 1.0);
+        let seed = Cartesian::new(1.5 * a, 0.0, 0.0);
+        let lines = trace_loop_field_lines(&loop1, &[seed], 2000, 0.005 * a);
+        let line = &lines[0];
+        // Outside the loop B points -z; the line wraps under the wire and
+        // comes back up through the loop plane inside the loop
+        assert!(line[1].z < 0.0);
+        let crossing = line.windows(2).find(|w| w[0].z < 0.0 && w[1].z >= 0.0).unwrap();
+        assert!(crossing[1].x.hypot(crossing[1].y) < a);
+        assert!(line.iter().any(|p| p.z > 0.1 * a) && line.iter().any(|p| p.z < -0.1 * a));
+    }
+
+    #[test]
+    fn field_lines_stay_away_from_wire() {
+        let a = 0.1;
+        let h = 0.005 * a;
+        let loop1 = CurrentLoop::new(a, 1.0);
+        let seeds: Vec<Cartesian> = (1..6)
+            .map(|i| Cartesian::new(a * (0.5 + 0.2 * i as f64), 0.0, 0.0))
+            .collect();
+        for line in trace_loop_field_lines(&loop1, &seeds, 2000, h) {
+            assert!(line.len() > 1);
+            assert!(line.iter().skip(1).all(|p| loop1.distance_to_wire(p) >= 2.0 * h));
+        }
+    }
+
+    #[test]
+    fn field_lines_are_symmetric_about_axis() {
+        let a = 0.1;
+        let loop1 = CurrentLoop::new(a, 1.0);
+        let seeds = [Cartesian::new(1.3 * a, 0.0, 0.02), Cartesian::new(-1.3 * a, 0.0, 0.02)];
+        let lines = trace_loop_field_lines(&loop1, &seeds, 500, 0.01 * a);
+        assert_eq!(lines[0].len(), lines[1].len());
+        for (p, q) in lines[0].iter().zip(&lines[1]) {
+            assert_relative_eq!(p.x, -q.x, epsilon = 1e-12);
+            assert_relative_eq!(p.y, -q.y, epsilon = 1e-12);
+            assert_relative_eq!(p.z, q.z, epsilon = 1e-12);
+        }
+    }
+
     #[test]
     fn discretize_loop_correct_segments() {
         let loop1 = CurrentLoop::new(0.1, 1.0);
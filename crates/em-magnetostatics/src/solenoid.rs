@@ -373,6 +373,7 @@ mod tests {
         let b_in = MU_0 * 1.0 * r / (2.0 * PI * r * r);
         let b_out = MU_0 * 1.0 / (2.0 * PI * r);
         assert_relative_eq!(b_in, b_out, max_relative = 1e-10);
+        assert_relative_eq!(c.b_at_radius(r), b_out, max_relative = 1e-10);
     }
 
     #[test]
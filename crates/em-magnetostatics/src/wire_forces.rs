@@ -1,9 +1,11 @@
 //! Forces between current-carrying conductors.
 //!
 //! Module 5.3: Force between parallel wires and force on a wire in external B-field.
+//! Also covers arbitrary straight segments in 3D (crossed or skew conductors).
 
+use super::biot_savart::CurrentSegment;
 use em_core::constants::MU_0;
-use em_core::coordinates::Vector3;
+use em_core::coordinates::{Cartesian, Vector3};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -94,6 +96,71 @@ impl WireInField {
     }
 }
 
+/// Number of sub-elements used to integrate along the target segment.
+const SEGMENT_FORCE_STEPS: usize = 1000;
+
+/// Exact B-field of a finite straight segment at a point.
+///
+/// B = (μ₀I/4π) · (û × d₁)/|û × d₁|² · (û·d₁/|d₁| - û·d₂/|d₂|),
+/// with dᵢ measured from the segment endpoints to the point.
+fn b_finite_segment(segment: &CurrentSegment, point: &Cartesian) -> Vector3 {
+    let u = segment.dl().normalized();
+    let d1 = point.to_vector3() - segment.start.to_vector3();
+    let d2 = point.to_vector3() - segment.end.to_vector3();
+    let perp = u.cross(&d1);
+    let rho_sq = perp.dot(&perp);
+    if rho_sq < 1e-24 {
+        return Vector3::zero();
+    }
+    let cos_terms = u.dot(&d1) / d1.magnitude() - u.dot(&d2) / d2.magnitude();
+    perp.scale(MU_0 * segment.current / (4.0 * PI * rho_sq) * cos_terms)
+}
+
+/// Force on straight segment `a` from the field of straight segment `b` (N).
+///
+/// F = I_a ∫ dl_a × B_b, using the exact finite-segment field of `b` and a
+/// midpoint rule along `a`. Works for parallel, crossed and skew conductors.
+pub fn force_between_segments(a: &CurrentSegment, b: &CurrentSegment) -> Vector3 {
+    let dl = a.dl().scale(1.0 / SEGMENT_FORCE_STEPS as f64);
+    let start = a.start.to_vector3();
+    let mut total = Vector3::zero();
+    for i in 0..SEGMENT_FORCE_STEPS {
+        let p = start + dl * (i as f64 + 0.5);
+        let b_field = b_finite_segment(b, &Cartesian::new(p.x, p.y, p.z));
+        total = total + dl.cross(&b_field);
+    }
+    total * a.current
+}
+
+/// Pairwise and net forces within a bundle of straight conductors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleForces {
+    /// (i, j, force on wire i from wire j) for every ordered pair i ≠ j
+    pub pair_forces: Vec<(usize, usize, Vector3)>,
+    /// Net force on each wire (N)
+    pub net_forces: Vec<Vector3>,
+}
+
+/// Compute per-pair and net forces for a set of straight current segments.
+pub fn bundle_forces(wires: &[CurrentSegment]) -> BundleForces {
+    let mut pair_forces = Vec::with_capacity(wires.len() * wires.len().saturating_sub(1));
+    let mut net_forces = vec![Vector3::zero(); wires.len()];
+    for (i, a) in wires.iter().enumerate() {
+        for (j, b) in wires.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let f = force_between_segments(a, b);
+            net_forces[i] = net_forces[i] + f;
+            pair_forces.push((i, j, f));
+        }
+    }
+    BundleForces {
+        pair_forces,
+        net_forces,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,6 +279,83 @@ mod tests {
         assert_relative_eq!(tau.magnitude(), 0.0, epsilon = 1e-12);
     }
 
+    // ================================================================
+    // Arbitrary 3D segments
+    // ================================================================
+
+    fn wire_x(y: f64, current: f64, length: f64) -> CurrentSegment {
+        CurrentSegment::new(
+            Cartesian::new(-length / 2.0, y, 0.0),
+            Cartesian::new(length / 2.0, y, 0.0),
+            current,
+        )
+    }
+
+    #[test]
+    fn parallel_segments_match_finite_wire_formula() {
+        // Two aligned wires of length L: F = μ₀I₁I₂/(2πd)·(√(L² + d²) - d)
+        let (l, d) = (1.0, 0.1);
+        let f = force_between_segments(&wire_x(0.0, 2.0, l), &wire_x(d, 3.0, l));
+        let expected = MU_0 * 6.0 / (2.0 * PI * d) * ((l * l + d * d).sqrt() - d);
+        assert_relative_eq!(f.y, expected, max_relative = 1e-5);
+        assert_relative_eq!(f.x, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(f.z, 0.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn long_parallel_segments_approach_per_length_result() {
+        let (l, d) = (200.0, 0.1);
+        let f = force_between_segments(&wire_x(0.0, 1.0, l), &wire_x(d, 1.0, l));
+        let per_length = ParallelWireForce::new(1.0, 1.0, d).force_per_length();
+        assert_relative_eq!(f.y / l, per_length, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn co_directed_segments_attract() {
+        let f = force_between_segments(&wire_x(0.0, 1.0, 1.0), &wire_x(0.05, 1.0, 1.0));
+        assert!(f.y > 0.0, "wire at y=0 should be pulled toward y=0.05");
+    }
+
+    #[test]
+    fn antiparallel_segments_repel() {
+        let f = force_between_segments(&wire_x(0.0, 1.0, 1.0), &wire_x(0.05, -1.0, 1.0));
+        assert!(f.y < 0.0, "wire at y=0 should be pushed away from y=0.05");
+    }
+
+    #[test]
+    fn crossed_wires_produce_non_central_force() {
+        // a along x at z = 0, b along y at z = h: the force on each half of a
+        // is along ±y, perpendicular to the line joining the wires, and the
+        // halves cancel so the net force vanishes
+        let h = 0.05;
+        let origin = Cartesian::new(0.0, 0.0, 0.0);
+        let b = CurrentSegment::new(Cartesian::new(0.0, -1.0, h), Cartesian::new(0.0, 1.0, h), 1.0);
+        let right = CurrentSegment::new(origin, Cartesian::new(1.0, 0.0, 0.0), 1.0);
+        let left = CurrentSegment::new(Cartesian::new(-1.0, 0.0, 0.0), origin, 1.0);
+
+        let f_right = force_between_segments(&right, &b);
+        let f_left = force_between_segments(&left, &b);
+        assert!(f_right.y.abs() > 1e-8);
+        assert_relative_eq!(f_right.x, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(f_right.z, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(f_left.y, -f_right.y, max_relative = 1e-9);
+
+        let whole = wire_x(0.0, 1.0, 2.0);
+        assert!(force_between_segments(&whole, &b).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn bundle_net_force_sums_pairs() {
+        let wires = [wire_x(-0.05, 1.0, 1.0), wire_x(0.0, 1.0, 1.0), wire_x(0.05, 1.0, 1.0)];
+        let bf = bundle_forces(&wires);
+        assert_eq!(bf.pair_forces.len(), 6);
+        // Middle wire is pulled equally both ways; outer wires are pulled inward
+        assert!(bf.net_forces[1].magnitude() < 1e-12);
+        assert!(bf.net_forces[0].y > 0.0);
+        assert!(bf.net_forces[2].y < 0.0);
+        assert_relative_eq!(bf.net_forces[0].y, -bf.net_forces[2].y, max_relative = 1e-9);
+    }
+
     #[test]
     #[should_panic]
     fn parallel_wire_zero_separation_panics() {
@@ -0,0 +1,95 @@
+//! Cylindrical waves radiated by an infinite line source.
+//!
+//! Far from the source the 2D field is E(ρ) = E₀·e^(-jβρ)/√ρ, so power
+//! density falls as 1/ρ and the power crossing any coaxial cylinder is the
+//! same.
+
+use em_core::constants::{C_0, intrinsic_impedance_relative};
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Outgoing cylindrical wave from a line source in a lossless medium.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CylindricalWave {
+    /// Field amplitude at ρ = 1 m (V/m)
+    pub amplitude: f64,
+    /// Frequency (Hz)
+    pub frequency: f64,
+    /// Relative permittivity of the medium
+    pub epsilon_r: f64,
+}
+
+impl CylindricalWave {
+    pub fn new(amplitude: f64, frequency: f64, epsilon_r: f64) -> Self {
+        Self {
+            amplitude,
+            frequency,
+            epsilon_r,
+        }
+    }
+
+    /// Phase constant β = 2πf·√εᵣ/c (rad/m).
+    pub fn beta(&self) -> f64 {
+        2.0 * PI * self.frequency * self.epsilon_r.sqrt() / C_0
+    }
+
+    /// Intrinsic impedance of the medium (Ω).
+    pub fn eta(&self) -> f64 {
+        intrinsic_impedance_relative(self.epsilon_r, 1.0)
+    }
+
+    /// Field phasor E(ρ) = E₀·e^(-jβρ)/√ρ.
+    pub fn field_at(&self, rho: f64) -> Complex64 {
+        Complex64::from_polar(self.amplitude / rho.sqrt(), -self.beta() * rho)
+    }
+
+    /// Time-averaged power density S = |E|²/(2η) (W/m²), ∝ 1/ρ.
+    pub fn power_density(&self, rho: f64) -> f64 {
+        self.field_at(rho).norm_sqr() / (2.0 * self.eta())
+    }
+
+    /// Power per unit source length crossing a cylinder of radius ρ (W/m).
+    ///
+    /// P' = 2πρ·S = π·E₀²/η, independent of ρ.
+    pub fn power_per_unit_length(&self, rho: f64) -> f64 {
+        2.0 * PI * rho * self.power_density(rho)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn amplitude_decays_as_inverse_sqrt_rho() {
+        let w = CylindricalWave::new(10.0, 1e9, 1.0);
+        assert_relative_eq!(w.field_at(1.0).norm(), 10.0, max_relative = 1e-12);
+        assert_relative_eq!(w.field_at(4.0).norm(), 5.0, max_relative = 1e-12);
+        assert_relative_eq!(w.field_at(100.0).norm(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn power_density_falls_as_inverse_rho() {
+        let w = CylindricalWave::new(10.0, 1e9, 2.25);
+        assert_relative_eq!(w.power_density(3.0) / w.power_density(6.0), 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn power_through_cylinder_is_constant() {
+        let w = CylindricalWave::new(10.0, 1e9, 2.25);
+        let expected = PI * 100.0 / w.eta();
+        for &rho in &[0.5, 2.0, 37.0, 1e3] {
+            assert_relative_eq!(w.power_per_unit_length(rho), expected, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn phase_advances_by_beta_per_metre() {
+        let w = CylindricalWave::new(1.0, 300e6, 1.0);
+        let (rho, d) = (2.0, 0.1);
+        let dphi = (w.field_at(rho) / w.field_at(rho + d)).arg();
+        assert_relative_eq!(dphi, w.beta() * d, max_relative = 1e-12);
+    }
+}
@@ -1,8 +1,16 @@
 //! Fresnel coefficients and Snell's law for wave reflection/transmission.
 //!
 //! Handles normal and oblique incidence at planar boundaries between
-//! lossless dielectric media.
-
+//! lossless dielectric media, plus complex Γ and τ at normal incidence on
+//! lossy media. Frequency sweeps accept dispersive (frequency-dependent)
+//! permittivities.
+
+use crate::multilayer::IncidencePolarization;
+use crate::plane_wave::medium_impedance;
+use crate::polarization::PolarizationState;
+use em_core::complex::input_impedance_lossless;
+use em_core::constants::{C_0, EPSILON_0, ETA_0, intrinsic_impedance_relative};
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -26,10 +34,9 @@ impl NormalIncidence {
     /// From relative permittivities (lossless, μᵣ=1).
     /// η = η₀/√εᵣ
     pub fn from_epsilon_r(er1: f64, er2: f64) -> Self {
-        let eta0 = 377.0; // approximate
         Self {
-            eta1: eta0 / er1.sqrt(),
-            eta2: eta0 / er2.sqrt(),
+            eta1: intrinsic_impedance_relative(er1, 1.0),
+            eta2: intrinsic_impedance_relative(er2, 1.0),
         }
     }
 
@@ -54,6 +61,101 @@ impl NormalIncidence {
     }
 }
 
+/// Complex transmission coefficient τ = 2η₂/(η₂ + η₁) for lossy media.
+pub fn tau_complex(eta1: Complex64, eta2: Complex64) -> Complex64 {
+    2.0 * eta2 / (eta2 + eta1)
+}
+
+/// Normal incidence between media with complex intrinsic impedances.
+///
+/// Γ and τ are phasors; 1 + Γ = τ still holds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NormalIncidenceComplex {
+    /// Complex intrinsic impedance of medium 1 (Ω)
+    pub eta1: Complex64,
+    /// Complex intrinsic impedance of medium 2 (Ω)
+    pub eta2: Complex64,
+}
+
+impl NormalIncidenceComplex {
+    pub fn new(eta1: Complex64, eta2: Complex64) -> Self {
+        Self { eta1, eta2 }
+    }
+
+    /// From (εᵣ, μᵣ, tan δ) of each medium at the given frequency (Hz).
+    pub fn from_loss_tangents(
+        medium1: (f64, f64, f64),
+        medium2: (f64, f64, f64),
+        frequency: f64,
+    ) -> Self {
+        let eta = |(er, mr, tan_d)| medium_impedance(er, mr, tan_d, frequency);
+        Self::new(eta(medium1), eta(medium2))
+    }
+
+    /// Reflection coefficient Γ = (η₂ - η₁)/(η₂ + η₁).
+    pub fn gamma(&self) -> Complex64 {
+        (self.eta2 - self.eta1) / (self.eta2 + self.eta1)
+    }
+
+    /// Transmission coefficient τ = 2η₂/(η₂ + η₁).
+    pub fn tau(&self) -> Complex64 {
+        tau_complex(self.eta1, self.eta2)
+    }
+
+    /// Power reflectance |Γ|².
+    pub fn reflectance(&self) -> f64 {
+        self.gamma().norm_sqr()
+    }
+}
+
+/// 1/e penetration depth of the transmitted field into a lossy medium (m).
+///
+/// The field decays as e^(-k₀·n''·s) along the refracted ray, so the depth
+/// below the surface is δ = λ·cos θₜ/(2π·|n''|), reducing to λ/(2π·|n''|) at
+/// normal incidence. Uses the real refraction angle (low-loss approximation).
+///
+/// # Arguments
+/// * `n2` - Complex refractive index n' ± jn'' of the lossy medium
+/// * `wavelength` - Free-space wavelength (m)
+/// * `theta_t` - Transmission angle from the normal (radians)
+///
+/// # Returns
+/// `f64::INFINITY` for a lossless medium.
+pub fn penetration_depth(n2: Complex64, wavelength: f64, theta_t: f64) -> f64 {
+    let kappa = n2.im.abs();
+    if kappa == 0.0 {
+        return f64::INFINITY;
+    }
+    wavelength * theta_t.cos() / (2.0 * PI * kappa)
+}
+
+/// Overall Γ at normal incidence on a stack of lossless, non-magnetic slabs.
+///
+/// Starting from η_out, each slab transforms the wave impedance like a line
+/// section: Z ← η·(Z + jη·tan βl)/(η + jZ·tan βl), with β = 2πf·(η₀/η)/c
+/// (μᵣ = 1, so εᵣ = (η₀/η)²). Then Γ = (Z - η_in)/(Z + η_in).
+///
+/// # Arguments
+/// * `layers` - (η (Ω), thickness (m)) per slab, from the incidence side
+/// * `eta_in` - Intrinsic impedance of the incidence medium (Ω)
+/// * `eta_out` - Intrinsic impedance of the final half-space (Ω)
+/// * `frequency` - Frequency (Hz)
+pub fn multilayer_normal(
+    layers: &[(f64, f64)],
+    eta_in: f64,
+    eta_out: f64,
+    frequency: f64,
+) -> Complex64 {
+    let z = layers
+        .iter()
+        .rev()
+        .fold(Complex64::new(eta_out, 0.0), |z, &(eta, thickness)| {
+            let beta = 2.0 * PI * frequency * (ETA_0 / eta) / C_0;
+            input_impedance_lossless(eta, z, beta * thickness)
+        });
+    (z - eta_in) / (z + eta_in)
+}
+
 /// Oblique incidence at a planar boundary between two lossless dielectrics.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ObliqueIncidence {
@@ -138,6 +240,41 @@ impl ObliqueIncidence {
         Some(num / den)
     }
 
+    /// Perpendicular (TE/s) power reflectance R_⊥ = |Γ_⊥|².
+    pub fn reflectance_perp(&self) -> Option<f64> {
+        self.gamma_perp().map(|g| g * g)
+    }
+
+    /// Parallel (TM/p) power reflectance R_∥ = |Γ_∥|².
+    pub fn reflectance_par(&self) -> Option<f64> {
+        self.gamma_par().map(|g| g * g)
+    }
+
+    /// Perpendicular (TE/s) power transmittance.
+    ///
+    /// T_⊥ = |τ_⊥|² · (n₂cosθₜ)/(n₁cosθᵢ), with τ_⊥ = 1 + Γ_⊥.
+    /// The factor accounts for the different beam cross-section and
+    /// impedance in medium 2, so R_⊥ + T_⊥ = 1.
+    pub fn transmittance_perp(&self) -> Option<f64> {
+        let theta_t = self.theta_t()?;
+        let tau = 1.0 + self.gamma_perp()?;
+        Some(tau * tau * self.power_projection_factor(theta_t))
+    }
+
+    /// Parallel (TM/p) power transmittance.
+    ///
+    /// T_∥ = |τ_∥|² · (n₂cosθₜ)/(n₁cosθᵢ), with τ_∥ = (1 + Γ_∥)·cosθᵢ/cosθₜ.
+    pub fn transmittance_par(&self) -> Option<f64> {
+        let theta_t = self.theta_t()?;
+        let tau = (1.0 + self.gamma_par()?) * self.theta_i.cos() / theta_t.cos();
+        Some(tau * tau * self.power_projection_factor(theta_t))
+    }
+
+    /// (n₂cosθₜ)/(n₁cosθᵢ): ratio of normal power flux per unit field² in medium 2 vs 1.
+    fn power_projection_factor(&self, theta_t: f64) -> f64 {
+        (self.n2() * theta_t.cos()) / (self.n1() * self.theta_i.cos())
+    }
+
     /// Sample reflection coefficients vs angle for visualization.
     pub fn sample_vs_angle(
         er1: f64,
@@ -173,10 +310,82 @@ pub struct FresnelSample {
     pub gamma_par: Vec<f64>,
 }
 
+/// Oblique-incidence power reflectance over frequency for dispersive media.
+///
+/// At each frequency the boundary is rebuilt as an [`ObliqueIncidence`] with
+/// ε_r1 = `er1_fn(f)` and ε_r2 = `er2_fn(f)`, so models such as
+/// [`em_core::dispersion::DebyeModel`] can supply the real part ε'. Loss is
+/// not included. Beyond the critical angle the reflectance is 1.
+///
+/// # Arguments
+/// * `er1_fn`, `er2_fn` - Relative permittivity vs frequency (Hz) on each side
+/// * `theta_i` - Angle of incidence (radians)
+/// * `pol` - Incident polarization
+/// * `freqs` - Frequencies to evaluate (Hz)
+pub fn reflectance_sweep(
+    er1_fn: impl Fn(f64) -> f64,
+    er2_fn: impl Fn(f64) -> f64,
+    theta_i: f64,
+    pol: IncidencePolarization,
+    freqs: &[f64],
+) -> Vec<f64> {
+    freqs
+        .iter()
+        .map(|&f| {
+            let oi = ObliqueIncidence::new(er1_fn(f), er2_fn(f), theta_i);
+            let r = match pol {
+                IncidencePolarization::Perpendicular => oi.reflectance_perp(),
+                IncidencePolarization::Parallel => oi.reflectance_par(),
+            };
+            r.unwrap_or(1.0)
+        })
+        .collect()
+}
+
+/// Fraction of incident power transmitted through a Brewster window.
+///
+/// The wave strikes the er1 → er2 boundary at the Brewster angle. The
+/// x-component of `state` is taken to lie in the plane of incidence (∥, TM)
+/// and the y-component perpendicular to it (⊥, TE). The ∥ part passes
+/// without reflection, so T = (a_x² + a_y²·T_⊥)/(a_x² + a_y²); the relative
+/// phase δ does not affect the power split.
+pub fn brewster_window_transmittance(er1: f64, er2: f64, state: &PolarizationState) -> f64 {
+    let theta_b = ObliqueIncidence::new(er1, er2, 0.0).brewster_angle();
+    let oi = ObliqueIncidence::new(er1, er2, theta_b);
+    let p_par = state.ax * state.ax;
+    let p_perp = state.ay * state.ay;
+    // θ_B never exceeds the critical angle, so both coefficients exist
+    let t_par = oi.transmittance_par().unwrap_or(0.0);
+    let t_perp = oi.transmittance_perp().unwrap_or(0.0);
+    (p_par * t_par + p_perp * t_perp) / (p_par + p_perp)
+}
+
+/// Fraction of normally incident power absorbed by a good conductor: A ≈ 4R_s/η₀.
+///
+/// R_s = Re(η_c) is the surface resistance, with η_c from [`medium_impedance`]
+/// at tan δ = σ/(ωε₀); for σ ≫ ωε₀ this is √(ωμ/(2σ)), μ = μᵣμ₀. Valid while
+/// R_s ≪ η₀; returns 0 for a perfect conductor (σ = ∞).
+pub fn conductor_absorptance(frequency: f64, sigma: f64, mu_r: f64) -> f64 {
+    if sigma.is_infinite() {
+        return 0.0;
+    }
+    let loss_tangent = sigma / (2.0 * PI * frequency * EPSILON_0);
+    let r_s = medium_impedance(1.0, mu_r, loss_tangent, frequency).re;
+    4.0 * r_s / ETA_0
+}
+
+/// Power reflectance of a good conductor at normal incidence from free space.
+///
+/// |Γ|² ≈ 1 - 4R_s/η₀; see [`conductor_absorptance`] for the absorbed fraction.
+pub fn reflection_from_conductor(frequency: f64, sigma: f64, mu_r: f64) -> f64 {
+    1.0 - conductor_absorptance(frequency, sigma, mu_r)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
+    use em_core::constants::MU_0;
 
     // ================================================================
     // Normal incidence
@@ -216,6 +425,98 @@ mod tests {
         assert_relative_eq!(ni.gamma(), -1.0 / 3.0, max_relative = 1e-6);
     }
 
+    #[test]
+    fn normal_from_epsilon_r_uses_exact_eta_0() {
+        let ni = NormalIncidence::from_epsilon_r(1.0, 4.0);
+        assert_eq!(ni.eta1, em_core::constants::ETA_0);
+        assert_relative_eq!(ni.eta2, em_core::constants::ETA_0 / 2.0, max_relative = 1e-15);
+    }
+
+    #[test]
+    fn quarter_wave_slab_matches_at_design_frequency() {
+        // η_slab = √(η_in·η_out), thickness λ_slab/4
+        let (f, eta_out) = (10e9, ETA_0 / 3.0);
+        let eta_slab = (ETA_0 * eta_out).sqrt();
+        let lambda_slab = C_0 / f * eta_slab / ETA_0;
+        let layers = [(eta_slab, lambda_slab / 4.0)];
+        assert!(multilayer_normal(&layers, ETA_0, eta_out, f).norm() < 1e-12);
+        assert!(multilayer_normal(&layers, ETA_0, eta_out, 1.3 * f).norm() > 0.05);
+    }
+
+    #[test]
+    fn half_wave_slab_is_invisible() {
+        let (f, eta_out) = (10e9, ETA_0 / 2.0);
+        let eta_slab = ETA_0 / 5.0;
+        let lambda_slab = C_0 / f * eta_slab / ETA_0;
+        let gamma = multilayer_normal(&[(eta_slab, lambda_slab / 2.0)], ETA_0, eta_out, f);
+        let bare = NormalIncidence::new(ETA_0, eta_out).gamma();
+        assert_relative_eq!(gamma.re, bare, max_relative = 1e-9);
+        assert_relative_eq!(gamma.im, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn empty_stack_is_single_interface() {
+        let gamma = multilayer_normal(&[], 377.0, 120.0, 1e9);
+        assert_eq!(gamma, Complex64::new(NormalIncidence::new(377.0, 120.0).gamma(), 0.0));
+    }
+
+    #[test]
+    fn multilayer_normal_agrees_with_transfer_matrix() {
+        use crate::multilayer::{IncidencePolarization, Layer, multilayer_gamma};
+        let f = 5e9;
+        let slabs = [(ETA_0 / 1.8, 7e-3), (ETA_0 / 3.1, 2.5e-3)];
+        let gamma = multilayer_normal(&slabs, ETA_0, ETA_0 / 2.0, f);
+        let layers: Vec<Layer> = slabs.iter().map(|&(eta, d)| Layer::new(ETA_0 / eta, d)).collect();
+        let pol = IncidencePolarization::Perpendicular;
+        let r = multilayer_gamma(&layers, 1.0, 2.0, C_0 / f, 0.0, pol);
+        assert_relative_eq!(gamma.re, r.re, max_relative = 1e-9);
+        assert_relative_eq!(gamma.im, r.im, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn complex_tau_reduces_to_real_case() {
+        let ni = NormalIncidence::new(377.0, 120.0);
+        let nc =
+            NormalIncidenceComplex::new(Complex64::new(377.0, 0.0), Complex64::new(120.0, 0.0));
+        assert_relative_eq!(nc.tau().re, ni.tau(), max_relative = 1e-12);
+        assert_relative_eq!(nc.tau().im, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(nc.gamma().re, ni.gamma(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn complex_one_plus_gamma_equals_tau() {
+        let nc =
+            NormalIncidenceComplex::new(Complex64::new(377.0, 0.0), Complex64::new(80.0, 35.0));
+        let diff = Complex64::new(1.0, 0.0) + nc.gamma() - nc.tau();
+        assert!(diff.norm() < 1e-12);
+    }
+
+    #[test]
+    fn lossy_medium_gives_phase_shifted_tau() {
+        // Seawater-like: εᵣ = 81, σ = 4 S/m at 1 MHz → η₂ = √(jωμ₀/(σ + jωε))
+        let omega = 2.0 * PI * 1e6;
+        let eps = 81.0 * em_core::constants::EPSILON_0;
+        let eta2 = (Complex64::new(0.0, omega * MU_0) / Complex64::new(4.0, omega * eps)).sqrt();
+        let tau = tau_complex(Complex64::new(ETA_0, 0.0), eta2);
+        assert!(tau.arg().abs() > 0.1, "τ phase = {}", tau.arg());
+        assert!(tau.norm() < 0.1);
+    }
+
+    #[test]
+    fn loss_tangent_constructor_matches_conductivity_form() {
+        let f = 1e6;
+        let omega = 2.0 * PI * f;
+        let eps = 81.0 * em_core::constants::EPSILON_0;
+        let eta2 = (Complex64::new(0.0, omega * MU_0) / Complex64::new(4.0, omega * eps)).sqrt();
+        let nc = NormalIncidenceComplex::from_loss_tangents(
+            (1.0, 1.0, 0.0),
+            (81.0, 1.0, 4.0 / (omega * eps)),
+            f,
+        );
+        assert_relative_eq!(nc.eta1.re, ETA_0, max_relative = 1e-9);
+        assert!((nc.eta2 - eta2).norm() / eta2.norm() < 1e-12);
+    }
+
     // ================================================================
     // Oblique incidence - Snell's law
     // ================================================================
@@ -283,6 +584,61 @@ mod tests {
         assert_relative_eq!(oi.gamma_perp().unwrap(), ni.gamma(), max_relative = 1e-6);
     }
 
+    #[test]
+    fn perp_power_conservation_below_critical() {
+        for (er1, er2) in [(1.0, 2.25), (2.25, 1.0), (1.0, 9.0)] {
+            let theta_max = ObliqueIncidence::new(er1, er2, 0.0)
+                .critical_angle()
+                .unwrap_or(PI / 2.0);
+            for i in 0..40 {
+                let theta = 0.99 * theta_max * i as f64 / 40.0;
+                let oi = ObliqueIncidence::new(er1, er2, theta);
+                let r = oi.reflectance_perp().unwrap();
+                let t = oi.transmittance_perp().unwrap();
+                assert_relative_eq!(r + t, 1.0, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn par_power_conservation_below_critical() {
+        for (er1, er2) in [(1.0, 2.25), (2.25, 1.0), (1.0, 9.0)] {
+            let theta_max = ObliqueIncidence::new(er1, er2, 0.0)
+                .critical_angle()
+                .unwrap_or(PI / 2.0);
+            for i in 0..40 {
+                let theta = 0.99 * theta_max * i as f64 / 40.0;
+                let oi = ObliqueIncidence::new(er1, er2, theta);
+                let r = oi.reflectance_par().unwrap();
+                let t = oi.transmittance_par().unwrap();
+                assert_relative_eq!(r + t, 1.0, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn par_transmittance_is_one_at_brewster() {
+        let oi = ObliqueIncidence::new(1.0, 2.25, 0.0);
+        let at_brewster = ObliqueIncidence::new(1.0, 2.25, oi.brewster_angle());
+        assert_relative_eq!(at_brewster.transmittance_par().unwrap(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn transmittance_at_normal_matches_normal_incidence() {
+        let oi = ObliqueIncidence::new(1.0, 4.0, 0.0);
+        let ni = NormalIncidence::from_epsilon_r(1.0, 4.0);
+        let t = ni.transmittance();
+        assert_relative_eq!(oi.transmittance_perp().unwrap(), t, epsilon = 1e-12);
+        assert_relative_eq!(oi.transmittance_par().unwrap(), t, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn transmittance_none_under_tir() {
+        let oi = ObliqueIncidence::new(2.25, 1.0, PI / 3.0);
+        assert!(oi.transmittance_perp().is_none());
+        assert!(oi.transmittance_par().is_none());
+    }
+
     #[test]
     fn sample_vs_angle_dimensions() {
         let s = ObliqueIncidence::sample_vs_angle(1.0, 4.0, 50);
@@ -290,4 +646,148 @@ mod tests {
         assert_eq!(s.gamma_perp.len(), 50);
         assert_eq!(s.gamma_par.len(), 50);
     }
+
+    // ================================================================
+    // Conductor reflection
+    // ================================================================
+
+    #[test]
+    fn copper_reflects_more_than_99_9_percent_at_microwave() {
+        for f in [1e9, 10e9, 30e9] {
+            let r = reflection_from_conductor(f, 5.8e7, 1.0);
+            assert!(r > 0.999 && r < 1.0, "R = {r} at {f} Hz");
+            assert_relative_eq!(r + conductor_absorptance(f, 5.8e7, 1.0), 1.0, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn conductor_absorption_rises_as_sqrt_frequency() {
+        let a1 = conductor_absorptance(1e9, 5.8e7, 1.0);
+        let a4 = conductor_absorptance(4e9, 5.8e7, 1.0);
+        assert!(a4 > a1);
+        // √f up to the ωε₀/σ ~ 1e-9 correction in the exact η_c
+        assert_relative_eq!(a4 / a1, 2.0, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn perfect_conductor_reflects_everything() {
+        assert_eq!(reflection_from_conductor(10e9, f64::INFINITY, 1.0), 1.0);
+    }
+
+    #[test]
+    fn conductor_absorptance_matches_exact_normal_incidence() {
+        // Exact |Γ|² with complex η_c = (1 + j)R_s vs the 4R_s/η₀ approximation
+        let (f, sigma) = (10e9, 5.8e7);
+        let r_s = (2.0 * PI * f * MU_0 / (2.0 * sigma)).sqrt();
+        let eta_c = Complex64::new(r_s, r_s);
+        let gamma = (eta_c - ETA_0) / (eta_c + ETA_0);
+        let exact = gamma.norm_sqr();
+        assert_relative_eq!(reflection_from_conductor(f, sigma, 1.0), exact, max_relative = 1e-7);
+    }
+
+    // ================================================================
+    // Brewster window
+    // ================================================================
+
+    #[test]
+    fn brewster_window_passes_parallel_polarization() {
+        let t = brewster_window_transmittance(1.0, 2.25, &PolarizationState::linear_x(1.0));
+        assert_relative_eq!(t, 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn brewster_window_reflects_some_perpendicular_polarization() {
+        let t = brewster_window_transmittance(1.0, 2.25, &PolarizationState::linear_y(1.0));
+        assert!(t < 0.9, "T_perp = {t}");
+        let theta_b = ObliqueIncidence::new(1.0, 2.25, 0.0).brewster_angle();
+        let oi = ObliqueIncidence::new(1.0, 2.25, theta_b);
+        assert_relative_eq!(t, 1.0 - oi.reflectance_perp().unwrap(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn brewster_window_45_degree_input_is_intermediate() {
+        let t_perp = brewster_window_transmittance(1.0, 2.25, &PolarizationState::linear_y(1.0));
+        let t45 = brewster_window_transmittance(
+            1.0,
+            2.25,
+            &PolarizationState::linear_at_angle(2.0, PI / 4.0),
+        );
+        assert!(t_perp < t45 && t45 < 1.0);
+        assert_relative_eq!(t45, 0.5 * (1.0 + t_perp), max_relative = 1e-12);
+        // Circular input carries the same power split
+        let t_circ = brewster_window_transmittance(1.0, 2.25, &PolarizationState::rhcp(1.0));
+        assert_relative_eq!(t_circ, t45, max_relative = 1e-12);
+    }
+
+    // ================================================================
+    // Penetration depth
+    // ================================================================
+
+    #[test]
+    fn penetration_depth_normal_incidence() {
+        // Wet soil at 100 MHz: n ≈ 4 - j0.5
+        let lambda = C_0 / 100e6;
+        let d = penetration_depth(Complex64::new(4.0, -0.5), lambda, 0.0);
+        assert_relative_eq!(d, lambda / (2.0 * PI * 0.5), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn penetration_depth_shrinks_with_loss_and_angle() {
+        let lambda = 0.3;
+        let d1 = penetration_depth(Complex64::new(3.0, 0.1), lambda, 0.3);
+        let d2 = penetration_depth(Complex64::new(3.0, 0.4), lambda, 0.3);
+        assert!(d2 < d1);
+        assert_relative_eq!(d1 / d2, 4.0, max_relative = 1e-12);
+        assert!(penetration_depth(Complex64::new(3.0, 0.1), lambda, 0.6) < d1);
+    }
+
+    #[test]
+    fn lossless_medium_penetrates_indefinitely() {
+        assert_eq!(penetration_depth(Complex64::new(1.5, 0.0), 1e-6, 0.2), f64::INFINITY);
+    }
+
+    // ================================================================
+    // Dispersive reflectance sweep
+    // ================================================================
+
+    #[test]
+    fn constant_permittivity_sweep_is_flat() {
+        let freqs = [1e6, 1e8, 1e9, 1e10];
+        let theta = 0.5;
+        let oi = ObliqueIncidence::new(1.0, 4.0, theta);
+        for (pol, expected) in [
+            (IncidencePolarization::Perpendicular, oi.reflectance_perp().unwrap()),
+            (IncidencePolarization::Parallel, oi.reflectance_par().unwrap()),
+        ] {
+            let r = reflectance_sweep(|_| 1.0, |_| 4.0, theta, pol, &freqs);
+            assert_eq!(r.len(), freqs.len());
+            for v in r {
+                assert_relative_eq!(v, expected, max_relative = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn debye_medium_reflectance_falls_through_relaxation() {
+        // Water-like Debye medium: ε' drops from 80 to 5 around ~17 GHz
+        let water = em_core::dispersion::DebyeModel::new(5.0, 80.0, 9.4e-12);
+        let f_r = water.relaxation_frequency();
+        let freqs = [0.01 * f_r, 0.3 * f_r, f_r, 3.0 * f_r, 100.0 * f_r];
+        let r = reflectance_sweep(
+            |_| 1.0,
+            |f| water.permittivity(f).re,
+            0.4,
+            IncidencePolarization::Perpendicular,
+            &freqs,
+        );
+        assert!(r.windows(2).all(|w| w[1] < w[0]), "{r:?}");
+        let low = ObliqueIncidence::new(1.0, 80.0, 0.4).reflectance_perp().unwrap();
+        assert_relative_eq!(r[0], low, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn sweep_beyond_critical_angle_is_total_reflection() {
+        let r = reflectance_sweep(|_| 4.0, |_| 1.0, 1.2, IncidencePolarization::Parallel, &[1e9]);
+        assert_eq!(r, vec![1.0]);
+    }
 }
@@ -5,8 +5,12 @@
 //! - Module 7.3: Polarization (linear, circular, elliptical, Poincaré sphere)
 //! - Module 7.4: Normal incidence on planar boundary (Fresnel, reflection, transmission)
 //! - Module 7.5: Oblique incidence (Snell's law, Brewster angle, TIR, critical angle)
+//! - Cylindrical waves from a line source (2D radiation)
+//! - Multilayer thin-film reflection (characteristic matrix)
 
 pub mod plane_wave;
 pub mod polarization;
 pub mod fresnel;
 pub mod waveguide;
+pub mod cylindrical_wave;
+pub mod multilayer;
@@ -0,0 +1,222 @@
+//! Reflection from thin-film stacks by the characteristic (transfer) matrix.
+//!
+//! Each lossless layer of index n and thickness d contributes
+//!
+//! ```text
+//! M = [ cos δ        j·sin δ / Y ]      δ = 2π·n·d·cos θ / λ
+//!     [ j·Y·sin δ    cos δ       ]
+//! ```
+//!
+//! with tilted admittance Y = n·cos θ (perpendicular/TE) or n / cos θ
+//! (parallel/TM). The stack product applied to [1, Y_sub] gives [B, C] and
+//! Γ = (Y_in·B - C)/(Y_in·B + C).
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Polarization of the incident field relative to the plane of incidence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IncidencePolarization {
+    /// E perpendicular to the plane of incidence (TE/s)
+    Perpendicular,
+    /// E parallel to the plane of incidence (TM/p)
+    Parallel,
+}
+
+/// One homogeneous, lossless film in a multilayer stack.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Layer {
+    /// Refractive index
+    pub n: f64,
+    /// Physical thickness (m)
+    pub thickness: f64,
+}
+
+impl Layer {
+    pub fn new(n: f64, thickness: f64) -> Self {
+        Self { n, thickness }
+    }
+
+    /// Quarter-wave layer of index `n` at the design wavelength (m).
+    pub fn quarter_wave(n: f64, design_wavelength: f64) -> Self {
+        Self::new(n, design_wavelength / (4.0 * n))
+    }
+}
+
+/// cos θ in a medium of index `n`, from the invariant n_in·sin θ_i.
+///
+/// Beyond the critical angle the wave is evanescent and cos θ = -j·√(sin²θ - 1),
+/// the branch that decays away from the boundary.
+fn cos_theta(n: f64, n_sin: f64) -> Complex64 {
+    let c = 1.0 - (n_sin / n).powi(2);
+    if c >= 0.0 {
+        Complex64::new(c.sqrt(), 0.0)
+    } else {
+        Complex64::new(0.0, -(-c).sqrt())
+    }
+}
+
+fn tilted_admittance(n: f64, cos: Complex64, pol: IncidencePolarization) -> Complex64 {
+    match pol {
+        IncidencePolarization::Perpendicular => n * cos,
+        IncidencePolarization::Parallel => n / cos,
+    }
+}
+
+/// Complex reflection coefficient of a multilayer stack.
+///
+/// # Arguments
+/// * `layers` - Films ordered from the incidence side toward the substrate
+/// * `n_in` - Index of the incidence medium
+/// * `n_sub` - Index of the substrate
+/// * `wavelength` - Free-space wavelength (m)
+/// * `theta_i` - Angle of incidence (radians)
+/// * `pol` - Incident polarization
+///
+/// An empty stack reduces to the single-interface Fresnel coefficient.
+pub fn multilayer_gamma(
+    layers: &[Layer],
+    n_in: f64,
+    n_sub: f64,
+    wavelength: f64,
+    theta_i: f64,
+    pol: IncidencePolarization,
+) -> Complex64 {
+    let n_sin = n_in * theta_i.sin();
+    let j = Complex64::new(0.0, 1.0);
+
+    let mut b = Complex64::new(1.0, 0.0);
+    let mut c = tilted_admittance(n_sub, cos_theta(n_sub, n_sin), pol);
+    // Multiply [B, C] by each layer matrix, starting next to the substrate
+    for layer in layers.iter().rev() {
+        let cos = cos_theta(layer.n, n_sin);
+        let y = tilted_admittance(layer.n, cos, pol);
+        let delta = 2.0 * PI * layer.n * layer.thickness * cos / wavelength;
+        let (sin_d, cos_d) = (delta.sin(), delta.cos());
+        (b, c) = (cos_d * b + j * sin_d / y * c, j * y * sin_d * b + cos_d * c);
+    }
+
+    let y_in = tilted_admittance(n_in, cos_theta(n_in, n_sin), pol);
+    (y_in * b - c) / (y_in * b + c)
+}
+
+/// Power reflectance |Γ|² of a multilayer stack; see [`multilayer_gamma`].
+pub fn multilayer_reflectance(
+    layers: &[Layer],
+    n_in: f64,
+    n_sub: f64,
+    wavelength: f64,
+    theta_i: f64,
+    pol: IncidencePolarization,
+) -> f64 {
+    multilayer_gamma(layers, n_in, n_sub, wavelength, theta_i, pol).norm_sqr()
+}
+
+/// Power reflectance |Γ|² of a multilayer stack at each wavelength (m).
+///
+/// Useful for plotting the band of an anti-reflection or mirror coating.
+pub fn multilayer_reflectance_sweep(
+    layers: &[Layer],
+    n_in: f64,
+    n_sub: f64,
+    wavelengths: &[f64],
+    theta_i: f64,
+    pol: IncidencePolarization,
+) -> Vec<f64> {
+    wavelengths
+        .iter()
+        .map(|&wl| multilayer_reflectance(layers, n_in, n_sub, wl, theta_i, pol))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fresnel::ObliqueIncidence;
+    use approx::assert_relative_eq;
+
+    const DESIGN: f64 = 550e-9;
+    const N_GLASS: f64 = 1.5;
+
+    fn ar_coating() -> Vec<Layer> {
+        vec![Layer::quarter_wave(N_GLASS.sqrt(), DESIGN)]
+    }
+
+    #[test]
+    fn quarter_wave_ar_layer_has_minimum_at_design_wavelength() {
+        let wavelengths: Vec<f64> = (0..=40).map(|i| 400e-9 + i as f64 * 7.5e-9).collect();
+        let r = multilayer_reflectance_sweep(
+            &ar_coating(),
+            1.0,
+            N_GLASS,
+            &wavelengths,
+            0.0,
+            IncidencePolarization::Perpendicular,
+        );
+        let i_min = (0..r.len()).min_by(|&a, &b| r[a].total_cmp(&r[b])).unwrap();
+        assert_relative_eq!(wavelengths[i_min], DESIGN, epsilon = 1e-15);
+        assert!(r[i_min] < 1e-20);
+    }
+
+    #[test]
+    fn ar_reflectance_rises_on_both_sides_of_design() {
+        let wavelengths = [0.7 * DESIGN, 0.85 * DESIGN, DESIGN, 1.2 * DESIGN, 1.5 * DESIGN];
+        let r = multilayer_reflectance_sweep(
+            &ar_coating(),
+            1.0,
+            N_GLASS,
+            &wavelengths,
+            0.0,
+            IncidencePolarization::Perpendicular,
+        );
+        assert!(r[0] > r[1] && r[1] > r[2]);
+        assert!(r[4] > r[3] && r[3] > r[2]);
+        // Still better than bare glass (4 %)
+        assert!(r.iter().all(|&v| v < 0.04));
+    }
+
+    #[test]
+    fn empty_stack_is_flat_single_interface_reflectance() {
+        let wavelengths = [400e-9, 550e-9, 700e-9, 1.55e-6];
+        let bare = ((1.0 - N_GLASS) / (1.0 + N_GLASS)).powi(2);
+        let r = multilayer_reflectance_sweep(
+            &[],
+            1.0,
+            N_GLASS,
+            &wavelengths,
+            0.0,
+            IncidencePolarization::Parallel,
+        );
+        for v in r {
+            assert_relative_eq!(v, bare, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn empty_stack_matches_oblique_fresnel() {
+        let theta = 0.6;
+        let oi = ObliqueIncidence::new(1.0, N_GLASS * N_GLASS, theta);
+        let r = |pol| multilayer_reflectance(&[], 1.0, N_GLASS, DESIGN, theta, pol);
+        let perp = r(IncidencePolarization::Perpendicular);
+        let par = r(IncidencePolarization::Parallel);
+        assert_relative_eq!(perp, oi.reflectance_perp().unwrap(), max_relative = 1e-12);
+        assert_relative_eq!(par, oi.reflectance_par().unwrap(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn half_wave_layer_is_absent() {
+        let layer = Layer::new(2.1, DESIGN / (2.0 * 2.1));
+        let pol = IncidencePolarization::Perpendicular;
+        let with = multilayer_reflectance(&[layer], 1.0, N_GLASS, DESIGN, 0.0, pol);
+        let without = multilayer_reflectance(&[], 1.0, N_GLASS, DESIGN, 0.0, pol);
+        assert_relative_eq!(with, without, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn total_internal_reflection_is_complete() {
+        let pol = IncidencePolarization::Parallel;
+        let r = multilayer_reflectance(&[], N_GLASS, 1.0, DESIGN, 1.2, pol);
+        assert_relative_eq!(r, 1.0, max_relative = 1e-12);
+    }
+}
@@ -1,9 +1,12 @@
 //! Plane wave propagation in lossless and lossy media.
 //!
 //! Covers propagation constant γ = α + jβ, intrinsic impedance η,
-//! phase velocity, wavelength, and skin depth.
+//! phase velocity, wavelength, skin depth, and the Poynting vector.
 
+use em_core::complex::physical_sqrt;
 use em_core::constants::{C_0, EPSILON_0, MU_0};
+use em_core::coordinates::Vector3;
+use em_core::{EmCoreResult, validate_frequency};
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -84,14 +87,11 @@ impl Medium {
     }
 
     /// Propagation constant γ = α + jβ = jω√(μ·ε_c)
+    ///
+    /// Computed as √(-ω²μ·ε_c) on the α ≥ 0 branch.
     pub fn propagation_constant(&self, omega: f64) -> Complex64 {
-        let mu = Complex64::new(self.mu(), 0.0);
         let eps_c = self.complex_permittivity(omega);
-        let jw = Complex64::new(0.0, omega);
-        (jw * jw * mu * eps_c).sqrt() // γ = √(-ω²με_c) but we want jω√(με_c)
-        // Actually: γ² = jωμ(σ + jωε) = -ω²με + jωμσ
-        // Let's compute correctly:
-        // γ = sqrt(jωμ(σ + jωε))
+        physical_sqrt(-omega * omega * self.mu() * eps_c, true)
     }
 
     /// Attenuation constant α (Np/m).
@@ -105,10 +105,13 @@ impl Medium {
     }
 
     /// Intrinsic impedance η = √(jωμ/(σ + jωε))
+    ///
+    /// Same value as [`medium_impedance`] with tan δ = σ/(ωε), but kept in the
+    /// conductivity form so that ω = 0 gives η = 0 rather than 0·∞.
     pub fn intrinsic_impedance(&self, omega: f64) -> Complex64 {
         let jwmu = Complex64::new(0.0, omega * self.mu());
         let sigma_plus_jwe = Complex64::new(self.conductivity, omega * self.epsilon());
-        (jwmu / sigma_plus_jwe).sqrt()
+        physical_sqrt(jwmu / sigma_plus_jwe, true)
     }
 
     /// Phase velocity: v_p = ω/β
@@ -132,6 +135,41 @@ impl Medium {
         let a = self.alpha(omega);
         if a.abs() < 1e-30 { f64::INFINITY } else { 1.0 / a }
     }
+
+    /// [`Self::wavelength`], rejecting ω ≤ 0 (the error reports f = ω/2π).
+    pub fn try_wavelength(&self, omega: f64) -> EmCoreResult<f64> {
+        validate_frequency(omega / (2.0 * PI))?;
+        Ok(self.wavelength(omega))
+    }
+
+    /// [`Self::skin_depth`], rejecting ω ≤ 0 (the error reports f = ω/2π).
+    pub fn try_skin_depth(&self, omega: f64) -> EmCoreResult<f64> {
+        validate_frequency(omega / (2.0 * PI))?;
+        Ok(self.skin_depth(omega))
+    }
+}
+
+/// Complex intrinsic impedance from relative material parameters (Ω).
+///
+/// η = √(jωμ/(jωε(1 - j·tan δ))), i.e. the conductivity form with the
+/// equivalent σ = ωε·tan δ. Real η₀√(μᵣ/εᵣ) for tan δ = 0; the phase tends
+/// to 45° as tan δ → ∞.
+///
+/// # Arguments
+/// * `epsilon_r` - Relative permittivity ε'ᵣ
+/// * `mu_r` - Relative permeability
+/// * `loss_tangent` - tan δ = ε''/ε'
+/// * `frequency` - Frequency (Hz)
+pub fn medium_impedance(
+    epsilon_r: f64,
+    mu_r: f64,
+    loss_tangent: f64,
+    frequency: f64,
+) -> Complex64 {
+    let omega = 2.0 * PI * frequency;
+    let we = omega * EPSILON_0 * epsilon_r;
+    let jwmu = Complex64::new(0.0, omega * MU_0 * mu_r);
+    physical_sqrt(jwmu / Complex64::new(we * loss_tangent, we), true)
 }
 
 /// Compute plane wave E and H field magnitudes at distance z from source.
@@ -149,6 +187,22 @@ pub fn poynting_average(e0: f64, alpha: f64, eta: Complex64, z: f64) -> f64 {
     e_z * e_z * eta.re / (2.0 * eta.norm_sqr())
 }
 
+/// Instantaneous Poynting vector S = E × H (W/m²).
+pub fn poynting_vector(e: Vector3, h: Vector3) -> Vector3 {
+    e.cross(&h)
+}
+
+/// Time-averaged Poynting vector S_avg = ½·Re(E × H*) from peak phasors (W/m²).
+pub fn poynting_avg(e_phasor: [Complex64; 3], h_phasor: [Complex64; 3]) -> Vector3 {
+    let [ex, ey, ez] = e_phasor;
+    let [hx, hy, hz] = h_phasor.map(|c| c.conj());
+    Vector3::new(
+        0.5 * (ey * hz - ez * hy).re,
+        0.5 * (ez * hx - ex * hz).re,
+        0.5 * (ex * hy - ey * hx).re,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,6 +225,58 @@ mod tests {
         assert_relative_eq!(eta.im, 0.0, epsilon = 1.0);
     }
 
+    #[test]
+    fn medium_impedance_lossless_is_real() {
+        let eta = medium_impedance(4.0, 2.0, 0.0, 1e9);
+        let expected = em_core::constants::intrinsic_impedance_relative(4.0, 2.0);
+        assert_relative_eq!(eta.re, expected, max_relative = 1e-9);
+        assert_relative_eq!(eta.im, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn medium_impedance_phase_approaches_45_degrees() {
+        let eta = medium_impedance(1.0, 1.0, 1e8, 1e6);
+        assert_relative_eq!(eta.arg(), PI / 4.0, max_relative = 1e-6);
+        // Phase is half the loss angle: ½·atan(tan δ)
+        let eta = medium_impedance(2.5, 1.0, 1.0, 1e9);
+        assert_relative_eq!(eta.arg(), PI / 8.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn medium_impedance_matches_conductivity_form() {
+        let (er, mr, sigma, f) = (10.0, 1.5, 0.3, 50e6);
+        let omega = 2.0 * PI * f;
+        let eps = EPSILON_0 * er;
+        let tan_d = sigma / (omega * eps);
+        let direct = (Complex64::new(0.0, omega * MU_0 * mr) / Complex64::new(sigma, omega * eps))
+            .sqrt();
+        let eta = medium_impedance(er, mr, tan_d, f);
+        assert_relative_eq!(eta.re, direct.re, max_relative = 1e-12);
+        assert_relative_eq!(eta.im, direct.im, max_relative = 1e-12);
+        let m = Medium {
+            epsilon_r: er,
+            mu_r: mr,
+            conductivity: sigma,
+        };
+        assert_relative_eq!(m.intrinsic_impedance(omega).re, direct.re, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn conducting_medium_impedance_is_zero_at_dc() {
+        let eta = Medium::lossy(4.0, 0.01).intrinsic_impedance(0.0);
+        assert_eq!(eta, Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn try_methods_reject_non_positive_omega() {
+        let m = Medium::lossy(4.0, 0.01);
+        assert!(m.try_wavelength(0.0).is_err());
+        assert!(m.try_skin_depth(-1.0).is_err());
+        let omega = 2.0 * PI * 1e8;
+        assert_eq!(m.try_wavelength(omega).unwrap(), m.wavelength(omega));
+        assert_eq!(m.try_skin_depth(omega).unwrap(), m.skin_depth(omega));
+    }
+
     #[test]
     fn free_space_no_attenuation() {
         let m = Medium::free_space();
@@ -254,4 +360,39 @@ mod tests {
         let s = poynting_average(1.0, 0.0, eta, 0.0);
         assert!(s > 0.0);
     }
+
+    #[test]
+    fn poynting_vector_of_x_e_and_y_h_points_along_z() {
+        let e = Vector3::new(3.0, 0.0, 0.0);
+        let h = Vector3::new(0.0, 0.5, 0.0);
+        let s = poynting_vector(e, h);
+        assert_relative_eq!(s.x, 0.0);
+        assert_relative_eq!(s.y, 0.0);
+        assert_relative_eq!(s.z, 1.5, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn poynting_avg_is_half_of_peak() {
+        let zero = Complex64::new(0.0, 0.0);
+        let e0 = 10.0;
+        let h0 = e0 / em_core::constants::ETA_0;
+        // In-phase phasors, arbitrary common phase
+        let phase = Complex64::from_polar(1.0, 0.7);
+        let e = [phase * e0, zero, zero];
+        let h = [zero, phase * h0, zero];
+        let s_avg = poynting_avg(e, h);
+        let s_peak = poynting_vector(Vector3::new(e0, 0.0, 0.0), Vector3::new(0.0, h0, 0.0));
+        assert_relative_eq!(s_avg.z, 0.5 * s_peak.z, max_relative = 1e-12);
+        assert_relative_eq!(s_avg.x, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(s_avg.y, 0.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn poynting_avg_vanishes_for_quadrature_fields() {
+        // E and H 90° apart carry no net power (purely reactive)
+        let zero = Complex64::new(0.0, 0.0);
+        let e = [Complex64::new(1.0, 0.0), zero, zero];
+        let h = [zero, Complex64::new(0.0, 1.0), zero];
+        assert_relative_eq!(poynting_avg(e, h).z, 0.0, epsilon = 1e-15);
+    }
 }
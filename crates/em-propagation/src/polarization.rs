@@ -2,7 +2,10 @@
 //!
 //! Linear, circular, and elliptical polarization states.
 //! Poincaré sphere representation, axial ratio, tilt angle.
+//! Stokes vectors and Mueller matrices for partially polarized waves.
+//! Linear (x, y) ↔ circular (RHCP, LHCP) basis changes.
 
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -161,6 +164,43 @@ impl PolarizationState {
         [s0, s1, s2, s3]
     }
 
+    /// Rebuild a state from measured Stokes parameters `[S₀, S₁, S₂, S₃]`.
+    ///
+    /// Uses the polarized part p = √(S₁² + S₂² + S₃²):
+    /// aₓ = √((p + S₁)/2), a_y = √((p − S₁)/2), δ = atan2(S₃, S₂).
+    /// Any unpolarized fraction is discarded.
+    ///
+    /// # Returns
+    /// `None` if S₀² < S₁² + S₂² + S₃² (unphysical) or if the wave carries no
+    /// polarized power (e.g. fully unpolarized).
+    pub fn from_stokes(stokes: [f64; 4]) -> Option<Self> {
+        let [s0, s1, s2, s3] = stokes;
+        let sv = StokesVector::new(s0, s1, s2, s3);
+        let p = sv.polarized_intensity();
+        if !sv.is_physical() || p <= 1e-15 * s0 {
+            return None;
+        }
+        let ax = ((p + s1) / 2.0).max(0.0).sqrt();
+        let ay = ((p - s1) / 2.0).max(0.0).sqrt();
+        Some(Self::new(ax, ay, s3.atan2(s2)))
+    }
+
+    /// Build a state from the complex phasors E_x and E_y.
+    ///
+    /// aₓ = |E_x|, a_y = |E_y|, δ = ∠E_y − ∠E_x wrapped to (−π, π]. The
+    /// absolute phase is discarded; δ is 0 if either component vanishes.
+    pub fn from_phasors(ex: Complex64, ey: Complex64) -> Self {
+        Self::new(ex.norm(), ey.norm(), (ey * ex.conj()).arg())
+    }
+
+    /// Jones vector [aₓ, a_y·e^(jδ)], referenced to the phase of E_x.
+    pub fn to_jones(&self) -> [Complex64; 2] {
+        [
+            Complex64::new(self.ax, 0.0),
+            Complex64::from_polar(self.ay, self.delta),
+        ]
+    }
+
     /// Normalized Poincaré sphere coordinates.
     pub fn poincare_point(&self) -> [f64; 3] {
         let [s0, s1, s2, s3] = self.stokes_parameters();
@@ -170,6 +210,157 @@ impl PolarizationState {
             [s1 / s0, s2 / s0, s3 / s0]
         }
     }
+
+    /// Geometric similarity of two polarization ellipses in [0, 1].
+    ///
+    /// With 2ψ = atan2(S₂, S₁) (tilt) and 2χ = asin(S₃/S₀) (ellipticity),
+    /// the score is the product of
+    /// - axial-ratio closeness 1 - ||2χ₁| - |2χ₂||/(π/2),
+    /// - tilt agreement 1 - w·(1 - cos Δ2ψ)/2, with w = cos 2χ₁·cos 2χ₂
+    ///   so the tilt of a near-circular ellipse hardly counts,
+    /// - handedness agreement 1 - |sin 2χ₁·sin 2χ₂| when the senses differ.
+    ///
+    /// Unlike the power-based polarization loss factor, amplitude and
+    /// absolute phase are ignored and a shape mismatch alone lowers the score.
+    pub fn ellipse_similarity(&self, other: &Self) -> f64 {
+        let [s1a, s2a, s3a] = self.poincare_point();
+        let [s1b, s2b, s3b] = other.poincare_point();
+        let (chi2_a, chi2_b) = (s3a.clamp(-1.0, 1.0).asin(), s3b.clamp(-1.0, 1.0).asin());
+
+        let axial = 1.0 - (chi2_a.abs() - chi2_b.abs()).abs() / (PI / 2.0);
+        let d_tilt = s2a.atan2(s1a) - s2b.atan2(s1b);
+        let weight = chi2_a.cos() * chi2_b.cos();
+        let tilt = 1.0 - weight * (1.0 - d_tilt.cos()) / 2.0;
+        let hand = if s3a * s3b < 0.0 { 1.0 - (s3a * s3b).abs() } else { 1.0 };
+
+        (axial * tilt * hand).clamp(0.0, 1.0)
+    }
+}
+
+/// Split linear phasors (E_x, E_y) into circular components (E_R, E_L).
+///
+/// Uses the unit vectors ê_R = (x̂ - jŷ)/√2 and ê_L = (x̂ + jŷ)/√2, matching
+/// [`PolarizationState::rhcp`] (δ = -π/2), so E_R = (E_x + jE_y)/√2 and
+/// E_L = (E_x - jE_y)/√2. The transform is unitary: |E_R|² + |E_L|² equals
+/// |E_x|² + |E_y|².
+pub fn to_circular_basis(ex: Complex64, ey: Complex64) -> (Complex64, Complex64) {
+    let j = Complex64::new(0.0, 1.0);
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    ((ex + j * ey) * s, (ex - j * ey) * s)
+}
+
+/// Recombine circular components (E_R, E_L) into linear phasors (E_x, E_y).
+///
+/// Inverse of [`to_circular_basis`]: E_x = (E_R + E_L)/√2, E_y = -j(E_R - E_L)/√2.
+pub fn from_circular_basis(er: Complex64, el: Complex64) -> (Complex64, Complex64) {
+    let j = Complex64::new(0.0, 1.0);
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    ((er + el) * s, -j * (er - el) * s)
+}
+
+/// A 4×4 Mueller matrix acting on Stokes vectors.
+pub type MuellerMatrix = [[f64; 4]; 4];
+
+/// Stokes vector (S₀, S₁, S₂, S₃) of a possibly partially polarized wave.
+///
+/// Physical states satisfy S₀² ≥ S₁² + S₂² + S₃², with equality for fully
+/// polarized waves. Uses the same convention as
+/// [`PolarizationState::stokes_parameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StokesVector {
+    /// Total intensity S₀
+    pub s0: f64,
+    /// Horizontal vs vertical linear preference S₁
+    pub s1: f64,
+    /// +45° vs -45° linear preference S₂
+    pub s2: f64,
+    /// Circular preference S₃
+    pub s3: f64,
+}
+
+impl StokesVector {
+    pub fn new(s0: f64, s1: f64, s2: f64, s3: f64) -> Self {
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Completely unpolarized wave of the given intensity.
+    pub fn unpolarized(intensity: f64) -> Self {
+        Self::new(intensity, 0.0, 0.0, 0.0)
+    }
+
+    /// Fully polarized Stokes vector of a polarization state.
+    pub fn from_state(state: &PolarizationState) -> Self {
+        let [s0, s1, s2, s3] = state.stokes_parameters();
+        Self::new(s0, s1, s2, s3)
+    }
+
+    /// Total intensity S₀.
+    pub fn intensity(&self) -> f64 {
+        self.s0
+    }
+
+    /// Polarized intensity √(S₁² + S₂² + S₃²).
+    pub fn polarized_intensity(&self) -> f64 {
+        (self.s1 * self.s1 + self.s2 * self.s2 + self.s3 * self.s3).sqrt()
+    }
+
+    /// Degree of polarization DOP = √(S₁² + S₂² + S₃²)/S₀ ∈ [0, 1].
+    ///
+    /// Returns 0 for zero intensity.
+    pub fn degree_of_polarization(&self) -> f64 {
+        if self.s0.abs() < 1e-15 {
+            0.0
+        } else {
+            self.polarized_intensity() / self.s0
+        }
+    }
+
+    /// Does the vector satisfy S₀² ≥ S₁² + S₂² + S₃² (within rounding)?
+    pub fn is_physical(&self) -> bool {
+        self.s0 >= 0.0 && self.polarized_intensity() <= self.s0 * (1.0 + 1e-12)
+    }
+
+    /// Apply a Mueller matrix: S' = M·S.
+    pub fn apply_mueller(&self, m: MuellerMatrix) -> Self {
+        let v = [self.s0, self.s1, self.s2, self.s3];
+        let out: [f64; 4] = std::array::from_fn(|i| (0..4).map(|j| m[i][j] * v[j]).sum());
+        Self::new(out[0], out[1], out[2], out[3])
+    }
+
+    /// Pass through a cascade of optical elements, in order of propagation.
+    pub fn apply_cascade(&self, elements: &[MuellerMatrix]) -> Self {
+        elements.iter().fold(*self, |s, &m| s.apply_mueller(m))
+    }
+}
+
+/// Mueller matrix of an ideal linear polarizer with transmission axis at θ from x.
+pub fn mueller_linear_polarizer(theta: f64) -> MuellerMatrix {
+    let c = (2.0 * theta).cos();
+    let s = (2.0 * theta).sin();
+    [
+        [0.5, 0.5 * c, 0.5 * s, 0.0],
+        [0.5 * c, 0.5 * c * c, 0.5 * c * s, 0.0],
+        [0.5 * s, 0.5 * c * s, 0.5 * s * s, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]
+}
+
+/// Mueller matrix of an ideal linear retarder.
+///
+/// # Arguments
+/// * `fast_axis` - Fast-axis angle from x (radians)
+/// * `retardance` - Phase retardance (radians), π/2 for a quarter-wave plate
+pub fn mueller_retarder(fast_axis: f64, retardance: f64) -> MuellerMatrix {
+    let c = (2.0 * fast_axis).cos();
+    let s = (2.0 * fast_axis).sin();
+    let cd = retardance.cos();
+    let sd = retardance.sin();
+    [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, c * c + s * s * cd, c * s * (1.0 - cd), -s * sd],
+        [0.0, c * s * (1.0 - cd), s * s + c * c * cd, c * sd],
+        [0.0, s * sd, -c * sd, cd],
+    ]
 }
 
 fn normalize_angle(a: f64) -> f64 {
@@ -265,6 +456,37 @@ mod tests {
         assert_relative_eq!(s3, 0.0, epsilon = 1e-12);
     }
 
+    #[test]
+    fn identical_ellipses_are_fully_similar() {
+        let p = PolarizationState::new(2.0, 1.2, PI / 5.0);
+        assert_relative_eq!(p.ellipse_similarity(&p), 1.0, epsilon = 1e-12);
+        // Scaling the amplitude does not change the shape
+        let q = PolarizationState::new(4.0, 2.4, PI / 5.0);
+        assert_relative_eq!(p.ellipse_similarity(&q), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn handedness_flip_lowers_similarity() {
+        let left = PolarizationState::new(1.0, 0.8, PI / 2.5);
+        let right = PolarizationState::new(1.0, 0.8, -PI / 2.5);
+        assert_eq!(left.axial_ratio(), right.axial_ratio());
+        assert!(left.ellipse_similarity(&right) < 0.5);
+        let circ = PolarizationState::rhcp(1.0).ellipse_similarity(&PolarizationState::lhcp(1.0));
+        assert_relative_eq!(circ, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn small_tilt_difference_is_highly_similar() {
+        let a = PolarizationState::linear_at_angle(1.0, 0.3);
+        let b = PolarizationState::linear_at_angle(1.0, 0.3 + 5f64.to_radians());
+        let score = a.ellipse_similarity(&b);
+        assert!(score > 0.95 && score < 1.0, "score = {score}");
+        // Symmetric, and orthogonal linear states share no orientation
+        assert_relative_eq!(score, b.ellipse_similarity(&a), epsilon = 1e-12);
+        let c = PolarizationState::linear_at_angle(1.0, 0.3 + PI / 2.0);
+        assert_relative_eq!(a.ellipse_similarity(&c), 0.0, epsilon = 1e-12);
+    }
+
     #[test]
     fn trace_ellipse_dimensions() {
         let p = PolarizationState::new(2.0, 1.0, PI / 4.0);
@@ -289,4 +511,176 @@ mod tests {
         // For linear along x (ay=0), tilt is 0
         assert_relative_eq!(p.tilt_angle(), 0.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn from_phasors_one_and_minus_j_is_rhcp() {
+        let (ex, ey) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, -1.0));
+        let p = PolarizationState::from_phasors(ex, ey);
+        assert_eq!(p.polarization_type(), PolarizationType::Circular);
+        assert_eq!(p.rotation_sense(), RotationSense::RightHand);
+        let rhcp = PolarizationState::rhcp(1.0);
+        assert_relative_eq!(p.delta, rhcp.delta, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_phasors_equal_real_is_linear_45() {
+        let e = Complex64::new(0.7, 0.0);
+        let p = PolarizationState::from_phasors(e, e);
+        assert_eq!(p.polarization_type(), PolarizationType::Linear);
+        assert_relative_eq!(p.tilt_angle(), PI / 4.0, max_relative = 1e-10);
+    }
+
+    #[test]
+    fn from_phasors_ignores_common_phase() {
+        let rot = Complex64::from_polar(1.0, 2.5);
+        let ex = Complex64::new(2.0, 0.0);
+        let ey = Complex64::from_polar(1.0, 0.4);
+        let a = PolarizationState::from_phasors(ex, ey);
+        let b = PolarizationState::from_phasors(ex * rot, ey * rot);
+        assert_relative_eq!(a.delta, b.delta, epsilon = 1e-12);
+        assert_relative_eq!(a.delta, 0.4, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn jones_round_trip() {
+        let p = PolarizationState::new(1.3, 0.6, -2.1);
+        let [jx, jy] = p.to_jones();
+        let q = PolarizationState::from_phasors(jx, jy);
+        assert_relative_eq!(q.ax, p.ax, max_relative = 1e-12);
+        assert_relative_eq!(q.ay, p.ay, max_relative = 1e-12);
+        assert_relative_eq!(q.delta, p.delta, max_relative = 1e-12);
+        let back = q.to_jones();
+        assert_relative_eq!(back[1].re, jy.re, max_relative = 1e-12);
+        assert_relative_eq!(back[1].im, jy.im, max_relative = 1e-12);
+    }
+
+    // ================================================================
+    // Linear ↔ circular basis
+    // ================================================================
+
+    #[test]
+    fn rhcp_is_purely_right_handed() {
+        let [ex, ey] = PolarizationState::rhcp(1.0).to_jones();
+        let (er, el) = to_circular_basis(ex, ey);
+        assert_relative_eq!(er.norm(), 2f64.sqrt(), max_relative = 1e-12);
+        assert!(el.norm() < 1e-12);
+        let [ex, ey] = PolarizationState::lhcp(1.0).to_jones();
+        let (er, el) = to_circular_basis(ex, ey);
+        assert!(er.norm() < 1e-12);
+        assert_relative_eq!(el.norm(), 2f64.sqrt(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn linear_x_splits_equally_between_r_and_l() {
+        let (er, el) = to_circular_basis(Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+        assert_relative_eq!(er.norm_sqr(), 0.5, max_relative = 1e-12);
+        assert_relative_eq!(el.norm_sqr(), 0.5, max_relative = 1e-12);
+        assert_relative_eq!((er - el).norm(), 0.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn circular_basis_round_trips_and_preserves_power() {
+        let ex = Complex64::new(0.3, -1.2);
+        let ey = Complex64::from_polar(0.8, 2.1);
+        let (er, el) = to_circular_basis(ex, ey);
+        assert_relative_eq!(
+            er.norm_sqr() + el.norm_sqr(),
+            ex.norm_sqr() + ey.norm_sqr(),
+            max_relative = 1e-12
+        );
+        let (bx, by) = from_circular_basis(er, el);
+        assert_relative_eq!((bx - ex).norm(), 0.0, epsilon = 1e-15);
+        assert_relative_eq!((by - ey).norm(), 0.0, epsilon = 1e-15);
+    }
+
+    // ================================================================
+    // Stokes vectors and Mueller matrices
+    // ================================================================
+
+    #[test]
+    fn stokes_from_state_is_fully_polarized() {
+        let p = PolarizationState::new(2.0, 1.0, PI / 3.0);
+        let s = StokesVector::from_state(&p);
+        assert_relative_eq!(s.degree_of_polarization(), 1.0, max_relative = 1e-10);
+        assert!(s.is_physical());
+    }
+
+    #[test]
+    fn unpolarized_has_zero_dop() {
+        let s = StokesVector::unpolarized(2.0);
+        assert_relative_eq!(s.degree_of_polarization(), 0.0, epsilon = 1e-15);
+        assert!(s.is_physical());
+        assert!(!StokesVector::new(1.0, 1.0, 1.0, 0.0).is_physical());
+    }
+
+    #[test]
+    fn from_stokes_round_trips_a_state() {
+        let p = PolarizationState::new(2.0, 0.7, -1.1);
+        let back = PolarizationState::from_stokes(p.stokes_parameters()).unwrap();
+        assert_relative_eq!(back.ax, p.ax, max_relative = 1e-12);
+        assert_relative_eq!(back.ay, p.ay, max_relative = 1e-12);
+        assert_relative_eq!(back.delta, p.delta, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_stokes_rejects_unpolarized_and_unphysical() {
+        assert!(PolarizationState::from_stokes([1.0, 0.0, 0.0, 0.0]).is_none());
+        assert!(PolarizationState::from_stokes([1.0, 1.0, 1.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn from_stokes_rebuilds_rhcp() {
+        let rhcp = PolarizationState::rhcp(1.5);
+        let back = PolarizationState::from_stokes(rhcp.stokes_parameters()).unwrap();
+        assert_eq!(back.polarization_type(), PolarizationType::Circular);
+        assert_eq!(back.rotation_sense(), rhcp.rotation_sense());
+        assert_relative_eq!(back.ax, 1.5, max_relative = 1e-12);
+        assert_relative_eq!(back.delta, -PI / 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn polarizer_halves_unpolarized_intensity() {
+        let s = StokesVector::unpolarized(1.0).apply_mueller(mueller_linear_polarizer(0.3));
+        assert_relative_eq!(s.intensity(), 0.5, max_relative = 1e-12);
+        assert_relative_eq!(s.degree_of_polarization(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn crossed_polarizers_block_everything() {
+        let theta = PI / 5.0;
+        let s = StokesVector::unpolarized(1.0).apply_cascade(&[
+            mueller_linear_polarizer(theta),
+            mueller_linear_polarizer(theta + PI / 2.0),
+        ]);
+        assert_relative_eq!(s.intensity(), 0.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn malus_law_through_two_polarizers() {
+        let angle = PI / 6.0;
+        let s = StokesVector::unpolarized(1.0).apply_cascade(&[
+            mueller_linear_polarizer(0.0),
+            mueller_linear_polarizer(angle),
+        ]);
+        assert_relative_eq!(s.intensity(), 0.5 * angle.cos().powi(2), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn retarder_preserves_intensity_and_dop() {
+        let input = StokesVector::new(1.0, 0.3, -0.4, 0.2);
+        let out = input.apply_mueller(mueller_retarder(0.7, 1.1));
+        assert_relative_eq!(out.intensity(), input.intensity(), max_relative = 1e-12);
+        assert_relative_eq!(
+            out.degree_of_polarization(),
+            input.degree_of_polarization(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn quarter_wave_plate_makes_45_degree_linear_circular() {
+        let input = StokesVector::from_state(&PolarizationState::linear_at_angle(1.0, PI / 4.0));
+        let out = input.apply_mueller(mueller_retarder(0.0, PI / 2.0));
+        assert_relative_eq!(out.s3.abs(), out.s0, max_relative = 1e-12);
+    }
 }
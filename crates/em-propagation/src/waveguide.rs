@@ -1,5 +1,6 @@
-//! Rectangular and circular waveguide analysis.
+//! Rectangular and circular waveguide analysis, plus rectangular cavity resonators.
 
+use em_core::constants::{ETA_0, MU_0, intrinsic_impedance_relative};
 use std::f64::consts::PI;
 
 /// Speed of light in vacuum.
@@ -46,12 +47,31 @@ impl RectWaveguide {
         0.5 * v * ((m as f64 / self.a).powi(2) + (n as f64 / self.b).powi(2)).sqrt()
     }
 
+    /// Cutoff wavelength λ_c = 2/√((m/a)² + (n/b)²), measured in the filling medium.
+    pub fn cutoff_wavelength(&self, m: usize, n: usize) -> f64 {
+        2.0 / ((m as f64 / self.a).powi(2) + (n as f64 / self.b).powi(2)).sqrt()
+    }
+
+    /// Whether the TE/TM_mn mode propagates (f > f_c).
+    pub fn is_propagating(&self, m: usize, n: usize, frequency: f64) -> bool {
+        frequency > self.cutoff_frequency(m, n)
+    }
+
+    /// Guide wavelength λ_g = λ/√(1 - (f_c/f)²), or `None` at or below cutoff.
+    pub fn guide_wavelength(&self, m: usize, n: usize, frequency: f64) -> Option<f64> {
+        if !self.is_propagating(m, n, frequency) {
+            return None;
+        }
+        let ratio = self.cutoff_frequency(m, n) / frequency;
+        Some(self.v_medium() / frequency / (1.0 - ratio * ratio).sqrt())
+    }
+
     /// Analyze a specific mode at given frequency.
     pub fn mode_at_frequency(&self, m: usize, n: usize, frequency: f64, mode_type: &'static str) -> ModeInfo {
         let fc = self.cutoff_frequency(m, n);
         let lambda_c = self.v_medium() / fc;
         let propagates = frequency > fc;
-        let eta = 377.0 / (self.epsilon_r / self.mu_r).sqrt();
+        let eta = intrinsic_impedance_relative(self.epsilon_r, self.mu_r);
 
         let (beta, lambda_g, v_phase, v_group, z_mode) = if propagates {
             let ratio = fc / frequency;
@@ -91,12 +111,59 @@ impl RectWaveguide {
         modes
     }
 
+    /// Attenuation constant of an evanescent mode below cutoff (Np/m).
+    ///
+    /// α = (2π/λ)·√((f_c/f)² - 1), with λ the wavelength in the filling
+    /// medium. Tends to 2π/λ_c far below cutoff. Returns `None` at or above
+    /// cutoff, where the mode propagates.
+    pub fn evanescent_attenuation(&self, m: usize, n: usize, frequency: f64) -> Option<f64> {
+        let fc = self.cutoff_frequency(m, n);
+        if frequency >= fc {
+            return None;
+        }
+        Some(2.0 * PI / self.v_medium() * (fc * fc - frequency * frequency).sqrt())
+    }
+
+    /// Attenuation (dB) of a below-cutoff section of the given length (m).
+    ///
+    /// A = 20·log10(e)·α·l; `None` if the mode propagates.
+    pub fn cutoff_attenuator_db(
+        &self,
+        m: usize,
+        n: usize,
+        frequency: f64,
+        length: f64,
+    ) -> Option<f64> {
+        self.evanescent_attenuation(m, n, frequency)
+            .map(|alpha| 20.0 * std::f64::consts::LOG10_E * alpha * length)
+    }
+
+    /// Dimensionless TE-mode parameters at normalized frequency u = f/f_c.
+    ///
+    /// Returns (β/k, v_p/v, v_g/v, Z_TE/η), with k, v and η those of the
+    /// filling medium (c and η₀ when air-filled). With F = √(1 - 1/u²) these
+    /// are (F, 1/F, F, 1/F), the same for every mode, so plots against u
+    /// collapse onto one curve. For that reason this takes no mode indices
+    /// (m, n): only u = f/f_c,mn depends on the mode, via
+    /// [`Self::cutoff_frequency`]. v_p·v_g = v² throughout. At cutoff β = v_g = 0
+    /// and v_p, Z_TE are infinite. Below cutoff (u < 1) the mode is evanescent
+    /// and, as in [`Self::mode_at_frequency`], β = v_g = Z_TE = 0, v_p = ∞.
+    pub fn normalized_parameters(u: f64) -> (f64, f64, f64, f64) {
+        if u < 1.0 {
+            return (0.0, f64::INFINITY, 0.0, 0.0);
+        }
+        let factor = (1.0 - 1.0 / (u * u)).sqrt();
+        (factor, 1.0 / factor, factor, 1.0 / factor)
+    }
+
     /// Dominant mode (TE10) cutoff frequency.
     pub fn dominant_cutoff(&self) -> f64 {
         self.cutoff_frequency(1, 0)
     }
 
     /// Single-mode bandwidth: f_c(TE10) to f_c(next mode).
+    ///
+    /// The next mode is TE20 or TE01, whichever cuts off lower (TE01 when b > a/2).
     pub fn single_mode_band(&self) -> (f64, f64) {
         let fc10 = self.cutoff_frequency(1, 0);
         let fc20 = self.cutoff_frequency(2, 0);
@@ -106,6 +173,58 @@ impl RectWaveguide {
     }
 }
 
+/// Air- or dielectric-filled rectangular cavity resonator with conducting walls.
+///
+/// A section of rectangular guide (a × b) shorted at both ends, length d.
+#[derive(Debug, Clone)]
+pub struct RectangularCavity {
+    pub a: f64, // width, meters
+    pub b: f64, // height, meters
+    pub d: f64, // length, meters
+    pub epsilon_r: f64,
+}
+
+impl RectangularCavity {
+    pub fn new(a: f64, b: f64, d: f64, epsilon_r: f64) -> Self {
+        Self { a, b, d, epsilon_r }
+    }
+
+    /// Speed of light in the filling medium.
+    pub fn v_medium(&self) -> f64 {
+        C / self.epsilon_r.sqrt()
+    }
+
+    /// Resonant frequency of the TE/TM_mnl mode.
+    ///
+    /// f = (v/2)·√((m/a)² + (n/b)² + (l/d)²)
+    pub fn resonant_frequency(&self, m: usize, n: usize, l: usize) -> f64 {
+        0.5 * self.v_medium()
+            * ((m as f64 / self.a).powi(2) + (n as f64 / self.b).powi(2) + (l as f64 / self.d).powi(2))
+                .sqrt()
+    }
+
+    /// Wall surface resistance R_s = √(ωμ₀/(2σ)) (Ω).
+    pub fn surface_resistance(frequency: f64, wall_conductivity: f64) -> f64 {
+        (2.0 * PI * frequency * MU_0 / (2.0 * wall_conductivity)).sqrt()
+    }
+
+    /// Conductor-loss Q of the TE101 mode (ratio of stored energy to wall loss).
+    ///
+    /// Q_c = (k·a·d)³·b·η / (2π²·R_s) · 1/(2a³b + 2bd³ + a³d + ad³)
+    ///
+    /// # Arguments
+    /// * `frequency` - Operating frequency, normally the TE101 resonance (Hz)
+    /// * `wall_conductivity` - Wall conductivity σ (S/m)
+    pub fn quality_factor(&self, frequency: f64, wall_conductivity: f64) -> f64 {
+        let (a, b, d) = (self.a, self.b, self.d);
+        let k = 2.0 * PI * frequency / self.v_medium();
+        let eta = ETA_0 / self.epsilon_r.sqrt();
+        let rs = Self::surface_resistance(frequency, wall_conductivity);
+        let geometry = 2.0 * a.powi(3) * b + 2.0 * b * d.powi(3) + a.powi(3) * d + a * d.powi(3);
+        (k * a * d).powi(3) * b * eta / (2.0 * PI * PI * rs) / geometry
+    }
+}
+
 /// Circular waveguide dominant mode (TE11).
 pub fn circular_te11_cutoff(radius: f64, epsilon_r: f64, mu_r: f64) -> f64 {
     let v = C / (epsilon_r * mu_r).sqrt();
@@ -142,6 +261,24 @@ mod tests {
         assert!((f2 / 1e9 - 13.12).abs() < 0.05);
     }
 
+    #[test]
+    fn test_single_mode_band_edges_are_mode_cutoffs() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
+        let (f1, f2) = wg.single_mode_band();
+        assert_eq!(f1, wg.dominant_cutoff());
+        assert_eq!(f2, wg.cutoff_frequency(2, 0));
+    }
+
+    #[test]
+    fn test_single_mode_band_limited_by_te01_in_tall_guide() {
+        // b > a/2 puts TE01 below TE20
+        let wg = RectWaveguide::new(0.02, 0.015, 1.0, 1.0);
+        let (f1, f2) = wg.single_mode_band();
+        assert_eq!(f1, wg.cutoff_frequency(1, 0));
+        assert_eq!(f2, wg.cutoff_frequency(0, 1));
+        assert!(f2 < wg.cutoff_frequency(2, 0));
+    }
+
     #[test]
     fn test_te10_propagation() {
         let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
@@ -161,6 +298,67 @@ mod tests {
         assert_eq!(mode.beta, 0.0);
     }
 
+    #[test]
+    fn test_evanescent_attenuation_formula() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
+        let fc = wg.dominant_cutoff();
+        let f = 0.5 * fc;
+        let lambda = C / f;
+        let expected = 2.0 * PI / lambda * ((fc / f).powi(2) - 1.0).sqrt();
+        let alpha = wg.evanescent_attenuation(1, 0, f).unwrap();
+        assert!((alpha - expected).abs() / expected < 1e-12);
+        // Stronger attenuation the further below cutoff
+        let near = wg.evanescent_attenuation(1, 0, 0.99 * fc).unwrap();
+        let far = wg.evanescent_attenuation(1, 0, 0.2 * fc).unwrap();
+        assert!(far > alpha && alpha > near && near > 0.0);
+    }
+
+    #[test]
+    fn test_evanescent_attenuation_none_above_cutoff() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
+        assert!(wg.evanescent_attenuation(1, 0, 10e9).is_none());
+        assert!(wg.evanescent_attenuation(1, 0, wg.dominant_cutoff()).is_none());
+        assert!(wg.cutoff_attenuator_db(1, 0, 10e9, 0.1).is_none());
+    }
+
+    #[test]
+    fn test_evanescent_attenuation_deep_below_cutoff() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 2.0, 1.0);
+        let lambda_c = wg.v_medium() / wg.dominant_cutoff();
+        let alpha = wg.evanescent_attenuation(1, 0, 1e3).unwrap();
+        assert!((alpha - 2.0 * PI / lambda_c).abs() / alpha < 1e-9);
+        // ~27.3 dB per λ_c of length deep below cutoff
+        let db = wg.cutoff_attenuator_db(1, 0, 1e3, lambda_c).unwrap();
+        assert!((db - 40.0 * PI * std::f64::consts::LOG10_E).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_guide_wavelength_above_cutoff() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
+        assert!(wg.is_propagating(1, 0, 10e9));
+        let lg = wg.guide_wavelength(1, 0, 10e9).unwrap();
+        assert!(lg > C / 10e9);
+        assert!((lg - wg.mode_at_frequency(1, 0, 10e9, "TE").lambda_g).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_guide_wavelength_none_below_cutoff() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
+        assert!(!wg.is_propagating(1, 0, 5e9));
+        assert!(wg.guide_wavelength(1, 0, 5e9).is_none());
+        assert!(wg.guide_wavelength(1, 0, wg.dominant_cutoff()).is_none());
+    }
+
+    #[test]
+    fn test_cutoff_wavelength_matches_cutoff_frequency() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 2.2, 1.0);
+        for (m, n) in [(1, 0), (0, 1), (1, 1), (2, 1)] {
+            let lc = wg.cutoff_wavelength(m, n);
+            assert!((lc * wg.cutoff_frequency(m, n) - wg.v_medium()).abs() < 1e-3);
+        }
+        assert!((wg.cutoff_wavelength(1, 0) - 2.0 * 0.02286).abs() < 1e-15);
+    }
+
     #[test]
     fn test_modes_below() {
         let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
@@ -176,6 +374,41 @@ mod tests {
         assert!(fc > 8e9 && fc < 10e9);
     }
 
+    #[test]
+    fn test_normalized_parameters_at_cutoff() {
+        let (beta, vp, vg, z) = RectWaveguide::normalized_parameters(1.0);
+        assert_eq!(beta, 0.0);
+        assert_eq!(vg, 0.0);
+        assert!(vp.is_infinite() && z.is_infinite());
+        assert_eq!(RectWaveguide::normalized_parameters(0.7), (0.0, f64::INFINITY, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_normalized_parameters_approach_unbounded_medium() {
+        let (beta, vp, vg, z) = RectWaveguide::normalized_parameters(1e4);
+        for ratio in [beta, vp, vg, z] {
+            assert!((ratio - 1.0).abs() < 1e-8, "ratio {ratio}");
+        }
+    }
+
+    #[test]
+    fn test_normalized_parameters_match_mode_info() {
+        let wg = RectWaveguide::new(0.02286, 0.01016, 2.2, 1.0);
+        let fc = wg.cutoff_frequency(1, 0);
+        let v = wg.v_medium();
+        let eta = intrinsic_impedance_relative(2.2, 1.0);
+        for u in [1.05, 1.3, 1.9, 3.0] {
+            let (beta, vp, vg, z) = RectWaveguide::normalized_parameters(u);
+            assert!((vp * vg - 1.0).abs() < 1e-12);
+            let mode = wg.mode_at_frequency(1, 0, u * fc, "TE");
+            let k = 2.0 * PI * u * fc / v;
+            assert!((beta - mode.beta / k).abs() < 1e-9);
+            assert!((vp - mode.v_phase / v).abs() < 1e-9);
+            assert!((vg - mode.v_group / v).abs() < 1e-9);
+            assert!((z - mode.z_mode / eta).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_phase_group_product() {
         let wg = RectWaveguide::new(0.02286, 0.01016, 1.0, 1.0);
@@ -184,4 +417,38 @@ mod tests {
         let v_sq = C * C;
         assert!((product - v_sq).abs() / v_sq < 0.001);
     }
+
+    #[test]
+    fn test_cavity_te101_resonance() {
+        let cav = RectangularCavity::new(0.02286, 0.01016, 0.03, 1.0);
+        let expected = 0.5 * C * ((1.0 / cav.a).powi(2) + (1.0 / cav.d).powi(2)).sqrt();
+        let f101 = cav.resonant_frequency(1, 0, 1);
+        assert!((f101 - expected).abs() / expected < 1e-12);
+    }
+
+    #[test]
+    fn test_cavity_dielectric_lowers_resonance() {
+        let air = RectangularCavity::new(0.02, 0.01, 0.03, 1.0);
+        let filled = RectangularCavity::new(0.02, 0.01, 0.03, 4.0);
+        let ratio = air.resonant_frequency(1, 0, 1) / filled.resonant_frequency(1, 0, 1);
+        assert!((ratio - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cavity_q_scales_as_sqrt_sigma() {
+        let cav = RectangularCavity::new(0.02286, 0.01016, 0.03, 1.0);
+        let f = cav.resonant_frequency(1, 0, 1);
+        let q1 = cav.quality_factor(f, 1.0e7);
+        let q4 = cav.quality_factor(f, 4.0e7);
+        assert!((q4 / q1 - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cavity_q_copper_in_thousands() {
+        // Copper X-band cavity: Q on the order of several thousand
+        let cav = RectangularCavity::new(0.02286, 0.01016, 0.03, 1.0);
+        let f = cav.resonant_frequency(1, 0, 1);
+        let q = cav.quality_factor(f, 5.8e7);
+        assert!(q > 1.0e3 && q < 2.0e4, "Q = {q}");
+    }
 }
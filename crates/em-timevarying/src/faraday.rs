@@ -5,6 +5,7 @@
 //! Covers: stationary loops in time-varying B, moving conductors in static B,
 //! and transformers/generators.
 
+use em_core::coordinates::Vector3;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -72,6 +73,71 @@ impl SinusoidalFlux {
     }
 }
 
+/// Flux through a flat loop rotating in a uniform B-field of arbitrary orientation.
+///
+/// The loop normal starts at `loop_normal` and turns about `rotation_axis`
+/// at angular speed ω, so n̂(t) = R(ωt)·n̂₀ and Φ(t) = A·(B · n̂(t)).
+/// Generalizes [`SinusoidalFlux`], which assumes B is normal to the loop.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FluxThroughLoop {
+    /// Uniform B-field (T)
+    pub b_vector: Vector3,
+    /// Loop normal at t = 0 (normalized internally)
+    pub loop_normal: Vector3,
+    /// Axis the loop rotates about (defaults to ẑ)
+    pub rotation_axis: Vector3,
+    /// Loop area (m²)
+    pub area: f64,
+    /// Rotation speed (rad/s)
+    pub omega: f64,
+}
+
+impl FluxThroughLoop {
+    pub fn new(b_vector: Vector3, loop_normal: Vector3, area: f64, omega: f64) -> Self {
+        Self {
+            b_vector,
+            loop_normal,
+            rotation_axis: Vector3::new(0.0, 0.0, 1.0),
+            area,
+            omega,
+        }
+    }
+
+    pub fn with_rotation_axis(mut self, axis: Vector3) -> Self {
+        self.rotation_axis = axis;
+        self
+    }
+
+    /// Unit loop normal at time t.
+    pub fn normal_at(&self, t: f64) -> Vector3 {
+        self.loop_normal
+            .normalized()
+            .rotate_about(&self.rotation_axis, self.omega * t)
+    }
+
+    /// Magnetic flux at time t: Φ = A·(B · n̂(t)).
+    pub fn flux_at(&self, t: f64) -> f64 {
+        self.area * self.b_vector.dot(&self.normal_at(t))
+    }
+
+    /// Induced EMF at time t: EMF = -dΦ/dt = -A·ω·B · (k̂ × n̂(t)).
+    pub fn emf_at(&self, t: f64) -> f64 {
+        let k = self.rotation_axis.normalized();
+        let dn_dt = k.cross(&self.normal_at(t)).scale(self.omega);
+        -self.area * self.b_vector.dot(&dn_dt)
+    }
+
+    /// Sample flux and EMF over time for visualization.
+    pub fn sample(&self, t_end: f64, num_points: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        assert!(num_points >= 2);
+        let dt = t_end / (num_points - 1) as f64;
+        let times: Vec<f64> = (0..num_points).map(|i| i as f64 * dt).collect();
+        let flux: Vec<f64> = times.iter().map(|&t| self.flux_at(t)).collect();
+        let emf: Vec<f64> = times.iter().map(|&t| self.emf_at(t)).collect();
+        (times, flux, emf)
+    }
+}
+
 /// A simple AC generator (rotating loop in uniform B-field).
 ///
 /// EMF = N·B·A·ω·sin(ωt)
@@ -126,6 +192,24 @@ impl AcGenerator {
     pub fn period(&self) -> f64 {
         1.0 / self.frequency()
     }
+
+    /// Time-averaged power delivered to a resistive load: P = V_rms²/R (W).
+    pub fn power_into_load(&self, resistance: f64) -> f64 {
+        self.vrms() * self.vrms() / resistance
+    }
+
+    /// Instantaneous power into a resistive load: p(t) = emf(t)²/R (W).
+    ///
+    /// Oscillates between 0 and V₀²/R at twice the rotation frequency.
+    pub fn instantaneous_power(&self, t: f64, resistance: f64) -> f64 {
+        let emf = self.emf_at(t);
+        emf * emf / resistance
+    }
+
+    /// Average mechanical torque needed to drive the load: τ = P/ω (N·m).
+    pub fn torque_required(&self, resistance: f64) -> f64 {
+        self.power_into_load(resistance) / self.omega
+    }
 }
 
 /// A simple transformer (ideal).
@@ -169,6 +253,29 @@ impl IdealTransformer {
     pub fn is_step_up(&self) -> bool {
         self.n_secondary > self.n_primary
     }
+
+    /// Transformer with `n_primary` turns that best matches `z_load` to `z_source`.
+    ///
+    /// The secondary turns are n_primary·√(Z_L/Z_S) rounded to the nearest
+    /// integer (at least 1); see [`Self::match_residual`] for what is left.
+    pub fn design_match(n_primary: usize, z_source: f64, z_load: f64) -> Self {
+        let exact = n_primary as f64 * turns_ratio_for_impedance_match(z_source, z_load);
+        Self::new(n_primary, (exact.round() as usize).max(1))
+    }
+
+    /// Residual mismatch |Γ| = |Z₂' - Z_S|/(Z₂' + Z_S) with `z_load` on the secondary.
+    ///
+    /// Zero for an exact match; nonzero when the turns cannot realize √(Z_L/Z_S).
+    pub fn match_residual(&self, z_source: f64, z_load: f64) -> f64 {
+        let z_in = self.impedance_reflected(z_load);
+        ((z_in - z_source) / (z_in + z_source)).abs()
+    }
+}
+
+/// Turns ratio n = N₂/N₁ = √(Z_L/Z_S) that presents `z_load` to the source as
+/// `z_source` (Z_L/n² = Z_S).
+pub fn turns_ratio_for_impedance_match(z_source: f64, z_load: f64) -> f64 {
+    (z_load / z_source).sqrt()
 }
 
 /// Motional EMF for a conductor moving in a magnetic field.
@@ -240,6 +347,51 @@ mod tests {
         assert_eq!(emf.len(), 100);
     }
 
+    // ================================================================
+    // Flux through a rotating loop
+    // ================================================================
+
+    #[test]
+    fn rotating_loop_flux_is_sinusoidal() {
+        // B along x, normal starts along x and sweeps through the xy-plane
+        let b = Vector3::new(0.5, 0.0, 0.0);
+        let lp = FluxThroughLoop::new(b, Vector3::new(1.0, 0.0, 0.0), 0.01, 100.0);
+        for &t in &[0.0, 0.003, 0.011, 0.02] {
+            assert_relative_eq!(lp.flux_at(t), 0.5 * 0.01 * (100.0 * t).cos(), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn rotating_loop_matches_ac_generator_emf() {
+        let b = Vector3::new(0.5, 0.0, 0.0);
+        let lp = FluxThroughLoop::new(b, Vector3::new(1.0, 0.0, 0.0), 0.01, 100.0);
+        let generator = AcGenerator::new(1, 0.5, 0.01, 100.0);
+        assert_relative_eq!(lp.emf_at(0.004), generator.emf_at(0.004), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn loop_normal_perpendicular_to_b_has_zero_flux() {
+        // B along the rotation axis, normal stays in the xy-plane
+        let b = Vector3::new(0.0, 0.0, 1.2);
+        let lp = FluxThroughLoop::new(b, Vector3::new(0.0, 1.0, 0.0), 0.02, 50.0);
+        for &t in &[0.0, 0.01, 0.037] {
+            assert_relative_eq!(lp.flux_at(t), 0.0, epsilon = 1e-15);
+            assert_relative_eq!(lp.emf_at(t), 0.0, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn tilted_loop_emf_is_negative_flux_derivative() {
+        let b = Vector3::new(0.3, -0.2, 0.4);
+        let lp = FluxThroughLoop::new(b, Vector3::new(1.0, 1.0, 1.0), 0.05, 60.0)
+            .with_rotation_axis(Vector3::new(0.0, 1.0, 1.0));
+        let h = 1e-7;
+        for &t in &[0.0, 0.013, 0.05] {
+            let dphi_dt = (lp.flux_at(t + h) - lp.flux_at(t - h)) / (2.0 * h);
+            assert_relative_eq!(lp.emf_at(t), -dphi_dt, max_relative = 1e-6);
+        }
+    }
+
     // ================================================================
     // AC Generator
     // ================================================================
@@ -275,6 +427,48 @@ mod tests {
         assert_relative_eq!(g.period(), 0.02, max_relative = 1e-10);
     }
 
+    #[test]
+    fn generator_average_power_is_vrms_squared_over_r() {
+        let g = AcGenerator::new(100, 0.5, 0.04, 120.0 * PI);
+        let r = 50.0;
+        assert_relative_eq!(g.power_into_load(r), g.vrms().powi(2) / r, max_relative = 1e-12);
+
+        // Time average of p(t) over one period equals the average power
+        let n = 10_000;
+        let dt = g.period() / n as f64;
+        let total: f64 = (0..n).map(|i| g.instantaneous_power(i as f64 * dt, r)).sum();
+        let avg = total / n as f64;
+        assert_relative_eq!(avg, g.power_into_load(r), max_relative = 1e-6);
+    }
+
+    #[test]
+    fn generator_instantaneous_power_oscillates_at_twice_omega() {
+        let g = AcGenerator::new(10, 1.0, 0.01, 2.0 * PI * 60.0);
+        let r = 10.0;
+        let half_period = g.period() / 2.0;
+        for i in 0..50 {
+            let t = i as f64 * g.period() / 50.0;
+            let p = g.instantaneous_power(t, r);
+            assert!(p >= 0.0, "instantaneous power must be non-negative");
+            assert_relative_eq!(p, g.instantaneous_power(t + half_period, r), epsilon = 1e-9);
+        }
+        // Peak at quarter period, zero at start
+        assert_relative_eq!(g.instantaneous_power(0.0, r), 0.0, epsilon = 1e-12);
+        let p_peak = g.instantaneous_power(g.period() / 4.0, r);
+        assert_relative_eq!(p_peak, g.emf_peak().powi(2) / r, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn generator_torque_is_power_over_omega() {
+        let g = AcGenerator::from_rpm(200, 0.2, 0.05, 3600.0);
+        let r = 25.0;
+        assert_relative_eq!(
+            g.torque_required(r),
+            g.power_into_load(r) / g.omega,
+            max_relative = 1e-12
+        );
+    }
+
     // ================================================================
     // Transformer
     // ================================================================
@@ -312,6 +506,35 @@ mod tests {
         assert_relative_eq!(z_ref, 25.0, epsilon = 1e-12);
     }
 
+    #[test]
+    fn eight_to_eight_hundred_ohms_needs_ten_to_one() {
+        let n = turns_ratio_for_impedance_match(8.0, 800.0);
+        assert_relative_eq!(n, 10.0, max_relative = 1e-12);
+        let t = IdealTransformer::design_match(20, 8.0, 800.0);
+        assert_eq!(t, IdealTransformer::new(20, 200));
+        assert!(t.is_step_up());
+    }
+
+    #[test]
+    fn matched_transformer_reflects_source_impedance() {
+        let t = IdealTransformer::design_match(50, 50.0, 450.0);
+        assert_eq!(t.n_secondary, 150);
+        assert_relative_eq!(t.impedance_reflected(450.0), 50.0, max_relative = 1e-12);
+        assert_relative_eq!(t.match_residual(50.0, 450.0), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn rounded_turns_leave_residual_mismatch() {
+        // √(300/50) ≈ 2.449, so 10 primary turns round to 24 on the secondary
+        let t = IdealTransformer::design_match(10, 50.0, 300.0);
+        assert_eq!(t.n_secondary, 24);
+        let residual = t.match_residual(50.0, 300.0);
+        assert!(residual > 0.0 && residual < 0.03, "|Γ| = {residual}");
+        // More primary turns give a finer approximation
+        let fine = IdealTransformer::design_match(1000, 50.0, 300.0);
+        assert!(fine.match_residual(50.0, 300.0) < residual);
+    }
+
     // ================================================================
     // Motional EMF
     // ================================================================
@@ -3,7 +3,9 @@
 //! - Module 6.1: Faraday's law — EMF from changing magnetic flux
 //! - Module 6.2: Displacement current — completing Ampère's law
 //! - Module 6.3: Charge-current continuity — conservation of charge
+//! - Driven series RLC circuit — resonance, Q and bandwidth
 
 pub mod faraday;
 pub mod displacement_current;
 pub mod charge_continuity;
+pub mod rlc_circuit;
@@ -0,0 +1,147 @@
+//! Sinusoidally driven series RLC circuit.
+//!
+//! Z(ω) = R + j(ωL - 1/(ωC)); resonance at ω₀ = 1/√(LC), where the current
+//! peaks at V/R. Phasors are peak amplitudes, so average power is ½|I|²R.
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Series RLC circuit driven by a sinusoidal voltage source.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RlcSeries {
+    /// Resistance (Ω)
+    pub resistance: f64,
+    /// Inductance (H)
+    pub inductance: f64,
+    /// Capacitance (F)
+    pub capacitance: f64,
+}
+
+impl RlcSeries {
+    pub fn new(resistance: f64, inductance: f64, capacitance: f64) -> Self {
+        Self {
+            resistance,
+            inductance,
+            capacitance,
+        }
+    }
+
+    /// Resonant frequency f₀ = 1/(2π√(LC)) (Hz).
+    pub fn resonant_frequency(&self) -> f64 {
+        1.0 / (2.0 * PI * (self.inductance * self.capacitance).sqrt())
+    }
+
+    /// Quality factor Q = ω₀L/R = (1/R)·√(L/C).
+    pub fn quality_factor(&self) -> f64 {
+        (self.inductance / self.capacitance).sqrt() / self.resistance
+    }
+
+    /// Half-power bandwidth Δf = f₀/Q = R/(2πL) (Hz).
+    pub fn bandwidth(&self) -> f64 {
+        self.resistance / (2.0 * PI * self.inductance)
+    }
+
+    /// Lower and upper half-power frequencies (Hz).
+    ///
+    /// ω₁,₂ = ∓R/(2L) + √((R/(2L))² + ω₀²), so f₂ - f₁ = f₀/Q exactly.
+    pub fn half_power_frequencies(&self) -> (f64, f64) {
+        let half_bw = self.resistance / (2.0 * self.inductance);
+        let omega_0 = 2.0 * PI * self.resonant_frequency();
+        let root = (half_bw * half_bw + omega_0 * omega_0).sqrt();
+        ((root - half_bw) / (2.0 * PI), (root + half_bw) / (2.0 * PI))
+    }
+
+    /// Impedance Z = R + j(ωL - 1/(ωC)) (Ω).
+    pub fn impedance(&self, frequency: f64) -> Complex64 {
+        let omega = 2.0 * PI * frequency;
+        let x = omega * self.inductance - 1.0 / (omega * self.capacitance);
+        Complex64::new(self.resistance, x)
+    }
+
+    /// Current phasor I = V/Z (A).
+    pub fn current_response(&self, v_source: Complex64, frequency: f64) -> Complex64 {
+        v_source / self.impedance(frequency)
+    }
+
+    /// Time-averaged power dissipated in R: P = ½|I|²R (W).
+    pub fn power_dissipated(&self, v_source: Complex64, frequency: f64) -> f64 {
+        0.5 * self.current_response(v_source, frequency).norm_sqr() * self.resistance
+    }
+
+    /// Sample the resonance curve |I(f)| over a linear frequency grid.
+    ///
+    /// # Returns
+    /// (frequencies, current_magnitudes)
+    pub fn frequency_sweep(
+        &self,
+        v_source: Complex64,
+        f_start: f64,
+        f_end: f64,
+        num_points: usize,
+    ) -> (Vec<f64>, Vec<f64>) {
+        assert!(num_points >= 2);
+        let df = (f_end - f_start) / (num_points - 1) as f64;
+        let freqs: Vec<f64> = (0..num_points).map(|i| f_start + i as f64 * df).collect();
+        let currents = freqs
+            .iter()
+            .map(|&f| self.current_response(v_source, f).norm())
+            .collect();
+        (freqs, currents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn circuit() -> RlcSeries {
+        // f₀ ≈ 5.03 kHz, Q ≈ 3.16
+        RlcSeries::new(10.0, 1e-3, 1e-6)
+    }
+
+    const V: Complex64 = Complex64::new(1.0, 0.0);
+
+    #[test]
+    fn current_peaks_at_resonance() {
+        let rlc = circuit();
+        let f0 = rlc.resonant_frequency();
+        let (freqs, currents) = rlc.frequency_sweep(V, 0.2 * f0, 3.0 * f0, 2801);
+        let i_max = (0..currents.len())
+            .max_by(|&a, &b| currents[a].total_cmp(&currents[b]))
+            .unwrap();
+        assert_relative_eq!(freqs[i_max], f0, max_relative = 1e-3);
+        assert_relative_eq!(currents[i_max], 1.0 / rlc.resistance, max_relative = 1e-6);
+        // Purely resistive at resonance
+        assert_relative_eq!(rlc.current_response(V, f0).arg(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn half_power_points_bracket_bandwidth() {
+        let rlc = circuit();
+        let f0 = rlc.resonant_frequency();
+        let (f1, f2) = rlc.half_power_frequencies();
+        assert!(f1 < f0 && f0 < f2);
+        assert_relative_eq!(f2 - f1, f0 / rlc.quality_factor(), max_relative = 1e-12);
+        assert_relative_eq!(f2 - f1, rlc.bandwidth(), max_relative = 1e-12);
+        let p0 = rlc.power_dissipated(V, f0);
+        assert_relative_eq!(rlc.power_dissipated(V, f1), p0 / 2.0, max_relative = 1e-9);
+        assert_relative_eq!(rlc.power_dissipated(V, f2), p0 / 2.0, max_relative = 1e-9);
+        // Geometric mean of the half-power points is f₀
+        assert_relative_eq!((f1 * f2).sqrt(), f0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn power_curve_integrates_to_v_squared_over_8l() {
+        // ∫₀^∞ P df = |V|²/(8L), independent of R and C
+        let rlc = circuit();
+        let f0 = rlc.resonant_frequency();
+        let n = 200_000;
+        let df = 100.0 * f0 / n as f64;
+        let integral: f64 = (1..=n)
+            .map(|i| rlc.power_dissipated(V, i as f64 * df) * df)
+            .sum();
+        assert_relative_eq!(integral, 1.0 / (8.0 * rlc.inductance), max_relative = 1e-2);
+    }
+}
@@ -0,0 +1,357 @@
+//! Input impedance of cascaded line sections and stubs.
+//!
+//! Each element is reduced to its ABCD (transmission) matrix; the chain is
+//! multiplied from the input toward the load and terminated in Z_L:
+//! Z_in = (A·Z_L + B) / (C·Z_L + D).
+//!
+//! Two-ports known by their S-parameters are chained through scattering
+//! transfer (T) matrices, which multiply like ABCD matrices:
+//!
+//! ```text
+//! [b₁]   [T₁₁ T₁₂] [a₂]      T₁₁ = -det(S)/S₂₁   T₁₂ = S₁₁/S₂₁
+//! [a₁] = [T₂₁ T₂₂] [b₂]      T₂₁ = -S₂₂/S₂₁      T₂₂ = 1/S₂₁
+//! ```
+
+use crate::stub_tuning::{StubType, stub_input_impedance};
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// One element of a lossless transmission-line cascade.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LineElement {
+    /// Line section of characteristic impedance `z0` and physical `length` (m)
+    Line {
+        z0: f64,
+        length: f64,
+        phase_velocity: f64,
+    },
+    /// Stub inserted in series with the main line
+    SeriesStub {
+        z0: f64,
+        length: f64,
+        phase_velocity: f64,
+        stub_type: StubType,
+    },
+    /// Stub connected in shunt across the main line
+    ShuntStub {
+        z0: f64,
+        length: f64,
+        phase_velocity: f64,
+        stub_type: StubType,
+    },
+}
+
+type Abcd = [[Complex64; 2]; 2];
+
+impl LineElement {
+    /// ABCD matrix of the element at `frequency` (Hz).
+    fn abcd(&self, frequency: f64) -> Abcd {
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        match *self {
+            LineElement::Line {
+                z0,
+                length,
+                phase_velocity,
+            } => {
+                let beta_l = 2.0 * PI * frequency * length / phase_velocity;
+                let (sin, cos) = beta_l.sin_cos();
+                [
+                    [Complex64::new(cos, 0.0), Complex64::new(0.0, z0 * sin)],
+                    [Complex64::new(0.0, sin / z0), Complex64::new(cos, 0.0)],
+                ]
+            }
+            LineElement::SeriesStub {
+                z0,
+                length,
+                phase_velocity,
+                stub_type,
+            } => {
+                let beta_l = 2.0 * PI * frequency * length / phase_velocity;
+                let z = stub_input_impedance(stub_type, z0, beta_l);
+                [[one, z], [zero, one]]
+            }
+            LineElement::ShuntStub {
+                z0,
+                length,
+                phase_velocity,
+                stub_type,
+            } => {
+                let beta_l = 2.0 * PI * frequency * length / phase_velocity;
+                let y = one / stub_input_impedance(stub_type, z0, beta_l);
+                [[one, zero], [y, one]]
+            }
+        }
+    }
+}
+
+fn abcd_mul(a: &Abcd, b: &Abcd) -> Abcd {
+    [
+        [
+            a[0][0] * b[0][0] + a[0][1] * b[1][0],
+            a[0][0] * b[0][1] + a[0][1] * b[1][1],
+        ],
+        [
+            a[1][0] * b[0][0] + a[1][1] * b[1][0],
+            a[1][0] * b[0][1] + a[1][1] * b[1][1],
+        ],
+    ]
+}
+
+/// Input impedance of a cascade of line sections and stubs terminated in `z_load`.
+///
+/// # Arguments
+/// * `elements` - Cascade ordered from the input port toward the load
+/// * `z_load` - Load impedance (Ω)
+/// * `frequency` - Operating frequency (Hz)
+///
+/// # Returns
+/// Impedance seen at the input (Ω). An empty cascade returns `z_load`.
+pub fn cascade_input_impedance(
+    elements: &[LineElement],
+    z_load: Complex64,
+    frequency: f64,
+) -> Complex64 {
+    let one = Complex64::new(1.0, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let m = elements
+        .iter()
+        .fold([[one, zero], [zero, one]], |acc, e| abcd_mul(&acc, &e.abcd(frequency)));
+    (m[0][0] * z_load + m[0][1]) / (m[1][0] * z_load + m[1][1])
+}
+
+/// Scattering transfer (T) matrix of a two-port.
+///
+/// Returns `None` when S₂₁ = 0: a two-port with no forward transmission has
+/// no T matrix.
+pub fn s_to_t(s: &[[Complex64; 2]; 2]) -> Option<[[Complex64; 2]; 2]> {
+    let det = s[0][0] * s[1][1] - s[0][1] * s[1][0];
+    let s21 = s[1][0];
+    if s21 == Complex64::new(0.0, 0.0) {
+        return None;
+    }
+    Some([[-det / s21, s[0][0] / s21], [-s[1][1] / s21, 1.0 / s21]])
+}
+
+/// S-parameters from a scattering transfer (T) matrix; inverse of [`s_to_t`].
+pub fn t_to_s(t: &[[Complex64; 2]; 2]) -> [[Complex64; 2]; 2] {
+    let det = t[0][0] * t[1][1] - t[0][1] * t[1][0];
+    let t22 = t[1][1];
+    [[t[0][1] / t22, det / t22], [1.0 / t22, -t[1][0] / t22]]
+}
+
+/// ABCD matrix of a two-port from its S-parameters in a real reference `z0` (Ω).
+pub fn s_to_abcd(s: &[[Complex64; 2]; 2], z0: f64) -> [[Complex64; 2]; 2] {
+    let one = Complex64::new(1.0, 0.0);
+    let (s11, s12, s21, s22) = (s[0][0], s[0][1], s[1][0], s[1][1]);
+    let s12s21 = s12 * s21;
+    let den = 2.0 * s21;
+    [
+        [
+            ((one + s11) * (one - s22) + s12s21) / den,
+            z0 * ((one + s11) * (one + s22) - s12s21) / den,
+        ],
+        [
+            ((one - s11) * (one - s22) - s12s21) / (den * z0),
+            ((one - s11) * (one + s22) + s12s21) / den,
+        ],
+    ]
+}
+
+/// S-parameters in a real reference `z0` (Ω) from an ABCD matrix.
+pub fn abcd_to_s(abcd: &[[Complex64; 2]; 2], z0: f64) -> [[Complex64; 2]; 2] {
+    let (a, b, c, d) = (abcd[0][0], abcd[0][1], abcd[1][0], abcd[1][1]);
+    let den = a + b / z0 + c * z0 + d;
+    [
+        [(a + b / z0 - c * z0 - d) / den, 2.0 * (a * d - b * c) / den],
+        [2.0 / den, (-a + b / z0 - c * z0 + d) / den],
+    ]
+}
+
+/// S-parameters of two-ports connected in cascade, port 2 of each stage to
+/// port 1 of the next.
+///
+/// Every stage must share the same reference impedance, which the result is
+/// also referred to. T matrices chain the same way whatever that impedance
+/// is, so unlike [`s_to_abcd`] this takes no `z0` argument. An empty chain
+/// is a matched through.
+///
+/// Returns `None` if any stage has S₂₁ = 0 (see [`s_to_t`]).
+pub fn cascade_s(stages: &[[[Complex64; 2]; 2]]) -> Option<[[Complex64; 2]; 2]> {
+    let one = Complex64::new(1.0, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let t = stages
+        .iter()
+        .try_fold([[one, zero], [zero, one]], |acc, s| {
+            Some(abcd_mul(&acc, &s_to_t(s)?))
+        })?;
+    Some(t_to_s(&t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use em_core::complex::input_impedance_lossless;
+    use em_core::constants::C_0;
+
+    const F: f64 = 1e9;
+
+    fn wavelength() -> f64 {
+        C_0 / F
+    }
+
+    fn line(z0: f64, wavelengths: f64) -> LineElement {
+        LineElement::Line {
+            z0,
+            length: wavelengths * wavelength(),
+            phase_velocity: C_0,
+        }
+    }
+
+    #[test]
+    fn single_section_matches_lossless_input_impedance() {
+        let zl = Complex64::new(30.0, -45.0);
+        let zin = cascade_input_impedance(&[line(75.0, 0.137)], zl, F);
+        let expected = input_impedance_lossless(75.0, zl, 2.0 * PI * 0.137);
+        assert_relative_eq!(zin.re, expected.re, max_relative = 1e-9);
+        assert_relative_eq!(zin.im, expected.im, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn shunt_quarter_wave_shorted_stub_is_transparent() {
+        // Shorted λ/4 stub looks like an open circuit: B ≈ 0
+        let zl = Complex64::new(100.0, 20.0);
+        let stub = LineElement::ShuntStub {
+            z0: 50.0,
+            length: 0.25 * wavelength(),
+            phase_velocity: C_0,
+            stub_type: StubType::Short,
+        };
+        let zin = cascade_input_impedance(&[stub], zl, F);
+        assert_relative_eq!(zin.re, zl.re, max_relative = 1e-9);
+        assert_relative_eq!(zin.im, zl.im, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn shunt_eighth_wave_shorted_stub_adds_inductive_susceptance() {
+        // Shorted λ/8 stub: Z = jZ_s·tan(π/4) = jZ_s  →  ΔY = -j/Z_s
+        let zl = Complex64::new(100.0, 20.0);
+        let stub = LineElement::ShuntStub {
+            z0: 50.0,
+            length: 0.125 * wavelength(),
+            phase_velocity: C_0,
+            stub_type: StubType::Short,
+        };
+        let zin = cascade_input_impedance(&[stub], zl, F);
+        let dy = 1.0 / zin - 1.0 / zl;
+        assert_relative_eq!(dy.re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(dy.im, -1.0 / 50.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn series_open_stub_adds_reactance() {
+        // Open λ/8 stub: Z = -jZ_s·cot(π/4) = -jZ_s
+        let zl = Complex64::new(50.0, 0.0);
+        let stub = LineElement::SeriesStub {
+            z0: 75.0,
+            length: 0.125 * wavelength(),
+            phase_velocity: C_0,
+            stub_type: StubType::Open,
+        };
+        let zin = cascade_input_impedance(&[stub], zl, F);
+        assert_relative_eq!(zin.re, 50.0, max_relative = 1e-9);
+        assert_relative_eq!(zin.im, -75.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn two_half_wave_lines_return_the_load() {
+        let zl = Complex64::new(12.0, 80.0);
+        let zin = cascade_input_impedance(&[line(50.0, 0.5), line(120.0, 0.5)], zl, F);
+        assert_relative_eq!(zin.re, zl.re, max_relative = 1e-9);
+        assert_relative_eq!(zin.im, zl.im, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn empty_cascade_returns_the_load() {
+        let zl = Complex64::new(33.0, -7.0);
+        assert_eq!(cascade_input_impedance(&[], zl, F), zl);
+    }
+
+    // ================================================================
+    // S-parameter cascade
+    // ================================================================
+
+    type Sp = [[Complex64; 2]; 2];
+
+    fn assert_s_eq(a: &Sp, b: &Sp) {
+        for (row_a, row_b) in a.iter().zip(b) {
+            for (x, y) in row_a.iter().zip(row_b) {
+                assert!((x - y).norm() < 1e-12, "{x} vs {y}");
+            }
+        }
+    }
+
+    fn attenuator(db: f64) -> Sp {
+        let k = Complex64::new(10f64.powf(-db / 20.0), 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        [[zero, k], [k, zero]]
+    }
+
+    fn mismatched_stage() -> Sp {
+        abcd_to_s(&line(75.0, 0.137).abcd(F), 50.0)
+    }
+
+    #[test]
+    fn t_matrix_round_trips() {
+        let s = mismatched_stage();
+        assert_s_eq(&t_to_s(&s_to_t(&s).unwrap()), &s);
+        assert_s_eq(&abcd_to_s(&s_to_abcd(&s, 50.0), 50.0), &s);
+    }
+
+    #[test]
+    fn through_is_identity_in_cascade() {
+        let through = attenuator(0.0);
+        let stage = mismatched_stage();
+        assert_s_eq(&cascade_s(&[through, stage]).unwrap(), &stage);
+        assert_s_eq(&cascade_s(&[stage, through]).unwrap(), &stage);
+        assert_s_eq(&cascade_s(&[]).unwrap(), &through);
+    }
+
+    #[test]
+    fn stage_without_transmission_has_no_cascade() {
+        let zero = Complex64::new(0.0, 0.0);
+        let isolator_reverse = [[zero, Complex64::new(1.0, 0.0)], [zero, zero]];
+        assert!(s_to_t(&isolator_reverse).is_none());
+        assert!(cascade_s(&[mismatched_stage(), isolator_reverse]).is_none());
+    }
+
+    #[test]
+    fn identical_attenuators_double_the_loss() {
+        let s = cascade_s(&[attenuator(3.0), attenuator(3.0)]).unwrap();
+        assert_relative_eq!(-20.0 * s[1][0].norm().log10(), 6.0, max_relative = 1e-12);
+        assert_relative_eq!(-20.0 * s[0][1].norm().log10(), 6.0, max_relative = 1e-12);
+        assert!(s[0][0].norm() < 1e-15 && s[1][1].norm() < 1e-15);
+    }
+
+    #[test]
+    fn s_cascade_matches_abcd_cascade() {
+        let elements = [
+            line(75.0, 0.137),
+            LineElement::ShuntStub {
+                z0: 50.0,
+                length: 0.1 * wavelength(),
+                phase_velocity: C_0,
+                stub_type: StubType::Short,
+            },
+            line(35.0, 0.31),
+        ];
+        let stages: Vec<Sp> = elements.iter().map(|e| abcd_to_s(&e.abcd(F), 50.0)).collect();
+        let abcd = elements
+            .iter()
+            .skip(1)
+            .fold(elements[0].abcd(F), |acc, e| abcd_mul(&acc, &e.abcd(F)));
+        assert_s_eq(&cascade_s(&stages).unwrap(), &abcd_to_s(&abcd, 50.0));
+    }
+}
@@ -0,0 +1,155 @@
+//! Single-section coupled-line directional coupler.
+//!
+//! Two parallel TEM lines of length λ/4 at the design frequency, with
+//! even/odd-mode impedances chosen for a voltage coupling factor C:
+//!
+//! ```text
+//! Z₀e = Z₀·√((1 + C)/(1 - C))      Z₀o = Z₀·√((1 - C)/(1 + C))
+//! ```
+//!
+//! so Z₀e·Z₀o = Z₀² and every port stays matched. With θ = βl the port
+//! voltages for a unit wave into port 1 are
+//!
+//! ```text
+//! V₃ = jC·tan θ / (√(1 - C²) + j·tan θ)          (coupled)
+//! V₂ = √(1 - C²) / (√(1 - C²)·cos θ + j·sin θ)   (through)
+//! V₄ = 0                                          (isolated)
+//! ```
+
+use em_core::units::amplitude_to_db;
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Quarter-wave coupled-line directional coupler.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DirectionalCoupler {
+    /// System impedance Z₀ (Ω)
+    pub z0: f64,
+    /// Voltage coupling factor C at the design frequency (0 < C < 1)
+    pub coupling: f64,
+    /// Frequency at which the lines are a quarter wavelength long (Hz)
+    pub center_frequency: f64,
+}
+
+/// Port voltages of a [`DirectionalCoupler`] at one frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CouplerResponse {
+    /// Frequency (Hz)
+    pub frequency: f64,
+    /// Coupled port V₃
+    pub coupled: Complex64,
+    /// Through port V₂
+    pub through: Complex64,
+    /// Isolated port V₄
+    pub isolated: Complex64,
+}
+
+impl DirectionalCoupler {
+    /// Design from a voltage coupling factor C (0 < C < 1).
+    pub fn new(z0: f64, coupling: f64, center_frequency: f64) -> Self {
+        assert!(coupling > 0.0 && coupling < 1.0, "coupling factor must lie in (0, 1)");
+        Self {
+            z0,
+            coupling,
+            center_frequency,
+        }
+    }
+
+    /// Design from a coupling in dB, e.g. 3.0 for a 3 dB hybrid (C = 10^(-dB/20)).
+    pub fn from_coupling_db(z0: f64, coupling_db: f64, center_frequency: f64) -> Self {
+        Self::new(z0, 10f64.powf(-coupling_db / 20.0), center_frequency)
+    }
+
+    /// Even-mode impedance Z₀e = Z₀·√((1 + C)/(1 - C)) (Ω).
+    pub fn even_mode_impedance(&self) -> f64 {
+        self.z0 * ((1.0 + self.coupling) / (1.0 - self.coupling)).sqrt()
+    }
+
+    /// Odd-mode impedance Z₀o = Z₀·√((1 - C)/(1 + C)) (Ω).
+    pub fn odd_mode_impedance(&self) -> f64 {
+        self.z0 * ((1.0 - self.coupling) / (1.0 + self.coupling)).sqrt()
+    }
+
+    /// Coupling at the design frequency, -20·log10(C) (dB).
+    pub fn coupling_db(&self) -> f64 {
+        -amplitude_to_db(self.coupling)
+    }
+
+    /// Electrical length θ = (π/2)·f/f₀ of the coupled section (rad).
+    pub fn electrical_length(&self, frequency: f64) -> f64 {
+        0.5 * PI * frequency / self.center_frequency
+    }
+
+    /// Port voltages for a unit incident wave at port 1.
+    pub fn response(&self, frequency: f64) -> CouplerResponse {
+        let c = self.coupling;
+        let root = (1.0 - c * c).sqrt();
+        let (sin, cos) = self.electrical_length(frequency).sin_cos();
+        // Multiplying through by cos θ keeps θ = π/2 finite
+        let den = Complex64::new(root * cos, sin);
+        CouplerResponse {
+            frequency,
+            coupled: Complex64::new(0.0, c * sin) / den,
+            through: Complex64::new(root, 0.0) / den,
+            isolated: Complex64::new(0.0, 0.0),
+        }
+    }
+
+    /// Port voltages over a list of frequencies (Hz).
+    pub fn sweep(&self, frequencies: &[f64]) -> Vec<CouplerResponse> {
+        frequencies.iter().map(|&f| self.response(f)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn even_odd_product_is_z0_squared() {
+        for c in [0.05, 0.316, 0.5, 0.9] {
+            let dc = DirectionalCoupler::new(50.0, c, 1e9);
+            let product = dc.even_mode_impedance() * dc.odd_mode_impedance();
+            assert_relative_eq!(product, 2500.0, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn three_db_coupler_impedance_split() {
+        // C = 1/√2 → Z₀e = Z₀(1 + √2), Z₀o = Z₀(√2 - 1)
+        let dc = DirectionalCoupler::new(50.0, 0.5_f64.sqrt(), 2e9);
+        assert_relative_eq!(dc.even_mode_impedance(), 50.0 * (1.0 + 2f64.sqrt()), epsilon = 1e-9);
+        assert_relative_eq!(dc.odd_mode_impedance(), 50.0 * (2f64.sqrt() - 1.0), epsilon = 1e-9);
+        assert_relative_eq!(dc.coupling_db(), 3.0103, epsilon = 1e-4);
+        let r = dc.response(2e9);
+        assert_relative_eq!(r.coupled.norm_sqr(), 0.5, max_relative = 1e-12);
+        assert_relative_eq!(r.through.norm_sqr(), 0.5, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn coupling_peaks_at_quarter_wave_frequency() {
+        let f0 = 1e9;
+        let dc = DirectionalCoupler::from_coupling_db(50.0, 10.0, f0);
+        let freqs: Vec<f64> = (1..=200).map(|i| i as f64 * 0.01 * f0).collect();
+        let sweep = dc.sweep(&freqs);
+        let i_max = (0..sweep.len())
+            .max_by(|&a, &b| sweep[a].coupled.norm().total_cmp(&sweep[b].coupled.norm()))
+            .unwrap();
+        assert_relative_eq!(freqs[i_max], f0, max_relative = 1e-12);
+        assert_relative_eq!(sweep[i_max].coupled.norm(), dc.coupling, max_relative = 1e-12);
+        // Coupled port leads the through port by 90° at f₀
+        let r = dc.response(f0);
+        assert_relative_eq!((r.coupled / r.through).arg(), PI / 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn lossless_coupler_conserves_power() {
+        let dc = DirectionalCoupler::new(50.0, 0.3, 1e9);
+        for r in dc.sweep(&[0.2e9, 0.7e9, 1e9, 1.6e9]) {
+            let total = r.coupled.norm_sqr() + r.through.norm_sqr() + r.isolated.norm_sqr();
+            assert_relative_eq!(total, 1.0, max_relative = 1e-12);
+        }
+    }
+}
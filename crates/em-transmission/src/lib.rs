@@ -6,6 +6,8 @@
 //! - Smith chart coordinate mapping
 //! - Impedance matching (quarter-wave, L/T/Pi networks, stub tuning)
 //! - Transient response (bounce diagram)
+//! - Cascaded line sections and stubs (ABCD matrices)
+//! - Coupled-line directional couplers
 
 pub mod line_types;
 pub mod smith_chart;
@@ -13,3 +15,5 @@ pub mod standing_waves;
 pub mod matching;
 pub mod stub_tuning;
 pub mod transient;
+pub mod cascade;
+pub mod coupler;
@@ -5,8 +5,16 @@
 //! - Two-wire transmission line
 //! - Coaxial cable
 //! - Microstrip line
+//! - Parallel-plate line (ideal, no fringing)
+//!
+//! Also provides electrical-length helpers (β·l, wrapped and unwrapped) and a
+//! Hammerstad surface-roughness correction for conductor loss.
 
+use em_core::complex::physical_sqrt;
 use em_core::constants::{self, EPSILON_0, MU_0};
+use em_core::coordinates::Vector3;
+use em_core::units::neper_to_db;
+use em_core::{EmCoreResult, validate_frequency};
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -38,9 +46,51 @@ impl LineParameters {
         let omega = 2.0 * PI * frequency;
         let z_series = Complex64::new(self.r_per_m, omega * self.l_per_m);
         let y_shunt = Complex64::new(self.g_per_m, omega * self.c_per_m);
-        let gamma = (z_series * y_shunt).sqrt();
-        // Ensure α ≥ 0
-        if gamma.re < 0.0 { -gamma } else { gamma }
+        physical_sqrt(z_series * y_shunt, true)
+    }
+
+    /// [`Self::characteristic_impedance`], rejecting a frequency ≤ 0.
+    pub fn try_characteristic_impedance(&self, frequency: f64) -> EmCoreResult<Complex64> {
+        validate_frequency(frequency)?;
+        Ok(self.characteristic_impedance(frequency))
+    }
+
+    /// [`Self::propagation_constant`], rejecting a frequency ≤ 0.
+    pub fn try_propagation_constant(&self, frequency: f64) -> EmCoreResult<Complex64> {
+        validate_frequency(frequency)?;
+        Ok(self.propagation_constant(frequency))
+    }
+
+    /// Phase constant β = Im(γ) in rad/m.
+    pub fn phase_constant(&self, frequency: f64) -> f64 {
+        self.propagation_constant(frequency).im
+    }
+
+    /// Sample α(f) and β(f) over a linear frequency sweep.
+    ///
+    /// R, L, G, C are held fixed (no skin effect), so with series loss only
+    /// α ≈ √(ωRC/2) grows as √f while ωL ≪ R and then saturates at R/(2Z₀).
+    /// Skin-effect lines need R recomputed per frequency, e.g. via
+    /// [`CoaxialLine::parameters`].
+    ///
+    /// # Returns
+    /// (frequencies in Hz, α in Np/m, β in rad/m)
+    ///
+    /// # Panics
+    /// Panics if `num_points < 2`.
+    pub fn sample_propagation(
+        &self,
+        f_start: f64,
+        f_end: f64,
+        num_points: usize,
+    ) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        assert!(num_points >= 2, "sample_propagation needs at least 2 points");
+        let df = (f_end - f_start) / (num_points - 1) as f64;
+        let freqs: Vec<f64> = (0..num_points).map(|i| f_start + i as f64 * df).collect();
+        let gammas: Vec<Complex64> = freqs.iter().map(|&f| self.propagation_constant(f)).collect();
+        let alphas = gammas.iter().map(|g| g.re).collect();
+        let betas = gammas.iter().map(|g| g.im).collect();
+        (freqs, alphas, betas)
     }
 
     /// Lossless characteristic impedance Z₀ = √(L/C).
@@ -52,6 +102,56 @@ impl LineParameters {
     pub fn phase_velocity_lossless(&self) -> f64 {
         1.0 / (self.l_per_m * self.c_per_m).sqrt()
     }
+
+    /// Attenuation α = Re(γ) in dB/m.
+    ///
+    /// With `roughness`, the conductor resistance R is scaled by
+    /// [`roughness_factor`] at the conductor's skin depth before γ is formed;
+    /// dielectric loss G is unaffected.
+    pub fn attenuation_db_per_m(
+        &self,
+        frequency: f64,
+        roughness: Option<ConductorRoughness>,
+    ) -> f64 {
+        let factor = roughness.map_or(1.0, |r| r.factor(frequency));
+        let rough = Self {
+            r_per_m: self.r_per_m * factor,
+            ..*self
+        };
+        neper_to_db(rough.propagation_constant(frequency).re)
+    }
+}
+
+/// RMS surface roughness of a non-magnetic conductor.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConductorRoughness {
+    /// RMS surface roughness Δ (m)
+    pub rms_roughness: f64,
+    /// Conductivity of the conductor (S/m)
+    pub conductivity: f64,
+}
+
+impl ConductorRoughness {
+    pub fn new(rms_roughness: f64, conductivity: f64) -> Self {
+        Self {
+            rms_roughness,
+            conductivity,
+        }
+    }
+
+    /// Loss multiplier at `frequency`, using the skin depth with μ = μ₀.
+    pub fn factor(&self, frequency: f64) -> f64 {
+        let delta = constants::skin_depth(frequency, MU_0, self.conductivity);
+        roughness_factor(self.rms_roughness, delta)
+    }
+}
+
+/// Hammerstad roughness correction K = 1 + (2/π)·atan(1.4·(Δ/δ)²).
+///
+/// Multiplies smooth-conductor loss. K = 1 for a smooth surface and rises
+/// toward 2 once the RMS roughness Δ exceeds the skin depth δ.
+pub fn roughness_factor(rms_roughness: f64, skin_depth: f64) -> f64 {
+    1.0 + (2.0 / PI) * (1.4 * (rms_roughness / skin_depth).powi(2)).atan()
 }
 
 /// Two-wire transmission line geometry and parameters.
@@ -69,6 +169,9 @@ pub struct TwoWireLine {
     pub sigma_conductor: f64,
     /// Conductivity of dielectric (S/m)
     pub sigma_dielectric: f64,
+    /// Earth conductivity (S/m) for a ground-return path; `None` ignores the earth
+    #[serde(default)]
+    pub earth_conductivity: Option<f64>,
 }
 
 impl TwoWireLine {
@@ -81,6 +184,45 @@ impl TwoWireLine {
             mu_r: 1.0,
             sigma_conductor: 0.0,
             sigma_dielectric: 0.0,
+            earth_conductivity: None,
+        }
+    }
+
+    /// Include an earth-return path of conductivity `earth_conductivity` (S/m).
+    ///
+    /// [`TwoWireLine::parameters`] then adds the leading Carson terms; see
+    /// [`TwoWireLine::carson_correction`].
+    pub fn with_earth_return(mut self, earth_conductivity: f64) -> Self {
+        self.earth_conductivity = Some(earth_conductivity);
+        self
+    }
+
+    /// Carson equivalent earth-return depth D_e = 1.85/√(ωμ₀σ_e) (m).
+    ///
+    /// Equal to the familiar 658.5·√(ρ/f). Returns `None` without an earth
+    /// return or at DC.
+    pub fn carson_depth(&self, frequency: f64) -> Option<f64> {
+        let sigma_e = self.earth_conductivity?;
+        if frequency <= 0.0 {
+            return None;
+        }
+        Some(1.85 / (2.0 * PI * frequency * MU_0 * sigma_e).sqrt())
+    }
+
+    /// Leading Carson earth-return terms (ΔR, ΔL) per unit length.
+    ///
+    /// - ΔR = μ₀ω/8 (Ω/m), independent of earth conductivity
+    /// - ΔL = (μ₀/2π) · ln(D_e/d) (H/m), with D_e from [`TwoWireLine::carson_depth`]
+    ///
+    /// Valid for D_e ≫ d (low frequency, poorly conducting earth). Returns
+    /// (0, 0) when no earth return is set or at DC.
+    pub fn carson_correction(&self, frequency: f64) -> (f64, f64) {
+        match self.carson_depth(frequency) {
+            Some(d_e) => (
+                MU_0 * 2.0 * PI * frequency / 8.0,
+                MU_0 / (2.0 * PI) * (d_e / self.separation).ln(),
+            ),
+            None => (0.0, 0.0),
         }
     }
 
@@ -89,14 +231,16 @@ impl TwoWireLine {
     /// For d >> a (wire separation >> wire radius):
     /// - L = (μ/π) · acosh(d/(2a)) ≈ (μ/π) · ln(d/a) for d >> a
     /// - C = πε / acosh(d/(2a))
+    ///
+    /// With [`TwoWireLine::with_earth_return`], the Carson correction is added
+    /// to R and L.
     pub fn parameters(&self, frequency: f64) -> LineParameters {
         let mu = self.mu_r * MU_0;
-        let epsilon = self.epsilon_r * EPSILON_0;
-        let ratio = self.separation / (2.0 * self.wire_radius);
-        let acosh_val = (ratio).acosh();
+        let acosh_val = self.acosh_ratio();
+        let (r_earth, l_earth) = self.carson_correction(frequency);
 
-        let l_per_m = mu * acosh_val / PI;
-        let c_per_m = PI * epsilon / acosh_val;
+        let l_per_m = mu * acosh_val / PI + l_earth;
+        let c_per_m = self.capacitance_per_m();
 
         // AC resistance due to skin effect: R = 1/(πaδσ) per wire, ×2 for both wires
         let r_per_m = if self.sigma_conductor > 0.0 && frequency > 0.0 {
@@ -104,7 +248,7 @@ impl TwoWireLine {
             2.0 / (PI * self.wire_radius * delta * self.sigma_conductor)
         } else {
             0.0
-        };
+        } + r_earth;
 
         let g_per_m = if self.sigma_dielectric > 0.0 {
             PI * self.sigma_dielectric / acosh_val
@@ -119,6 +263,61 @@ impl TwoWireLine {
             c_per_m,
         }
     }
+
+    /// [`Self::parameters`], rejecting a frequency ≤ 0.
+    pub fn try_parameters(&self, frequency: f64) -> EmCoreResult<LineParameters> {
+        validate_frequency(frequency)?;
+        Ok(self.parameters(frequency))
+    }
+
+    /// acosh(d/(2a)), the geometry factor shared by L, C and G.
+    fn acosh_ratio(&self) -> f64 {
+        (self.separation / (2.0 * self.wire_radius)).acosh()
+    }
+
+    /// Offset b = √((d/2)² - a²) of the equivalent line charges from the center.
+    fn line_charge_offset(&self) -> f64 {
+        let half_d = self.separation / 2.0;
+        (half_d * half_d - self.wire_radius * self.wire_radius).sqrt()
+    }
+
+    /// Electrostatic capacitance per unit length C = πε / acosh(d/(2a)) (F/m).
+    pub fn capacitance_per_m(&self) -> f64 {
+        PI * self.epsilon_r * EPSILON_0 / self.acosh_ratio()
+    }
+
+    /// Electrostatic potential in the cross-section (V).
+    ///
+    /// Wires are centered at (±d/2, 0); the wire at +d/2 is held at +V/2 and
+    /// the wire at -d/2 at -V/2. Uses the equivalent line charges at (±b, 0):
+    /// φ = V/(2·acosh(d/(2a))) · ln(r₋/r₊)
+    ///
+    /// # Arguments
+    /// * `x`, `y` - Cross-section coordinates (m), outside the conductors
+    /// * `voltage` - Voltage between the wires (V)
+    pub fn potential_at(&self, x: f64, y: f64, voltage: f64) -> f64 {
+        let b = self.line_charge_offset();
+        let r_plus = ((x - b).powi(2) + y * y).sqrt();
+        let r_minus = ((x + b).powi(2) + y * y).sqrt();
+        voltage / (2.0 * self.acosh_ratio()) * (r_minus / r_plus).ln()
+    }
+
+    /// Electric field in the cross-section (V/m), E = -∇φ.
+    ///
+    /// Same geometry and sign convention as [`TwoWireLine::potential_at`].
+    /// The z component is always zero.
+    pub fn field_at(&self, x: f64, y: f64, voltage: f64) -> Vector3 {
+        let b = self.line_charge_offset();
+        let k = voltage / (2.0 * self.acosh_ratio());
+        let (dx_p, dx_m) = (x - b, x + b);
+        let r_plus_sq = dx_p * dx_p + y * y;
+        let r_minus_sq = dx_m * dx_m + y * y;
+        Vector3::new(
+            k * (dx_p / r_plus_sq - dx_m / r_minus_sq),
+            k * (y / r_plus_sq - y / r_minus_sq),
+            0.0,
+        )
+    }
 }
 
 /// Coaxial cable geometry and parameters.
@@ -186,6 +385,12 @@ impl CoaxialLine {
             c_per_m,
         }
     }
+
+    /// [`Self::parameters`], rejecting a frequency ≤ 0.
+    pub fn try_parameters(&self, frequency: f64) -> EmCoreResult<LineParameters> {
+        validate_frequency(frequency)?;
+        Ok(self.parameters(frequency))
+    }
 }
 
 /// Microstrip line geometry and parameters.
@@ -262,15 +467,95 @@ impl MicrostripLine {
     }
 }
 
+/// Parallel-plate line geometry and parameters.
+///
+/// Ideal model: plates much wider than their separation, fringing ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ParallelPlateLine {
+    /// Plate width (m)
+    pub width: f64,
+    /// Plate separation (m)
+    pub separation: f64,
+    /// Relative permittivity of dielectric fill
+    pub epsilon_r: f64,
+}
+
+impl ParallelPlateLine {
+    pub fn new(width: f64, separation: f64, epsilon_r: f64) -> Self {
+        Self {
+            width,
+            separation,
+            epsilon_r,
+        }
+    }
+
+    /// Characteristic impedance Z₀ = η·d/w (Ω).
+    pub fn characteristic_impedance(&self) -> f64 {
+        constants::intrinsic_impedance_relative(self.epsilon_r, 1.0) * self.separation / self.width
+    }
+
+    /// Phase velocity v_p = c/√ε_r (m/s).
+    pub fn phase_velocity(&self) -> f64 {
+        constants::C_0 / self.epsilon_r.sqrt()
+    }
+
+    /// Compute per-unit-length parameters (lossless).
+    ///
+    /// - L = μ·d/w
+    /// - C = ε·w/d
+    pub fn parameters(&self) -> LineParameters {
+        LineParameters {
+            r_per_m: 0.0,
+            l_per_m: MU_0 * self.separation / self.width,
+            g_per_m: 0.0,
+            c_per_m: self.epsilon_r * EPSILON_0 * self.width / self.separation,
+        }
+    }
+}
+
+/// Unwrapped electrical length θ = β·l in radians.
+///
+/// Not reduced modulo 2π, so lengths of cascaded sections add directly.
+pub fn electrical_length(beta: f64, physical_length: f64) -> f64 {
+    beta * physical_length
+}
+
+/// Unwrapped electrical length in wavelengths, l/λ.
+///
+/// Multiply by 2π for the radian form given by [`electrical_length`].
+pub fn electrical_length_wavelengths(physical_length: f64, wavelength: f64) -> f64 {
+    physical_length / wavelength
+}
+
+/// Electrical length β·l reduced to [0, 2π).
+pub fn wrapped_electrical_length(beta: f64, physical_length: f64) -> f64 {
+    electrical_length(beta, physical_length).rem_euclid(2.0 * PI)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
+    use em_core::EmCoreError;
 
     // ================================================================
     // LineParameters tests
     // ================================================================
 
+    #[test]
+    fn try_methods_reject_non_positive_frequency() {
+        let coax = CoaxialLine::lossless(0.5e-3, 1.75e-3, 2.25);
+        let err = coax.try_parameters(0.0).unwrap_err();
+        assert!(matches!(err, EmCoreError::InvalidFrequency { frequency } if frequency == 0.0));
+        let twl = TwoWireLine::lossless(1e-3, 1e-2, 1.0).with_earth_return(0.01);
+        assert!(twl.try_parameters(-60.0).is_err());
+        let p = twl.try_parameters(60.0).unwrap();
+        assert_eq!(p, twl.parameters(60.0));
+        assert!(p.try_characteristic_impedance(0.0).is_err());
+        assert!(p.try_propagation_constant(f64::NAN).is_err());
+        assert_eq!(p.try_propagation_constant(60.0).unwrap(), p.propagation_constant(60.0));
+    }
+
     #[test]
     fn lossless_z0_equals_sqrt_l_over_c() {
         let p = LineParameters {
@@ -350,6 +635,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sampled_beta_grows_linearly_for_lossless_line() {
+        let p = CoaxialLine::lossless(0.5e-3, 2.0e-3, 2.25).parameters(0.0);
+        let (f, a, b) = p.sample_propagation(1e8, 1e9, 10);
+        assert_eq!(f.len(), 10);
+        assert!(a.iter().all(|&v| v.abs() < 1e-12));
+        for i in 0..f.len() {
+            assert_relative_eq!(b[i] / f[i], b[0] / f[0], max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn sampled_alpha_of_series_loss_goes_from_sqrt_f_to_constant() {
+        let p = LineParameters {
+            r_per_m: 10.0,
+            l_per_m: 250e-9,
+            g_per_m: 0.0,
+            c_per_m: 100e-12,
+        };
+        // RC regime (ωL ≪ R): α ∝ √f
+        let (_, a, _) = p.sample_propagation(10.0, 40.0, 2);
+        assert_relative_eq!(a[1] / a[0], 2.0, max_relative = 1e-3);
+        // High-frequency limit: α → R/(2Z₀)
+        let (_, a, _) = p.sample_propagation(1e10, 2e10, 2);
+        let limit = p.r_per_m / (2.0 * p.z0_lossless());
+        assert_relative_eq!(a[0], limit, max_relative = 1e-6);
+        assert_relative_eq!(a[1], limit, max_relative = 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 points")]
+    fn sample_propagation_rejects_empty_sweep() {
+        CoaxialLine::lossless(0.5e-3, 2.0e-3, 1.0).parameters(0.0).sample_propagation(1e8, 1e9, 0);
+    }
+
     // ================================================================
     // Two-wire line tests
     // ================================================================
@@ -387,6 +707,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn two_wire_capacitance_matches_parameters() {
+        let line = TwoWireLine::lossless(1e-3, 10e-3, 2.0);
+        assert_relative_eq!(
+            line.capacitance_per_m(),
+            line.parameters(1e6).c_per_m,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn two_wire_conductor_surfaces_are_equipotentials() {
+        let (a, d, v) = (1e-3, 10e-3, 10.0);
+        let line = TwoWireLine::lossless(a, d, 1.0);
+        for i in 0..36 {
+            let ang = 2.0 * PI * i as f64 / 36.0;
+            let (cx, cy) = (ang.cos() * a, ang.sin() * a);
+            let v_right = line.potential_at(d / 2.0 + cx, cy, v);
+            let v_left = line.potential_at(-d / 2.0 + cx, cy, v);
+            assert_relative_eq!(v_right, v / 2.0, max_relative = 1e-9);
+            assert_relative_eq!(v_left, -v / 2.0, max_relative = 1e-9);
+        }
+        assert_relative_eq!(line.potential_at(0.0, 3e-3, v), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn two_wire_field_strongest_between_wires() {
+        let (a, d, v) = (1e-3, 10e-3, 10.0);
+        let line = TwoWireLine::lossless(a, d, 1.0);
+        let inner = line.field_at(d / 2.0 - a, 0.0, v).magnitude();
+        let outer = line.field_at(d / 2.0 + a, 0.0, v).magnitude();
+        let above = line.field_at(d / 2.0, a, v).magnitude();
+        assert!(inner > outer, "inner-surface field should exceed outer");
+        assert!(inner > above, "inner-surface field should exceed top");
+        // Field points from the +V/2 wire toward the -V/2 wire at the midpoint
+        let mid = line.field_at(0.0, 0.0, v);
+        assert!(mid.x < 0.0);
+        assert_relative_eq!(mid.y, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn two_wire_field_is_negative_gradient_of_potential() {
+        let line = TwoWireLine::lossless(1e-3, 10e-3, 1.0);
+        let (x, y, v, h) = (1.5e-3, 2.0e-3, 5.0, 1e-8);
+        let e = line.field_at(x, y, v);
+        let ex = -(line.potential_at(x + h, y, v) - line.potential_at(x - h, y, v)) / (2.0 * h);
+        let ey = -(line.potential_at(x, y + h, v) - line.potential_at(x, y - h, v)) / (2.0 * h);
+        assert_relative_eq!(e.x, ex, max_relative = 1e-5);
+        assert_relative_eq!(e.y, ey, max_relative = 1e-5);
+    }
+
+    #[test]
+    fn earth_return_adds_positive_resistance() {
+        let line = TwoWireLine::lossless(10e-3, 1.0, 1.0);
+        let grounded = line.with_earth_return(0.01);
+        let dr = grounded.parameters(60.0).r_per_m - line.parameters(60.0).r_per_m;
+        assert!(dr > 0.0);
+        // ΔR = π²f·10⁻⁷ Ω/m ≈ 0.0592 Ω/km at 60 Hz
+        assert_relative_eq!(dr, PI * PI * 60.0 * 1e-7, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn earth_return_inductance_grows_with_carson_depth() {
+        let line = TwoWireLine::lossless(10e-3, 1.0, 1.0);
+        let dl = |sigma_e: f64| {
+            line.with_earth_return(sigma_e).parameters(60.0).l_per_m - line.parameters(60.0).l_per_m
+        };
+        // Drier earth → deeper return current → larger D_e and ΔL
+        let d_e = line.with_earth_return(0.01).carson_depth(60.0).unwrap();
+        assert_relative_eq!(d_e, 658.5 * (100.0_f64 / 60.0).sqrt(), max_relative = 1e-3);
+        assert!(dl(0.01) > 0.0);
+        assert!(dl(0.001) > dl(0.01));
+        // D_e ∝ 1/√σ_e: a tenfold drop in σ_e adds (μ₀/4π)·ln 10
+        let expected = MU_0 / (4.0 * PI) * 10.0_f64.ln();
+        assert_relative_eq!(dl(0.001) - dl(0.01), expected, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn without_earth_return_parameters_are_unchanged() {
+        let mut line = TwoWireLine::lossless(1e-3, 10e-3, 2.0);
+        line.sigma_conductor = 5.8e7;
+        line.sigma_dielectric = 1e-6;
+        assert_eq!(line.carson_correction(1e6), (0.0, 0.0));
+        let grounded = line.with_earth_return(0.01);
+        let mut disabled = grounded;
+        disabled.earth_conductivity = None;
+        assert_eq!(disabled.parameters(1e6), line.parameters(1e6));
+    }
+
     // ================================================================
     // Coaxial line tests
     // ================================================================
@@ -431,6 +840,51 @@ mod tests {
         assert_eq!(p.g_per_m, 0.0);
     }
 
+    // ================================================================
+    // Conductor roughness tests
+    // ================================================================
+
+    fn lossy_coax() -> CoaxialLine {
+        CoaxialLine {
+            sigma_conductor: 5.8e7,
+            ..CoaxialLine::lossless(0.5e-3, 1.75e-3, 2.1)
+        }
+    }
+
+    #[test]
+    fn smooth_conductor_has_unit_roughness_factor() {
+        assert_eq!(roughness_factor(0.0, 1e-6), 1.0);
+        let p = lossy_coax().parameters(1e9);
+        let smooth = p.attenuation_db_per_m(1e9, Some(ConductorRoughness::new(0.0, 5.8e7)));
+        assert_relative_eq!(smooth, p.attenuation_db_per_m(1e9, None), max_relative = 1e-12);
+        assert_relative_eq!(
+            p.attenuation_db_per_m(1e9, None),
+            8.685_889_638_065_037 * p.propagation_constant(1e9).re,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn roughness_factor_saturates_near_two() {
+        assert_relative_eq!(roughness_factor(1.0, 1.0), 1.0 + (2.0 / PI) * 1.4_f64.atan());
+        let k = roughness_factor(20e-6, 1e-6);
+        assert!(k < 2.0 && k > 1.99, "K = {k}");
+    }
+
+    #[test]
+    fn attenuation_increases_with_roughness() {
+        let f = 10e9;
+        let p = lossy_coax().parameters(f);
+        let mut previous = p.attenuation_db_per_m(f, None);
+        for rms in [0.1e-6, 0.3e-6, 0.6e-6, 1e-6, 3e-6] {
+            let alpha = p.attenuation_db_per_m(f, Some(ConductorRoughness::new(rms, 5.8e7)));
+            assert!(alpha > previous, "α({rms}) = {alpha} ≤ {previous}");
+            previous = alpha;
+        }
+        // Conductor loss dominates, so very rough copper nearly doubles α
+        assert!(previous > 1.9 * p.attenuation_db_per_m(f, None));
+    }
+
     // ================================================================
     // Microstrip tests
     // ================================================================
@@ -482,4 +936,83 @@ mod tests {
         let z0_direct = ms.characteristic_impedance();
         assert_relative_eq!(z0_from_params, z0_direct, max_relative = 1e-6);
     }
+
+    // ================================================================
+    // Parallel-plate tests
+    // ================================================================
+
+    #[test]
+    fn parallel_plate_z0_scales_with_d_over_w() {
+        let base = ParallelPlateLine::new(10e-3, 1e-3, 1.0);
+        let wider_gap = ParallelPlateLine::new(10e-3, 2e-3, 1.0);
+        let wider_plates = ParallelPlateLine::new(20e-3, 1e-3, 1.0);
+        assert_relative_eq!(
+            wider_gap.characteristic_impedance(),
+            2.0 * base.characteristic_impedance(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            wider_plates.characteristic_impedance(),
+            0.5 * base.characteristic_impedance(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            base.characteristic_impedance(),
+            constants::ETA_0 / 10.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn parallel_plate_air_phase_velocity_is_c() {
+        let line = ParallelPlateLine::new(5e-3, 1e-3, 1.0);
+        assert_relative_eq!(line.phase_velocity(), constants::C_0, max_relative = 1e-12);
+        let vp = line.parameters().phase_velocity_lossless();
+        assert_relative_eq!(vp, constants::C_0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn parallel_plate_l_and_c_give_z0() {
+        let line = ParallelPlateLine::new(8e-3, 0.5e-3, 4.0);
+        let z0 = line.parameters().z0_lossless();
+        assert_relative_eq!(z0, line.characteristic_impedance(), max_relative = 1e-6);
+    }
+
+    // ================================================================
+    // Electrical length tests
+    // ================================================================
+
+    #[test]
+    fn one_and_a_half_wavelengths_unwrapped_and_wrapped() {
+        let lambda = 0.3;
+        let beta = 2.0 * PI / lambda;
+        let l = 1.5 * lambda;
+        assert_relative_eq!(electrical_length(beta, l), 3.0 * PI, max_relative = 1e-12);
+        assert_relative_eq!(wrapped_electrical_length(beta, l), PI, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn wavelength_form_matches_radian_form() {
+        let lambda = 0.125;
+        let beta = 2.0 * PI / lambda;
+        for &l in &[0.01, 0.2, 1.37] {
+            assert_relative_eq!(
+                2.0 * PI * electrical_length_wavelengths(l, lambda),
+                electrical_length(beta, l),
+                max_relative = 1e-12
+            );
+        }
+        assert_relative_eq!(electrical_length_wavelengths(0.45, 0.3), 1.5, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn phase_constant_of_air_coax() {
+        let f = 1e9;
+        let p = CoaxialLine::lossless(0.5e-3, 2.0e-3, 1.0).parameters(f);
+        assert_relative_eq!(
+            p.phase_constant(f),
+            2.0 * PI * f / constants::C_0,
+            max_relative = 1e-6
+        );
+    }
 }
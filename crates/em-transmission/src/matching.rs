@@ -1,7 +1,9 @@
 //! Impedance matching network design.
 //!
-//! Implements quarter-wave transformer and lumped-element (L-network) matching.
+//! Implements quarter-wave transformer and lumped-element (L-network) matching,
+//! plus a shared numerical bandwidth search for any matching response.
 
+use em_core::{EmCoreError, EmCoreResult};
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -41,15 +43,13 @@ pub fn quarter_wave_single(
     let wavelength = phase_velocity / frequency;
     let length = wavelength / 4.0;
 
-    // Bandwidth: Δf/f₀ = (4/π)·arccos(Γ_m·2·√(Z₀·R_L) / |Z₀ - R_L|)
-    // where Γ_m = (VSWR - 1)/(VSWR + 1)
-    let gamma_m = (max_vswr - 1.0) / (max_vswr + 1.0);
-    let cos_arg = gamma_m * 2.0 * z_t / (z0 - r_load).abs();
-    let bandwidth = if cos_arg.abs() <= 1.0 {
-        2.0 - (4.0 / PI) * cos_arg.acos()
-    } else {
-        2.0 // all frequencies match (load already close to z0)
-    };
+    // The response repeats every 2f₀, so a span of 2 covers the whole passband
+    let bandwidth = fractional_bandwidth(
+        |f| quarter_wave_gamma_vs_frequency(z_t, z0, r_load, frequency, f).norm(),
+        frequency,
+        max_vswr,
+        2.0,
+    );
 
     QuarterWaveTransformer {
         z_transformer: z_t,
@@ -59,6 +59,61 @@ pub fn quarter_wave_single(
     }
 }
 
+/// Fractional bandwidth over which a matching network keeps VSWR ≤ `max_vswr`.
+///
+/// Walks outward from the center frequency until |Γ| exceeds the threshold
+/// Γ_m = (VSWR - 1)/(VSWR + 1), then bisects to locate each band edge.
+///
+/// # Arguments
+/// * `gamma_fn` - |Γ| as a function of frequency (Hz)
+/// * `center_freq` - Design frequency f₀ (Hz)
+/// * `max_vswr` - Maximum acceptable VSWR
+/// * `search_span` - Fractional width searched, centered on f₀ (e.g. 2.0 for 0..2f₀)
+///
+/// # Returns
+/// (f_high - f_low)/f₀. Returns 0 if f₀ itself is not matched, and
+/// `search_span` if the response stays matched across the whole search range.
+pub fn fractional_bandwidth(
+    gamma_fn: impl Fn(f64) -> f64,
+    center_freq: f64,
+    max_vswr: f64,
+    search_span: f64,
+) -> f64 {
+    const STEPS: usize = 200;
+    const BISECTIONS: usize = 60;
+
+    let gamma_max = (max_vswr - 1.0) / (max_vswr + 1.0);
+    // Written so that NaN (e.g. from a component evaluated at f = 0) counts as mismatched
+    let matched = |f: f64| gamma_fn(f) <= gamma_max;
+    if !matched(center_freq) {
+        return 0.0;
+    }
+
+    let half_span = search_span * center_freq / 2.0;
+    let band_edge = |direction: f64| -> f64 {
+        let mut inside = center_freq;
+        for i in 1..=STEPS {
+            let f = center_freq + direction * half_span * i as f64 / STEPS as f64;
+            if !matched(f) {
+                let (mut lo, mut hi) = (inside, f);
+                for _ in 0..BISECTIONS {
+                    let mid = 0.5 * (lo + hi);
+                    if matched(mid) {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                return lo;
+            }
+            inside = f;
+        }
+        inside
+    };
+
+    (band_edge(1.0) - band_edge(-1.0)) / center_freq
+}
+
 /// Multi-section quarter-wave transformer design result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MultiSectionTransformer {
@@ -104,6 +159,103 @@ pub fn quarter_wave_binomial(
     }
 }
 
+/// Design an N-section Chebyshev (equal-ripple) quarter-wave transformer.
+///
+/// Small-reflection synthesis: Γ(θ) = A·e^(-jNθ)·T_N(sec θ_m · cos θ), with
+/// A = ±Γ_m and T_N(sec θ_m) = |ln(R_L/Z₀)|/(2Γ_m). The section reflections
+/// Γ_n are read off the cosine series of T_N, and ln(Z_{n+1}/Z_n) = 2Γ_n.
+///
+/// # Arguments
+/// * `gamma_max` - Passband ripple Γ_m, below |ln(R_L/Z₀)|/2
+pub fn quarter_wave_chebyshev(
+    z0: f64,
+    r_load: f64,
+    frequency: f64,
+    phase_velocity: f64,
+    num_sections: usize,
+    gamma_max: f64,
+) -> MultiSectionTransformer {
+    let n = num_sections;
+    let ln_ratio = (r_load / z0).ln();
+    let t_n_edge = ln_ratio.abs() / (2.0 * gamma_max);
+    assert!(n >= 1 && t_n_edge >= 1.0, "ripple must be below |ln(R_L/Z₀)|/2");
+    let sec_theta_m = (t_n_edge.acosh() / n as f64).cosh();
+    let a = gamma_max * ln_ratio.signum();
+    let cos_series = chebyshev_cosine_series(n, sec_theta_m);
+
+    // Reflections Γ₀..Γ_N are symmetric; section n sits after Γ₀..Γ_n
+    let gamma = |k: usize| {
+        let m = n.abs_diff(2 * k);
+        if m == 0 { a * cos_series[0] } else { a * cos_series[m] / 2.0 }
+    };
+    let mut impedances = Vec::with_capacity(n);
+    let mut z_prev = z0;
+    for k in 0..n {
+        z_prev *= (2.0 * gamma(k)).exp();
+        impedances.push(z_prev);
+    }
+
+    MultiSectionTransformer {
+        section_impedances: impedances,
+        section_length: phase_velocity / (4.0 * frequency),
+        frequency,
+    }
+}
+
+/// Coefficients c_k of T_N(x·cos θ) = Σ c_k·cos(kθ), k = 0..=N (N ≥ 1).
+fn chebyshev_cosine_series(n: usize, x: f64) -> Vec<f64> {
+    // Power-series coefficients of T_N via T_{k+1} = 2y·T_k - T_{k-1}
+    let mut t_prev = vec![1.0];
+    let mut t_cur = vec![0.0, 1.0];
+    for _ in 1..n {
+        let mut t_next = vec![0.0; t_cur.len() + 1];
+        for (i, &c) in t_cur.iter().enumerate() {
+            t_next[i + 1] += 2.0 * c;
+        }
+        for (i, &c) in t_prev.iter().enumerate() {
+            t_next[i] -= c;
+        }
+        t_prev = std::mem::replace(&mut t_cur, t_next);
+    }
+
+    // cos^m θ = 2^(-m) Σ_j C(m, j)·cos((m - 2j)θ)
+    let mut series = vec![0.0; n + 1];
+    for (m, &t_m) in t_cur.iter().enumerate() {
+        let scale = t_m * x.powi(m as i32) * 2.0_f64.powi(-(m as i32));
+        for j in 0..=m {
+            series[m.abs_diff(2 * j)] += scale * binomial(m, j) as f64;
+        }
+    }
+    series
+}
+
+impl MultiSectionTransformer {
+    /// Reflection coefficient seen from Z₀ at `eval_freq`.
+    ///
+    /// Every section is λ/4 at the design frequency, so each has electrical
+    /// length θ = (π/2)·f/f₀; the input impedance is built up recursively
+    /// from the load toward the source.
+    pub fn gamma_at(&self, z0: f64, r_load: f64, eval_freq: f64) -> Complex64 {
+        let theta = PI / 2.0 * eval_freq / self.frequency;
+        let z_in = self
+            .section_impedances
+            .iter()
+            .rev()
+            .fold(Complex64::new(r_load, 0.0), |z, &z_section| {
+                em_core::complex::input_impedance_lossless(z_section, z, theta)
+            });
+        em_core::complex::reflection_coefficient(z_in, Complex64::new(z0, 0.0))
+    }
+
+    /// |Γ| seen from Z₀ at each frequency in `eval_freqs`.
+    pub fn response(&self, z0: f64, r_load: f64, eval_freqs: &[f64]) -> Vec<f64> {
+        eval_freqs
+            .iter()
+            .map(|&f| self.gamma_at(z0, r_load, f).norm())
+            .collect()
+    }
+}
+
 /// Compute binomial coefficient C(n, k).
 fn binomial(n: usize, k: usize) -> usize {
     if k > n {
@@ -120,9 +272,9 @@ fn binomial(n: usize, k: usize) -> usize {
 /// L-network matching topology.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LNetworkTopology {
-    /// Series element first (closer to source), then shunt element
+    /// Series element at the load, shunt element across the source side (R_L < Z₀)
     SeriesShunt,
-    /// Shunt element first, then series element
+    /// Shunt element across the load, series element toward the source (R_L > Z₀)
     ShuntSeries,
 }
 
@@ -147,7 +299,99 @@ pub enum ComponentValue {
     Capacitor { farads: f64 },
 }
 
+impl LNetworkMatch {
+    /// Input impedance seen from the source at `eval_freq`.
+    ///
+    /// Component values are fixed at the design frequency, so their reactances
+    /// scale with frequency. The load impedance is assumed frequency-independent.
+    pub fn input_impedance(&self, z_load: Complex64, eval_freq: f64) -> Complex64 {
+        let omega = 2.0 * PI * eval_freq;
+        let j = Complex64::new(0.0, 1.0);
+        let z_series = j * self.series_component.reactance(omega);
+        let y_shunt = j * self.shunt_component.susceptance(omega);
+        match self.topology {
+            LNetworkTopology::ShuntSeries => z_series + 1.0 / (y_shunt + 1.0 / z_load),
+            LNetworkTopology::SeriesShunt => 1.0 / (y_shunt + 1.0 / (z_series + z_load)),
+        }
+    }
+
+    /// Reflection coefficient seen by a source of impedance Z₀ at `eval_freq`.
+    pub fn gamma_at(&self, z0: f64, z_load: Complex64, eval_freq: f64) -> Complex64 {
+        em_core::complex::reflection_coefficient(
+            self.input_impedance(z_load, eval_freq),
+            Complex64::new(z0, 0.0),
+        )
+    }
+
+    /// Largest element Q at the design frequency: |X|/R of the series arm or
+    /// |B|/G of the shunt arm, whichever is higher.
+    ///
+    /// The arm resistance is R_L for a series element at the load and Z₀ for
+    /// one at the source; likewise for the shunt conductance. A higher Q means
+    /// a narrower match.
+    pub fn q_factor(&self, z0: f64, z_load: Complex64) -> f64 {
+        let (r_series, g_shunt) = match self.topology {
+            LNetworkTopology::SeriesShunt => (z_load.re, 1.0 / z0),
+            LNetworkTopology::ShuntSeries => (z0, (1.0 / z_load).re),
+        };
+        (self.x_series.abs() / r_series).max(self.b_shunt.abs() / g_shunt)
+    }
+
+    /// Fractional bandwidth over which VSWR ≤ `max_vswr` (see [`fractional_bandwidth`]).
+    pub fn fractional_bandwidth(
+        &self,
+        z0: f64,
+        z_load: Complex64,
+        design_freq: f64,
+        max_vswr: f64,
+    ) -> f64 {
+        fractional_bandwidth(
+            |f| self.gamma_at(z0, z_load, f).norm(),
+            design_freq,
+            max_vswr,
+            2.0,
+        )
+    }
+}
+
 impl ComponentValue {
+    /// Reactance X at angular frequency ω (Ω): ωL or -1/(ωC).
+    pub fn reactance(&self, omega: f64) -> f64 {
+        match *self {
+            ComponentValue::Inductor { henries } => omega * henries,
+            ComponentValue::Capacitor { farads } => -1.0 / (omega * farads),
+        }
+    }
+
+    /// Susceptance B at angular frequency ω (S): ωC or -1/(ωL).
+    pub fn susceptance(&self, omega: f64) -> f64 {
+        match *self {
+            ComponentValue::Inductor { henries } => -1.0 / (omega * henries),
+            ComponentValue::Capacitor { farads } => omega * farads,
+        }
+    }
+
+    /// Impedance at `frequency` (Hz) of a real component with quality factor `q`.
+    ///
+    /// Loss is modelled as a series resistance R = |X|/Q, so Z = |X|/Q + jX.
+    /// Pass `f64::INFINITY` for an ideal component.
+    pub fn impedance_at(&self, frequency: f64, q: f64) -> Complex64 {
+        let x = self.reactance(2.0 * PI * frequency);
+        Complex64::new(x.abs() / q, x)
+    }
+
+    /// Self-resonant frequency (Hz) with a parasitic of the opposite kind.
+    ///
+    /// `parasitic` is the shunt capacitance (F) across an inductor, or the
+    /// series inductance (H) of a capacitor: f_SRF = 1/(2π√(LC)).
+    pub fn self_resonant_frequency(&self, parasitic: f64) -> f64 {
+        let lc = match *self {
+            ComponentValue::Inductor { henries } => henries * parasitic,
+            ComponentValue::Capacitor { farads } => farads * parasitic,
+        };
+        1.0 / (2.0 * PI * lc.sqrt())
+    }
+
     fn from_reactance(x: f64, omega: f64) -> Self {
         if x >= 0.0 {
             ComponentValue::Inductor {
@@ -214,7 +458,8 @@ pub fn l_network(z0: f64, z_load: Complex64, frequency: f64) -> Vec<LNetworkMatc
 
                     // More precise: after shunting, the real part should be Z₀
                     // Shunt element: B = ±√((R_L - Z₀)/(Z₀·R_L²))... use direct formula
-                    let b = sign * ((r_l - z0) / (z0 * r_l * r_l + z0 * x_l * x_l)).sqrt();
+                    let mag_sq = r_l * r_l + x_l * x_l;
+                    let b = (x_l + sign * (r_l / z0).sqrt() * (mag_sq - z0 * r_l).sqrt()) / mag_sq;
                     if b.is_nan() {
                         continue;
                     }
@@ -231,12 +476,12 @@ pub fn l_network(z0: f64, z_load: Complex64, frequency: f64) -> Vec<LNetworkMatc
             } else {
                 // Series-shunt topology
                 for sign in [1.0, -1.0] {
-                    let x_s = sign * (z0 * (z0 - r_l)).sqrt() - x_l;
+                    let x_s = sign * (r_l * (z0 - r_l)).sqrt() - x_l;
                     if x_s.is_nan() {
                         continue;
                     }
                     let _x_total = x_s + x_l;
-                    let b = -sign * (z0 - r_l).sqrt() / (z0 * r_l.sqrt());
+                    let b = sign * ((z0 - r_l) / r_l).sqrt() / z0;
                     if b.is_nan() {
                         continue;
                     }
@@ -256,6 +501,25 @@ pub fn l_network(z0: f64, z_load: Complex64, frequency: f64) -> Vec<LNetworkMatc
     solutions
 }
 
+/// |Γ| of an L-network match across a band.
+///
+/// The inductors and capacitors keep their design values, so their
+/// reactances scale with frequency; the load is frequency-independent.
+///
+/// # Arguments
+/// * `solution` - One of the designs returned by [`l_network`]
+/// * `z0` - Source impedance (Ω)
+/// * `z_load` - Load impedance (Ω)
+/// * `freqs` - Frequencies to evaluate (Hz)
+pub fn l_network_response(
+    solution: &LNetworkMatch,
+    z0: f64,
+    z_load: Complex64,
+    freqs: &[f64],
+) -> Vec<f64> {
+    freqs.iter().map(|&f| solution.gamma_at(z0, z_load, f).norm()).collect()
+}
+
 /// Compute the reflection coefficient of a matching network at a given frequency.
 ///
 /// For a quarter-wave transformer section between Z₀ and R_L.
@@ -271,6 +535,30 @@ pub fn quarter_wave_gamma_vs_frequency(
     em_core::complex::reflection_coefficient(z_in, Complex64::new(z0, 0.0))
 }
 
+/// Reflection-free resistive termination for a line: R = Z₀.
+pub fn matched_termination(z0: f64) -> f64 {
+    z0
+}
+
+/// Range of resistive terminations that keep VSWR ≤ `max_vswr` on a line of Z₀.
+///
+/// A real load R gives VSWR = max(R/Z₀, Z₀/R), so the band is [Z₀/s, Z₀·s].
+/// Its ends have Γ = ∓(s − 1)/(s + 1), symmetric about the matched point.
+///
+/// # Returns
+/// (minimum resistance, maximum resistance) in Ω, or
+/// [`EmCoreError::OutOfRange`] if `max_vswr` is below 1.
+pub fn resistor_tolerance_for_vswr(z0: f64, max_vswr: f64) -> EmCoreResult<(f64, f64)> {
+    if max_vswr < 1.0 || max_vswr.is_nan() {
+        return Err(EmCoreError::OutOfRange {
+            name: "max_vswr".into(),
+            value: max_vswr,
+            expected: "VSWR ≥ 1".into(),
+        });
+    }
+    Ok((z0 / max_vswr, z0 * max_vswr))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +643,51 @@ mod tests {
         assert!(z2 > z1 && z2 <= rl * 1.01);
     }
 
+    #[test]
+    fn binomial_response_is_maximally_flat() {
+        let (z0, rl, f0) = (50.0, 100.0, 1e9);
+        let multi = quarter_wave_binomial(z0, rl, f0, em_core::constants::C_0, 3);
+        let g = |df: f64| multi.gamma_at(z0, rl, f0 * (1.0 + df)).norm();
+        assert!(g(0.0) < 1e-3);
+        // Zero slope at f₀, and |Γ| ∝ |cos θ|^N away from it
+        assert_relative_eq!(g(1e-3), g(-1e-3), max_relative = 1e-3);
+        let cubic = ((0.1 * PI).sin() / (0.05 * PI).sin()).powi(3);
+        assert_relative_eq!(g(0.2) / g(0.1), cubic, max_relative = 0.1);
+    }
+
+    #[test]
+    fn chebyshev_response_is_equal_ripple() {
+        let (z0, rl, f0, ripple) = (50.0, 100.0, 1e9, 0.05);
+        let multi = quarter_wave_chebyshev(z0, rl, f0, em_core::constants::C_0, 3, ripple);
+        assert_relative_eq!(multi.section_impedances[2], rl, max_relative = 0.2);
+
+        // Band edge θ_m from T_3(sec θ_m) = ln 2/(2Γ_m)
+        let t_edge = (rl / z0).ln() / (2.0 * ripple);
+        let theta_m = (1.0 / (t_edge.acosh() / 3.0).cosh()).acos();
+        let f_lo = f0 * theta_m / (PI / 2.0);
+        let freqs: Vec<f64> = (0..=400).map(|i| f_lo + i as f64 * (f0 - f_lo) / 400.0).collect();
+        let r = multi.response(z0, rl, &freqs);
+
+        // Ripple peaks at the band edge and at T_3 = ±1 inside, both ≈ Γ_m
+        let inner_peak = r[100..].iter().cloned().fold(0.0, f64::max);
+        assert_relative_eq!(r[0], ripple, max_relative = 0.1);
+        assert_relative_eq!(inner_peak, ripple, max_relative = 0.1);
+        assert!(r.iter().all(|&g| g < 1.1 * ripple));
+        assert!(multi.gamma_at(z0, rl, 0.8 * f_lo).norm() > ripple);
+    }
+
+    #[test]
+    fn single_section_response_matches_quarter_wave() {
+        let (z0, rl, f0) = (50.0, 200.0, 1e9);
+        let multi = quarter_wave_binomial(z0, rl, f0, em_core::constants::C_0, 1);
+        let freqs = [0.5e9, 0.8e9, 1e9, 1.3e9];
+        let z_t = (z0 * rl).sqrt();
+        for (&f, g) in freqs.iter().zip(multi.response(z0, rl, &freqs)) {
+            let expected = quarter_wave_gamma_vs_frequency(z_t, z0, rl, f0, f).norm();
+            assert_relative_eq!(g, expected, max_relative = 1e-9, epsilon = 1e-12);
+        }
+    }
+
     #[test]
     fn binomial_coefficient_known_values() {
         assert_eq!(binomial(4, 0), 1);
@@ -395,4 +728,215 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn l_network_solutions_match_at_design_frequency() {
+        let f = 1e9;
+        let loads = [
+            Complex64::new(100.0, 0.0),
+            Complex64::new(200.0, -80.0),
+            Complex64::new(25.0, 0.0),
+            Complex64::new(10.0, 15.0),
+        ];
+        for zl in loads {
+            let solutions = l_network(50.0, zl, f);
+            assert!(!solutions.is_empty());
+            for sol in &solutions {
+                let g = sol.gamma_at(50.0, zl, f).norm();
+                assert!(g < 1e-9, "L-network for {zl} should match, got |Γ| = {g}");
+            }
+        }
+    }
+
+    #[test]
+    fn l_network_response_is_matched_only_at_design_frequency() {
+        let (f0, zl) = (1e9, Complex64::new(10.0, 15.0));
+        let freqs: Vec<f64> = (0..=20).map(|i| 0.5e9 + i as f64 * 0.05e9).collect();
+        for sol in &l_network(50.0, zl, f0) {
+            let resp = l_network_response(sol, 50.0, zl, &freqs);
+            assert!(resp[10] < 1e-9, "|Γ| at f₀ = {}", resp[10]);
+            // Grows monotonically away from f₀ on both sides
+            assert!(resp[..=10].windows(2).all(|w| w[0] > w[1]));
+            assert!(resp[10..].windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn higher_q_l_network_has_narrower_match() {
+        let (f0, zl) = (1e9, Complex64::new(10.0, 15.0));
+        let sols = l_network(50.0, zl, f0);
+        assert_eq!(sols.len(), 2);
+        let (hi, lo) = if sols[0].q_factor(50.0, zl) > sols[1].q_factor(50.0, zl) {
+            (&sols[0], &sols[1])
+        } else {
+            (&sols[1], &sols[0])
+        };
+        assert!(hi.q_factor(50.0, zl) > lo.q_factor(50.0, zl));
+
+        let freqs: Vec<f64> = (0..=400).map(|i| 0.6e9 + i as f64 * 2e6).collect();
+        let in_band = |s: &LNetworkMatch| {
+            l_network_response(s, 50.0, zl, &freqs).iter().filter(|&&g| g < 0.2).count()
+        };
+        assert!(in_band(hi) < in_band(lo));
+    }
+
+    // ================================================================
+    // Component parasitics
+    // ================================================================
+
+    #[test]
+    fn finite_q_inductor_has_series_resistance() {
+        let l = ComponentValue::Inductor { henries: 10e-9 };
+        let f = 1e9;
+        let z = l.impedance_at(f, 50.0);
+        let x = 2.0 * PI * f * 10e-9;
+        assert_relative_eq!(z.re, x / 50.0, max_relative = 1e-12);
+        assert_relative_eq!(z.im, x, max_relative = 1e-12);
+        assert!(z.re > 0.0);
+    }
+
+    #[test]
+    fn ideal_components_are_purely_reactive() {
+        let f = 2.4e9;
+        let omega = 2.0 * PI * f;
+        let l = ComponentValue::Inductor { henries: 3.3e-9 };
+        let c = ComponentValue::Capacitor { farads: 1.5e-12 };
+        let zl = l.impedance_at(f, f64::INFINITY);
+        let zc = c.impedance_at(f, f64::INFINITY);
+        assert_eq!(zl.re, 0.0);
+        assert_eq!(zc.re, 0.0);
+        assert_relative_eq!(zl.im, omega * 3.3e-9, max_relative = 1e-12);
+        assert_relative_eq!(zc.im, -1.0 / (omega * 1.5e-12), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn inductor_self_resonant_frequency() {
+        let (l, c_par) = (100e-9, 0.2e-12);
+        let srf = ComponentValue::Inductor { henries: l }.self_resonant_frequency(c_par);
+        assert_relative_eq!(srf, 1.0 / (2.0 * PI * (l * c_par).sqrt()), max_relative = 1e-12);
+    }
+
+    // ================================================================
+    // Matched termination
+    // ================================================================
+
+    #[test]
+    fn matched_termination_is_z0() {
+        assert_eq!(matched_termination(75.0), 75.0);
+    }
+
+    #[test]
+    fn resistor_band_is_symmetric_in_gamma() {
+        let z0 = Complex64::new(50.0, 0.0);
+        let (lo, hi) = resistor_tolerance_for_vswr(50.0, 1.5).unwrap();
+        let g_lo = em_core::complex::reflection_coefficient(Complex64::new(lo, 0.0), z0);
+        let g_hi = em_core::complex::reflection_coefficient(Complex64::new(hi, 0.0), z0);
+        assert_relative_eq!(g_lo.re, -g_hi.re, max_relative = 1e-12);
+        assert_relative_eq!(g_hi.re, 0.2, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn unit_vswr_collapses_band_to_z0() {
+        assert_eq!(resistor_tolerance_for_vswr(50.0, 1.0).unwrap(), (50.0, 50.0));
+    }
+
+    #[test]
+    fn vswr_two_admits_half_to_double_z0() {
+        let (lo, hi) = resistor_tolerance_for_vswr(50.0, 2.0).unwrap();
+        assert_relative_eq!(lo, 25.0, max_relative = 1e-12);
+        assert_relative_eq!(hi, 100.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn vswr_below_one_is_rejected() {
+        let err = resistor_tolerance_for_vswr(50.0, 0.8).unwrap_err();
+        assert!(matches!(err, EmCoreError::OutOfRange { value, .. } if value == 0.8));
+        assert!(resistor_tolerance_for_vswr(50.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn previous_l_network_formulas_did_not_match() {
+        // Element values the earlier formulas produced; kept to pin the fix
+        let (z0, f) = (50.0, 1e9);
+        let omega = 2.0 * PI * f;
+        let stale = |topology, x_series: f64, b_shunt: f64| LNetworkMatch {
+            topology,
+            x_series,
+            b_shunt,
+            series_component: ComponentValue::from_reactance(x_series, omega),
+            shunt_component: ComponentValue::from_susceptance(b_shunt, omega),
+        };
+
+        // R_L < Z₀: X = ±√(Z₀(Z₀ - R_L)) - X_L, B = ∓√(Z₀ - R_L)/(Z₀√R_L)
+        let zl = Complex64::new(25.0, 0.0);
+        let old = stale(
+            LNetworkTopology::SeriesShunt,
+            (z0 * (z0 - zl.re)).sqrt(),
+            -(z0 - zl.re).sqrt() / (z0 * zl.re.sqrt()),
+        );
+        assert!(old.gamma_at(z0, zl, f).norm() > 0.1);
+        let new = l_network(z0, zl, f)[0];
+        assert_relative_eq!(new.x_series, 25.0, max_relative = 1e-12);
+        assert_relative_eq!(new.b_shunt, 0.02, max_relative = 1e-12);
+
+        // R_L > Z₀ with reactance: B = √((R_L - Z₀)/(Z₀|Z_L|²)) ignored X_L
+        let zl = Complex64::new(200.0, -80.0);
+        let b = ((zl.re - z0) / (z0 * zl.norm_sqr())).sqrt();
+        let x = 1.0 / b + zl.im * z0 / zl.re - z0 / (b * zl.re);
+        let old = stale(LNetworkTopology::ShuntSeries, x, b);
+        assert!(old.gamma_at(z0, zl, f).norm() > 0.1);
+    }
+
+    // ================================================================
+    // Fractional bandwidth search
+    // ================================================================
+
+    #[test]
+    fn flat_response_returns_full_span() {
+        let bw = fractional_bandwidth(|_| 0.0, 1e9, 1.5, 0.6);
+        assert_relative_eq!(bw, 0.6, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn unmatched_center_returns_zero() {
+        assert_eq!(fractional_bandwidth(|_| 0.9, 1e9, 2.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn narrow_resonance_gives_small_centered_band() {
+        // |Γ| grows linearly away from f₀ with slope 10 per unit fractional detuning
+        let f0 = 1e9;
+        let gamma = |f: f64| (10.0 * (f - f0) / f0).abs();
+        let bw = fractional_bandwidth(gamma, f0, 2.0, 1.0);
+        // Γ_m = 1/3 → edges at ±1/30 → bandwidth 1/15
+        assert_relative_eq!(bw, 1.0 / 15.0, max_relative = 1e-9);
+        assert!(bw < 0.1);
+    }
+
+    #[test]
+    fn qw_bandwidth_matches_textbook_formula() {
+        let (z0, rl, max_vswr) = (50.0, 200.0, 2.0);
+        let t = quarter_wave_single(z0, rl, 1e9, em_core::constants::C_0, max_vswr);
+        let gm: f64 = (max_vswr - 1.0) / (max_vswr + 1.0);
+        let arg = gm / (1.0 - gm * gm).sqrt() * 2.0 * (z0 * rl).sqrt() / (rl - z0).abs();
+        let expected = 2.0 - (4.0 / PI) * arg.acos();
+        assert_relative_eq!(t.bandwidth_fractional, expected, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn qw_matched_load_reports_full_band() {
+        let t = quarter_wave_single(50.0, 50.0, 1e9, em_core::constants::C_0, 1.5);
+        assert_relative_eq!(t.bandwidth_fractional, 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn l_network_bandwidth_narrows_with_higher_q() {
+        let f = 1e9;
+        let low_q = Complex64::new(100.0, 0.0);
+        let high_q = Complex64::new(1000.0, 0.0);
+        let bw_low = l_network(50.0, low_q, f)[0].fractional_bandwidth(50.0, low_q, f, 2.0);
+        let bw_high = l_network(50.0, high_q, f)[0].fractional_bandwidth(50.0, high_q, f, 2.0);
+        assert!(bw_low > 0.0 && bw_high > 0.0);
+        assert!(bw_high < bw_low, "higher transformation ratio → narrower band");
+    }
 }
@@ -7,12 +7,36 @@
 //! - SWR circle computation
 //! - Moving along the transmission line (rotation on Smith chart)
 //! - Q circle computation
+//! - Frequency sweeps of a load (Γ, VSWR, return loss per frequency)
+//! - Γ trajectory of a series RLC load
+//! - Impedance ↔ Γ round-trip residuals for consistency checks
+//! - Amplifier design: constant-gain and constant-noise-figure circles
 
 use em_core::complex::vswr;
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+/// Magnitude substituted for quantities that diverge at a Smith chart pole.
+///
+/// Keeps every field finite so points serialize cleanly to JSON/JS.
+const SINGULAR_CLAMP: f64 = 1e12;
+
+/// Complex division that maps a pole (num/0) to a large finite value in the
+/// direction of the numerator instead of producing NaN or ∞.
+fn finite_div(num: Complex64, den: Complex64) -> Complex64 {
+    let q = num / den;
+    if q.re.is_finite() && q.im.is_finite() {
+        return q;
+    }
+    let mag = num.norm();
+    if mag == 0.0 || !mag.is_finite() {
+        Complex64::new(SINGULAR_CLAMP, 0.0)
+    } else {
+        num / mag * SINGULAR_CLAMP
+    }
+}
+
 /// A point on the Smith chart with both impedance and reflection coefficient representations.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct SmithPoint {
@@ -26,10 +50,13 @@ pub struct SmithPoint {
 
 impl SmithPoint {
     /// Create a Smith chart point from normalized impedance z = r + jx.
+    ///
+    /// At the poles (z = -1 for Γ, z = 0 for y) the diverging quantity is
+    /// clamped to a large finite magnitude rather than NaN.
     pub fn from_impedance(z_normalized: Complex64) -> Self {
         let one = Complex64::new(1.0, 0.0);
-        let gamma = (z_normalized - one) / (z_normalized + one);
-        let y_normalized = one / z_normalized;
+        let gamma = finite_div(z_normalized - one, z_normalized + one);
+        let y_normalized = finite_div(one, z_normalized);
         Self {
             z_normalized,
             gamma,
@@ -38,10 +65,12 @@ impl SmithPoint {
     }
 
     /// Create a Smith chart point from reflection coefficient Γ.
+    ///
+    /// At the open-circuit pole (Γ = 1) z is clamped to a large finite value.
     pub fn from_gamma(gamma: Complex64) -> Self {
         let one = Complex64::new(1.0, 0.0);
-        let z_normalized = (one + gamma) / (one - gamma);
-        let y_normalized = one / z_normalized;
+        let z_normalized = finite_div(one + gamma, one - gamma);
+        let y_normalized = finite_div(one, z_normalized);
         Self {
             z_normalized,
             gamma,
@@ -49,6 +78,14 @@ impl SmithPoint {
         }
     }
 
+    /// Create a Smith chart point from a measured VSWR and reflection phase.
+    ///
+    /// |Γ| = (VSWR - 1)/(VSWR + 1) at angle `gamma_phase_rad`.
+    pub fn from_vswr_and_phase(vswr: f64, gamma_phase_rad: f64) -> Self {
+        let gamma_mag = (vswr - 1.0) / (vswr + 1.0);
+        Self::from_gamma(Complex64::from_polar(gamma_mag, gamma_phase_rad))
+    }
+
     /// Create from actual impedance and characteristic impedance.
     pub fn from_impedance_and_z0(z: Complex64, z0: f64) -> Self {
         Self::from_impedance(z / z0)
@@ -254,12 +291,54 @@ pub fn trace_toward_generator(
 ) -> Vec<SmithPoint> {
     (0..num_points)
         .map(|i| {
-            let beta_l = total_electrical_length * i as f64 / (num_points - 1).max(1) as f64;
+            let beta_l = trace_step(i, num_points, total_electrical_length);
             load_point.move_toward_generator(beta_l)
         })
         .collect()
 }
 
+/// Electrical length βl of step `i` in an evenly spaced trace.
+fn trace_step(i: usize, num_points: usize, total_electrical_length: f64) -> f64 {
+    total_electrical_length * i as f64 / (num_points - 1).max(1) as f64
+}
+
+/// One step of an annotated trace toward the generator.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TracePoint {
+    /// Electrical distance from the load βd (radians)
+    pub beta_l: f64,
+    /// Smith chart point at this position
+    pub point: SmithPoint,
+    /// Impedance looking toward the load at this position (Ω)
+    pub impedance: Complex64,
+}
+
+/// Like [`trace_toward_generator`], but each step carries βd and the actual impedance.
+///
+/// # Arguments
+/// * `load_point` - Smith chart point at the load
+/// * `num_points` - Number of points to trace
+/// * `total_electrical_length` - Total βl in radians
+/// * `z0` - Characteristic impedance used to denormalize (Ω)
+pub fn trace_toward_generator_annotated(
+    load_point: &SmithPoint,
+    num_points: usize,
+    total_electrical_length: f64,
+    z0: f64,
+) -> Vec<TracePoint> {
+    (0..num_points)
+        .map(|i| {
+            let beta_l = trace_step(i, num_points, total_electrical_length);
+            let point = load_point.move_toward_generator(beta_l);
+            TracePoint {
+                beta_l,
+                point,
+                impedance: point.impedance(z0),
+            }
+        })
+        .collect()
+}
+
 /// Q-circle: constant Q = |x|/r on the Smith chart.
 ///
 /// For a given Q value, the circle passes through the origin and center of the chart
@@ -291,6 +370,209 @@ pub fn q_circle_points(q: f64, num_points: usize) -> Vec<(f64, f64)> {
     points
 }
 
+/// One frequency point of a load sweep, bundled for plotting.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SweepPoint {
+    /// Frequency (Hz)
+    pub frequency: f64,
+    /// Reflection coefficient Γ
+    pub gamma: Complex64,
+    /// Voltage standing wave ratio
+    pub vswr: f64,
+    /// Return loss (dB); infinite for a perfect match
+    pub return_loss_db: f64,
+    /// Impedance presented at the reference plane (Ω)
+    pub z_in: Complex64,
+}
+
+/// Sweep a frequency-dependent load and collect Γ, VSWR and return loss.
+///
+/// # Arguments
+/// * `z_load_fn` - Load impedance (Ω) as a function of frequency (Hz)
+/// * `z0` - Reference impedance (Ω)
+/// * `freqs` - Frequencies to evaluate (Hz)
+///
+/// # Returns
+/// One [`SweepPoint`] per entry of `freqs`, in the same order.
+pub fn frequency_sweep(
+    z_load_fn: impl Fn(f64) -> Complex64,
+    z0: f64,
+    freqs: &[f64],
+) -> Vec<SweepPoint> {
+    freqs
+        .iter()
+        .map(|&frequency| {
+            let z_in = z_load_fn(frequency);
+            let sp = SmithPoint::from_impedance_and_z0(z_in, z0);
+            SweepPoint {
+                frequency,
+                gamma: sp.gamma,
+                vswr: sp.vswr(),
+                return_loss_db: sp.return_loss_db(),
+                z_in,
+            }
+        })
+        .collect()
+}
+
+/// Reflection coefficient of a series RLC load at each frequency.
+///
+/// Z = R + jωL + 1/(jωC) traces a constant-r circle as f sweeps: capacitive
+/// (lower half) below f₀ = 1/(2π√(LC)), crossing the real axis at
+/// Γ = (R - Z₀)/(R + Z₀) at resonance, and inductive above.
+///
+/// # Arguments
+/// * `r`, `l`, `c` - Series resistance (Ω), inductance (H), capacitance (F)
+/// * `z0` - Reference impedance (Ω)
+/// * `freqs` - Frequencies to evaluate (Hz), all > 0
+pub fn series_rlc_gamma(r: f64, l: f64, c: f64, z0: f64, freqs: &[f64]) -> Vec<Complex64> {
+    freqs
+        .iter()
+        .map(|&f| {
+            let omega = 2.0 * PI * f;
+            let z = Complex64::new(r, omega * l - 1.0 / (omega * c));
+            SmithPoint::from_impedance_and_z0(z, z0).gamma
+        })
+        .collect()
+}
+
+/// Map a batch of impedances onto the Smith chart in one call.
+///
+/// Equivalent to calling [`SmithPoint::from_impedance_and_z0`] per element,
+/// but avoids per-point call overhead when sweeping many loads.
+///
+/// # Arguments
+/// * `zs` - Load impedances (Ω)
+/// * `z0` - Reference impedance (Ω)
+pub fn smith_points_from_impedances(zs: &[Complex64], z0: f64) -> Vec<SmithPoint> {
+    zs.iter().map(|&z| SmithPoint::from_impedance_and_z0(z, z0)).collect()
+}
+
+/// Residual of the impedance → Γ → impedance round trip for one load.
+///
+/// Maps `z_load` to Γ with [`SmithPoint::from_impedance_and_z0`], rebuilds the
+/// impedance with [`SmithPoint::from_gamma`], and returns |Z' - Z| / (|Z| + Z₀),
+/// which stays meaningful for a short circuit. Intended for integration tests.
+pub fn roundtrip_error(z_load: Complex64, z0: f64) -> f64 {
+    roundtrip_error_with(
+        z_load,
+        z0,
+        |z, z0| SmithPoint::from_impedance_and_z0(z, z0).gamma,
+        |gamma, z0| SmithPoint::from_gamma(gamma).impedance(z0),
+    )
+}
+
+/// Largest [`roundtrip_error`] over a set of loads; 0 for an empty set.
+pub fn max_roundtrip_error(loads: &[Complex64], z0: f64) -> f64 {
+    loads.iter().map(|&z| roundtrip_error(z, z0)).fold(0.0, f64::max)
+}
+
+fn roundtrip_error_with(
+    z_load: Complex64,
+    z0: f64,
+    to_gamma: impl Fn(Complex64, f64) -> Complex64,
+    to_impedance: impl Fn(Complex64, f64) -> Complex64,
+) -> f64 {
+    let z_back = to_impedance(to_gamma(z_load, z0), z0);
+    (z_back - z_load).norm() / (z_load.norm() + z0)
+}
+
+/// Two-port scattering parameters at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SParameters {
+    pub s11: Complex64,
+    pub s12: Complex64,
+    pub s21: Complex64,
+    pub s22: Complex64,
+}
+
+impl SParameters {
+    pub fn new(s11: Complex64, s12: Complex64, s21: Complex64, s22: Complex64) -> Self {
+        Self { s11, s12, s21, s22 }
+    }
+
+    /// Determinant Δ = S₁₁S₂₂ - S₁₂S₂₁.
+    pub fn delta(&self) -> Complex64 {
+        self.s11 * self.s22 - self.s12 * self.s21
+    }
+
+    /// Rollett stability factor K = (1 - |S₁₁|² - |S₂₂|² + |Δ|²) / (2|S₁₂S₂₁|).
+    pub fn stability_k(&self) -> f64 {
+        let num = 1.0 - self.s11.norm_sqr() - self.s22.norm_sqr() + self.delta().norm_sqr();
+        num / (2.0 * (self.s12 * self.s21).norm())
+    }
+
+    /// Unconditionally stable: K > 1 and |Δ| < 1.
+    pub fn is_unconditionally_stable(&self) -> bool {
+        self.stability_k() > 1.0 && self.delta().norm() < 1.0
+    }
+
+    /// Maximum available gain G_max = |S₂₁/S₁₂|·(K - √(K² - 1)) in dB.
+    ///
+    /// Only meaningful for unconditionally stable devices.
+    pub fn max_available_gain_db(&self) -> f64 {
+        let k = self.stability_k();
+        let g = (self.s21 / self.s12).norm() * (k - (k * k - 1.0).sqrt());
+        10.0 * g.log10()
+    }
+
+    /// Load reflection coefficient Γ_L for simultaneous conjugate match.
+    pub fn conjugate_match_gamma_load(&self) -> Complex64 {
+        let delta = self.delta();
+        let b2 = 1.0 + self.s22.norm_sqr() - self.s11.norm_sqr() - delta.norm_sqr();
+        let c2 = self.s22 - delta * self.s11.conj();
+        (b2 - (b2 * b2 - 4.0 * c2.norm_sqr()).sqrt()) / (2.0 * c2)
+    }
+}
+
+/// Constant operating-power-gain circle in the load (Γ_L) plane.
+///
+/// With g_p = G_p/|S₂₁|² and C₂ = S₂₂ - ΔS₁₁*:
+/// - center = g_p·C₂* / (1 + g_p(|S₂₂|² - |Δ|²))
+/// - radius = √(1 - 2K|S₁₂S₂₁|g_p + |S₁₂S₂₁|²g_p²) / |1 + g_p(|S₂₂|² - |Δ|²)|
+///
+/// At G_p = G_max the circle shrinks to the conjugate-match Γ_L.
+///
+/// # Returns
+/// (center, radius) in the Γ plane. The radius is NaN if the requested gain
+/// exceeds the maximum available gain.
+pub fn gain_circle(s_params: &SParameters, gain_db: f64) -> (Complex64, f64) {
+    let delta = s_params.delta();
+    let k = s_params.stability_k();
+    let g_p = 10.0_f64.powf(gain_db / 10.0) / s_params.s21.norm_sqr();
+    let c2 = s_params.s22 - delta * s_params.s11.conj();
+    let denom = 1.0 + g_p * (s_params.s22.norm_sqr() - delta.norm_sqr());
+    let s12s21 = (s_params.s12 * s_params.s21).norm();
+
+    let center = g_p * c2.conj() / denom;
+    // Clamp tiny negative round-off at G_max so the circle degenerates cleanly
+    let radicand = 1.0 - 2.0 * k * s12s21 * g_p + s12s21 * s12s21 * g_p * g_p;
+    let radicand = if radicand < 0.0 && radicand > -1e-9 { 0.0 } else { radicand };
+    (center, radicand.sqrt() / denom.abs())
+}
+
+/// Constant-noise-figure circle in the source (Γ_S) plane.
+///
+/// N = (F - F_min)/(4r_n)·|1 + Γ_opt|², center = Γ_opt/(N + 1),
+/// radius = √(N(N + 1 - |Γ_opt|²))/(N + 1).
+///
+/// # Arguments
+/// * `nf_min` - Minimum noise figure (dB)
+/// * `rn` - Normalized noise resistance r_n = R_N/Z₀
+/// * `gamma_opt` - Optimum source reflection coefficient
+/// * `nf_db` - Noise figure of the circle (dB), ≥ `nf_min`
+///
+/// # Returns
+/// (center, radius) in the Γ plane.
+pub fn noise_circle(nf_min: f64, rn: f64, gamma_opt: Complex64, nf_db: f64) -> (Complex64, f64) {
+    let f = 10.0_f64.powf(nf_db / 10.0);
+    let f_min = 10.0_f64.powf(nf_min / 10.0);
+    let n = (f - f_min) / (4.0 * rn) * (Complex64::new(1.0, 0.0) + gamma_opt).norm_sqr();
+    let center = gamma_opt / (n + 1.0);
+    let radius = (n * (n + 1.0 - gamma_opt.norm_sqr())).max(0.0).sqrt() / (n + 1.0);
+    (center, radius)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,6 +642,39 @@ mod tests {
         assert_relative_eq!(recovered.im, z.im, epsilon = 1e-10);
     }
 
+    fn assert_all_finite(sp: &SmithPoint) {
+        for c in [sp.z_normalized, sp.gamma, sp.y_normalized] {
+            assert!(c.re.is_finite() && c.im.is_finite(), "non-finite field in {sp:?}");
+        }
+    }
+
+    #[test]
+    fn smith_point_at_gamma_pole_has_no_nans() {
+        let sp = SmithPoint::from_impedance(Complex64::new(-1.0, 0.0));
+        assert_all_finite(&sp);
+        assert!(sp.gamma.norm() > 1e9);
+        assert_relative_eq!(sp.y_normalized.re, -1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn smith_point_short_has_finite_admittance() {
+        let sp = SmithPoint::from_impedance(Complex64::new(0.0, 0.0));
+        assert_all_finite(&sp);
+        assert!(sp.y_normalized.re > 1e9);
+    }
+
+    #[test]
+    fn smith_point_open_from_gamma_serializes_finite() {
+        for gamma in [Complex64::new(1.0, 0.0), Complex64::new(1.0 - 1e-15, 0.0)] {
+            let sp = SmithPoint::from_gamma(gamma);
+            assert_all_finite(&sp);
+            let json = serde_json::to_string(&sp).unwrap();
+            assert!(!json.contains("null"), "non-finite value serialized: {json}");
+            let back: SmithPoint = serde_json::from_str(&json).unwrap();
+            assert_all_finite(&back);
+        }
+    }
+
     // ================================================================
     // VSWR and losses
     // ================================================================
@@ -371,6 +686,32 @@ mod tests {
         assert_relative_eq!(sp.vswr(), 2.0, epsilon = 1e-10);
     }
 
+    #[test]
+    fn from_vswr_3_at_zero_phase_gives_gamma_half() {
+        let sp = SmithPoint::from_vswr_and_phase(3.0, 0.0);
+        assert_relative_eq!(sp.gamma.re, 0.5, epsilon = 1e-12);
+        assert_relative_eq!(sp.gamma.im, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_vswr_and_phase_recovers_inputs() {
+        for &(vswr, phase) in &[(1.5, 0.3), (2.0, -2.0), (10.0, PI / 2.0)] {
+            let sp = SmithPoint::from_vswr_and_phase(vswr, phase);
+            assert_relative_eq!(sp.vswr(), vswr, max_relative = 1e-12);
+            assert_relative_eq!(sp.gamma_angle_rad(), phase, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn unit_vswr_is_chart_center_for_any_phase() {
+        for &phase in &[0.0, 1.0, PI, -2.5] {
+            let sp = SmithPoint::from_vswr_and_phase(1.0, phase);
+            assert_relative_eq!(sp.gamma.norm(), 0.0, epsilon = 1e-15);
+            assert_relative_eq!(sp.z_normalized.re, 1.0, epsilon = 1e-12);
+            assert_relative_eq!(sp.z_normalized.im, 0.0, epsilon = 1e-12);
+        }
+    }
+
     #[test]
     fn return_loss_matched_is_infinite() {
         let sp = SmithPoint::from_impedance(Complex64::new(1.0, 0.0));
@@ -561,4 +902,245 @@ mod tests {
             assert_relative_eq!(pt.gamma_magnitude(), expected_mag, epsilon = 1e-12);
         }
     }
+
+    #[test]
+    fn annotated_trace_starts_at_load_impedance() {
+        let zl = Complex64::new(25.0, 40.0);
+        let sp = SmithPoint::from_impedance_and_z0(zl, 50.0);
+        let trace = trace_toward_generator_annotated(&sp, 30, 1.3, 50.0);
+        assert_eq!(trace.len(), 30);
+        assert_eq!(trace[0].beta_l, 0.0);
+        assert_relative_eq!(trace[0].impedance.re, zl.re, max_relative = 1e-12);
+        assert_relative_eq!(trace[0].impedance.im, zl.im, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn annotated_trace_ends_at_generator_impedance() {
+        let zl = Complex64::new(25.0, 40.0);
+        let sp = SmithPoint::from_impedance_and_z0(zl, 50.0);
+        let trace = trace_toward_generator_annotated(&sp, 30, 1.3, 50.0);
+        let last = trace.last().unwrap();
+        let expected = em_core::complex::input_impedance_lossless(50.0, zl, 1.3);
+        assert_relative_eq!(last.beta_l, 1.3, max_relative = 1e-12);
+        assert_relative_eq!(last.impedance.re, expected.re, max_relative = 1e-9);
+        assert_relative_eq!(last.impedance.im, expected.im, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn annotated_trace_beta_l_increases() {
+        let sp = SmithPoint::from_impedance(Complex64::new(2.0, -1.0));
+        let trace = trace_toward_generator_annotated(&sp, 50, 2.0 * PI, 75.0);
+        assert!(trace.windows(2).all(|w| w[1].beta_l > w[0].beta_l));
+    }
+
+    // ================================================================
+    // Frequency sweep
+    // ================================================================
+
+    #[test]
+    fn sweep_of_matched_load_has_unit_vswr() {
+        let freqs: Vec<f64> = (1..=10).map(|i| i as f64 * 1e8).collect();
+        let sweep = frequency_sweep(|_| Complex64::new(50.0, 0.0), 50.0, &freqs);
+        assert_eq!(sweep.len(), freqs.len());
+        for (p, &f) in sweep.iter().zip(&freqs) {
+            assert_relative_eq!(p.frequency, f);
+            assert_relative_eq!(p.vswr, 1.0, epsilon = 1e-12);
+            assert_relative_eq!(p.gamma.norm(), 0.0, epsilon = 1e-12);
+            assert!(p.return_loss_db.is_infinite());
+        }
+    }
+
+    #[test]
+    fn sweep_of_series_rlc_dips_at_resonance() {
+        // R = Z0 with L, C resonant at 1 GHz
+        let (r, l) = (50.0, 40e-9);
+        let f0 = 1e9;
+        let c = 1.0 / ((2.0 * PI * f0).powi(2) * l);
+        let z_load = |f: f64| {
+            let w = 2.0 * PI * f;
+            Complex64::new(r, w * l - 1.0 / (w * c))
+        };
+        let freqs: Vec<f64> = (0..=40).map(|i| 0.8e9 + i as f64 * 0.01e9).collect();
+        let sweep = frequency_sweep(z_load, 50.0, &freqs);
+
+        let best = sweep
+            .iter()
+            .min_by(|a, b| a.vswr.partial_cmp(&b.vswr).unwrap())
+            .unwrap();
+        assert_relative_eq!(best.frequency, f0, max_relative = 1e-9);
+        assert_relative_eq!(best.vswr, 1.0, epsilon = 1e-9);
+        assert!(sweep[0].vswr > 2.0 && sweep[40].vswr > 2.0);
+    }
+
+    /// R < Z₀ series RLC and its resonant frequency.
+    fn series_rlc() -> (f64, f64, f64, f64) {
+        let (r, l, c): (f64, f64, f64) = (20.0, 25e-9, 1e-12);
+        (r, l, c, 1.0 / (2.0 * PI * (l * c).sqrt()))
+    }
+
+    #[test]
+    fn series_rlc_gamma_is_real_at_resonance() {
+        let (r, l, c, f0) = series_rlc();
+        let gamma = series_rlc_gamma(r, l, c, 50.0, &[f0]);
+        assert_relative_eq!(gamma[0].re, -30.0 / 70.0, max_relative = 1e-9);
+        assert!(gamma[0].im.abs() < 1e-9);
+    }
+
+    #[test]
+    fn series_rlc_trajectory_crosses_real_axis_at_resonance() {
+        let (r, l, c, f0) = series_rlc();
+        // Grid straddles f₀ without landing on it
+        let freqs: Vec<f64> = (0..=100).map(|i| f0 * (0.505 + i as f64 * 0.01)).collect();
+        let gammas = series_rlc_gamma(r, l, c, 50.0, &freqs);
+        assert_eq!(gammas.len(), freqs.len());
+        // Capacitive below f₀, inductive above; the sign flips once
+        let crossings: Vec<usize> = (1..gammas.len())
+            .filter(|&i| gammas[i - 1].im < 0.0 && gammas[i].im >= 0.0)
+            .collect();
+        assert_eq!(crossings.len(), 1);
+        let (f_lo, f_hi) = (freqs[crossings[0] - 1], freqs[crossings[0]]);
+        assert!(f_lo < f0 && f0 < f_hi);
+        // Every point stays on the r = R/Z₀ circle
+        let circle = constant_r_circle(r / 50.0);
+        for g in &gammas {
+            let d = (g.re - circle.center_x).hypot(g.im - circle.center_y);
+            assert_relative_eq!(d, circle.radius, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn series_rlc_gamma_magnitude_is_smallest_at_resonance() {
+        let (r, l, c, f0) = series_rlc();
+        let freqs: Vec<f64> = (0..=80).map(|i| f0 * (0.6 + i as f64 * 0.01)).collect();
+        let gammas = series_rlc_gamma(r, l, c, 50.0, &freqs);
+        let i_min = (0..gammas.len())
+            .min_by(|&a, &b| gammas[a].norm().total_cmp(&gammas[b].norm()))
+            .unwrap();
+        assert_relative_eq!(freqs[i_min], f0, max_relative = 1e-9);
+        assert_relative_eq!(gammas[i_min].norm(), 30.0 / 70.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn batch_points_match_per_element_construction() {
+        let zs = [
+            Complex64::new(50.0, 0.0),
+            Complex64::new(100.0, 25.0),
+            Complex64::new(10.0, -40.0),
+            Complex64::new(0.0, 0.0),
+        ];
+        let batch = smith_points_from_impedances(&zs, 50.0);
+        assert_eq!(batch.len(), zs.len());
+        for (p, &z) in batch.iter().zip(&zs) {
+            assert_eq!(*p, SmithPoint::from_impedance_and_z0(z, 50.0));
+        }
+    }
+
+    #[test]
+    fn batch_of_no_impedances_is_empty() {
+        assert!(smith_points_from_impedances(&[], 50.0).is_empty());
+    }
+
+    // ================================================================
+    // Impedance ↔ Γ round trip
+    // ================================================================
+
+    fn load_spread() -> Vec<Complex64> {
+        let mut loads = vec![Complex64::new(0.0, 0.0)];
+        for &r in &[0.1, 1.0, 10.0, 50.0, 300.0, 5000.0] {
+            for &x in &[-2000.0, -75.0, -1.0, 0.0, 1.0, 75.0, 2000.0] {
+                loads.push(Complex64::new(r, x));
+            }
+        }
+        loads
+    }
+
+    #[test]
+    fn roundtrip_residual_is_negligible() {
+        let loads = load_spread();
+        assert!(max_roundtrip_error(&loads, 50.0) < 1e-12);
+        assert!(max_roundtrip_error(&loads, 75.0) < 1e-12);
+    }
+
+    #[test]
+    fn roundtrip_of_no_loads_is_zero() {
+        assert_eq!(max_roundtrip_error(&[], 50.0), 0.0);
+    }
+
+    #[test]
+    fn roundtrip_detects_wrong_formula() {
+        // Test double with the sign of Γ flipped: (Z₀ - Z)/(Z₀ + Z)
+        let wrong_gamma = |z: Complex64, z0: f64| (z0 - z) / (z0 + z);
+        let correct_z = |gamma, z0| SmithPoint::from_gamma(gamma).impedance(z0);
+        let err = roundtrip_error_with(Complex64::new(100.0, 30.0), 50.0, wrong_gamma, correct_z);
+        assert!(err > 0.1, "residual {err}");
+    }
+
+    // ================================================================
+    // Gain and noise circles
+    // ================================================================
+
+    /// Stable GaAs FET at 4 GHz (Pozar Example 12.3-style values).
+    fn stable_fet() -> SParameters {
+        SParameters::new(
+            Complex64::from_polar(0.72, (-116.0_f64).to_radians()),
+            Complex64::from_polar(0.03, 57.0_f64.to_radians()),
+            Complex64::from_polar(2.60, 76.0_f64.to_radians()),
+            Complex64::from_polar(0.73, (-54.0_f64).to_radians()),
+        )
+    }
+
+    #[test]
+    fn reference_device_is_unconditionally_stable() {
+        let sp = stable_fet();
+        assert!(sp.is_unconditionally_stable(), "K = {}", sp.stability_k());
+    }
+
+    #[test]
+    fn max_gain_circle_degenerates_to_conjugate_match() {
+        let sp = stable_fet();
+        let (center, radius) = gain_circle(&sp, sp.max_available_gain_db());
+        let gamma_ml = sp.conjugate_match_gamma_load();
+        assert!(radius < 1e-3, "radius at G_max should vanish, got {radius}");
+        assert_relative_eq!(center.re, gamma_ml.re, epsilon = 1e-6);
+        assert_relative_eq!(center.im, gamma_ml.im, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn lower_gain_gives_larger_circle() {
+        let sp = stable_fet();
+        let g_max = sp.max_available_gain_db();
+        let (_, r1) = gain_circle(&sp, g_max - 1.0);
+        let (_, r3) = gain_circle(&sp, g_max - 3.0);
+        assert!(r1.is_finite() && r3.is_finite());
+        assert!(r3 > r1 && r1 > 0.0);
+    }
+
+    #[test]
+    fn gain_circles_finite_for_stable_device() {
+        let sp = stable_fet();
+        for gain_db in [6.0, 8.0, 10.0, 12.0] {
+            let (center, radius) = gain_circle(&sp, gain_db);
+            assert!(center.re.is_finite() && center.im.is_finite());
+            assert!(radius.is_finite() && radius >= 0.0);
+        }
+    }
+
+    #[test]
+    fn noise_circle_at_nf_min_is_gamma_opt() {
+        let gamma_opt = Complex64::from_polar(0.62, 100.0_f64.to_radians());
+        let (center, radius) = noise_circle(1.6, 0.4, gamma_opt, 1.6);
+        assert_relative_eq!(radius, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(center.re, gamma_opt.re, epsilon = 1e-12);
+        assert_relative_eq!(center.im, gamma_opt.im, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn higher_noise_figure_gives_larger_circle() {
+        let gamma_opt = Complex64::from_polar(0.62, 100.0_f64.to_radians());
+        let (c1, r1) = noise_circle(1.6, 0.4, gamma_opt, 2.0);
+        let (c2, r2) = noise_circle(1.6, 0.4, gamma_opt, 2.5);
+        assert!(r2 > r1);
+        assert!(c2.norm() < c1.norm(), "center moves toward origin as NF grows");
+        assert!(r2 + c2.norm() <= 1.0 + 1e-12, "circle must stay inside the chart");
+    }
 }
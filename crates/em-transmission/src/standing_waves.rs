@@ -13,7 +13,8 @@ use std::f64::consts::PI;
 pub struct StandingWaveParams {
     /// Characteristic impedance Z₀ (Ω)
     pub z0: f64,
-    /// Load impedance (complex, Ω)
+    /// Load impedance (complex, Ω); infinite for an open circuit
+    #[serde(with = "load_impedance_serde")]
     pub z_load: Complex64,
     /// Operating frequency (Hz)
     pub frequency: f64,
@@ -23,6 +24,46 @@ pub struct StandingWaveParams {
     pub length: f64,
 }
 
+/// Serde for a load impedance that may be infinite (open circuit).
+///
+/// JSON has no infinity, so non-finite parts are written as the strings
+/// `"inf"`, `"-inf"` or `"NaN"`; finite loads keep the usual `[re, im]` form.
+mod load_impedance_serde {
+    use num_complex::Complex64;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum Part {
+        Finite(f64),
+        NonFinite(String),
+    }
+
+    fn to_part(x: f64) -> Part {
+        if x.is_finite() {
+            Part::Finite(x)
+        } else {
+            Part::NonFinite(x.to_string())
+        }
+    }
+
+    fn from_part<E: de::Error>(part: Part) -> Result<f64, E> {
+        match part {
+            Part::Finite(x) => Ok(x),
+            Part::NonFinite(s) => s.parse().map_err(E::custom),
+        }
+    }
+
+    pub fn serialize<S: Serializer>(z: &Complex64, serializer: S) -> Result<S::Ok, S::Error> {
+        (to_part(z.re), to_part(z.im)).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Complex64, D::Error> {
+        let (re, im) = <(Part, Part)>::deserialize(deserializer)?;
+        Ok(Complex64::new(from_part(re)?, from_part(im)?))
+    }
+}
+
 impl StandingWaveParams {
     /// Create with explicit beta.
     pub fn new(z0: f64, z_load: Complex64, frequency: f64, beta: f64, length: f64) -> Self {
@@ -47,8 +88,34 @@ impl StandingWaveParams {
         }
     }
 
+    /// Short-circuited line in free space (Z_L = 0): voltage node at the load.
+    pub fn short_circuit(z0: f64, frequency: f64, length: f64) -> Self {
+        Self::in_free_space(z0, Complex64::new(0.0, 0.0), frequency, length)
+    }
+
+    /// Open-circuited line in free space (Z_L = ∞): voltage antinode at the load.
+    ///
+    /// The load is stored as an infinite real impedance; [`Self::gamma_load`]
+    /// maps it to Γ_L = 1.
+    pub fn open_circuit(z0: f64, frequency: f64, length: f64) -> Self {
+        Self::in_free_space(z0, Complex64::new(f64::INFINITY, 0.0), frequency, length)
+    }
+
+    /// Line in free space terminated in a pure reactance Z_L = jX.
+    pub fn reactive_load(z0: f64, reactance: f64, frequency: f64, length: f64) -> Self {
+        Self::in_free_space(z0, Complex64::new(0.0, reactance), frequency, length)
+    }
+
+    /// True if the load is an open circuit (infinite impedance).
+    fn is_open(&self) -> bool {
+        !self.z_load.is_finite()
+    }
+
     /// Reflection coefficient at the load.
     pub fn gamma_load(&self) -> Complex64 {
+        if self.is_open() {
+            return Complex64::new(1.0, 0.0);
+        }
         em_core::complex::reflection_coefficient(self.z_load, Complex64::new(self.z0, 0.0))
     }
 
@@ -78,7 +145,12 @@ impl StandingWaveParams {
     }
 
     /// Input impedance at distance d from the load.
+    ///
+    /// For an open load this is Z(d) = -jZ₀·cot(βd).
     pub fn impedance_at(&self, d: f64) -> Complex64 {
+        if self.is_open() {
+            return Complex64::new(0.0, -self.z0 / (self.beta * d).tan());
+        }
         input_impedance_lossless(self.z0, self.z_load, self.beta * d)
     }
 
@@ -94,6 +166,35 @@ impl StandingWaveParams {
         (ds, vs)
     }
 
+    /// Instantaneous voltage v(d, t) = Re[(e^(jβd) + Γ_L·e^(-jβd))·e^(jωt)] (V⁺ = 1).
+    ///
+    /// The incident wave travels toward the load (decreasing d); its magnitude
+    /// envelope over a period is [`Self::voltage_magnitude`].
+    pub fn instantaneous_voltage(&self, d: f64, t: f64) -> f64 {
+        let omega = 2.0 * PI * self.frequency;
+        let incident = Complex64::from_polar(1.0, self.beta * d);
+        let reflected = self.gamma_load() * Complex64::from_polar(1.0, -self.beta * d);
+        ((incident + reflected) * Complex64::from_polar(1.0, omega * t)).re
+    }
+
+    /// Frames of v(d, t) for animating the standing wave.
+    ///
+    /// `num_frames` equally spaced instants cover one period 1/f; each frame
+    /// holds `num_points` samples on the same grid as [`Self::sample_voltage`].
+    pub fn animation_snapshots(&self, num_points: usize, num_frames: usize) -> Vec<Vec<f64>> {
+        assert!(num_points >= 2);
+        let dd = self.length / (num_points - 1) as f64;
+        let dt = 1.0 / (self.frequency * num_frames as f64);
+        (0..num_frames)
+            .map(|k| {
+                let t = k as f64 * dt;
+                (0..num_points)
+                    .map(|i| self.instantaneous_voltage(i as f64 * dd, t))
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Sample current standing wave pattern.
     pub fn sample_current(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
         assert!(num_points >= 2);
@@ -103,6 +204,26 @@ impl StandingWaveParams {
         (ds, is)
     }
 
+    /// Phase of the reflection coefficient at distance d from the load (rad).
+    ///
+    /// ∠Γ(d) = ∠Γ_L - 2βd, returned unwrapped so it decreases linearly toward
+    /// the generator; wrap with `normalize_angle` for a ±π reading.
+    pub fn gamma_phase_at(&self, d: f64) -> f64 {
+        self.gamma_load().arg() - 2.0 * self.beta * d
+    }
+
+    /// Sample the reflection-coefficient phase along the line.
+    ///
+    /// # Returns
+    /// (distances_from_load, phases_rad)
+    pub fn sample_gamma_phase(&self, num_points: usize) -> (Vec<f64>, Vec<f64>) {
+        assert!(num_points >= 2);
+        let dd = self.length / (num_points - 1) as f64;
+        let ds: Vec<f64> = (0..num_points).map(|i| i as f64 * dd).collect();
+        let phases: Vec<f64> = ds.iter().map(|&d| self.gamma_phase_at(d)).collect();
+        (ds, phases)
+    }
+
     /// Sample impedance along the line.
     ///
     /// # Returns
@@ -128,35 +249,173 @@ impl StandingWaveParams {
 
     /// Distance from load to first voltage minimum.
     ///
-    /// V_min occurs where Γ_L · e^(-j2βd) = -|Γ_L| (i.e., phase = π + ∠Γ_L)
-    /// d_min = (π - ∠Γ_L) / (2β), adjusted to be positive.
+    /// V_min occurs where Γ_L · e^(-j2βd) = -|Γ_L| (i.e., ∠Γ_L - 2βd = -π)
+    /// d_min = (∠Γ_L + π) / (2β), reduced to [0, λ/2).
     pub fn first_voltage_minimum(&self) -> f64 {
         let angle = self.gamma_load().arg();
-        let d = (PI - angle) / (2.0 * self.beta);
-        if d < 0.0 {
-            d + self.wavelength() / 2.0
-        } else {
-            d
-        }
+        ((angle + PI) / (2.0 * self.beta)).rem_euclid(self.wavelength() / 2.0)
     }
 
     /// Distance from load to first voltage maximum.
     ///
     /// V_max occurs where Γ_L · e^(-j2βd) = +|Γ_L|
-    /// d_max = -∠Γ_L / (2β), adjusted to be positive.
+    /// d_max = ∠Γ_L / (2β), reduced to [0, λ/2).
     pub fn first_voltage_maximum(&self) -> f64 {
         let angle = self.gamma_load().arg();
-        let d = -angle / (2.0 * self.beta);
-        if d < 0.0 {
-            d + self.wavelength() / 2.0
-        } else if d == 0.0 && angle == 0.0 {
-            0.0
-        } else {
-            d
+        (angle / (2.0 * self.beta)).rem_euclid(self.wavelength() / 2.0)
+    }
+
+    /// Positions of every voltage minimum on the line, from the load outward.
+    pub fn voltage_minima(&self) -> Vec<f64> {
+        self.repeat_every_half_wave(self.first_voltage_minimum())
+    }
+
+    /// Positions of every voltage maximum on the line, from the load outward.
+    pub fn voltage_maxima(&self) -> Vec<f64> {
+        self.repeat_every_half_wave(self.first_voltage_maximum())
+    }
+
+    /// Positions of every current minimum on the line.
+    ///
+    /// |I(d)| ∝ |1 - Γ(d)|, so current minima sit at the voltage maxima.
+    pub fn current_minima(&self) -> Vec<f64> {
+        self.voltage_maxima()
+    }
+
+    /// Positions of every current maximum on the line.
+    ///
+    /// Current maxima sit at the voltage minima.
+    pub fn current_maxima(&self) -> Vec<f64> {
+        self.voltage_minima()
+    }
+
+    /// Distance from the first voltage maximum to the nearest current maximum.
+    ///
+    /// The current maximum is located numerically from [`Self::current_magnitude`]
+    /// (a coarse scan over λ/2, then bisection on the slope), independently of
+    /// the closed-form extremum positions. Should be λ/4 for any mismatched load.
+    pub fn verify_voltage_current_orthogonality(&self) -> f64 {
+        const STEPS: usize = 360;
+        let d_vmax = self.first_voltage_maximum();
+        let half_wave = self.wavelength() / 2.0;
+        let step = half_wave / STEPS as f64;
+        let at = |k: usize| d_vmax + k as f64 * step;
+        let current = |k: usize| self.current_magnitude(at(k));
+        let k = (0..STEPS)
+            .max_by(|&a, &b| current(a).total_cmp(&current(b)))
+            .unwrap_or(0);
+
+        let h = step * 1e-3;
+        let rising = |d: f64| self.current_magnitude(d + h) > self.current_magnitude(d - h);
+        let (mut lo, mut hi) = (at(k) - step, at(k) + step);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if rising(mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
         }
+        let offset = (0.5 * (lo + hi) - d_vmax).rem_euclid(half_wave);
+        offset.min(half_wave - offset)
+    }
+
+    /// `first`, `first + λ/2`, ... up to the line length.
+    fn repeat_every_half_wave(&self, first: f64) -> Vec<f64> {
+        let half_wave = self.wavelength() / 2.0;
+        (0..)
+            .map(|n| first + n as f64 * half_wave)
+            .take_while(|&d| d <= self.length)
+            .collect()
     }
 }
 
+/// Infer the load impedance from a slotted-line measurement.
+///
+/// |Γ_L| = (VSWR - 1)/(VSWR + 1), and a voltage minimum at distance d_min
+/// from the load means ∠Γ_L - 2β·d_min = -π, so ∠Γ_L = 2β·d_min - π.
+/// Then Z_L = Z₀·(1 + Γ_L)/(1 - Γ_L). Any minimum (d_min + nλ/2) gives the
+/// same load.
+///
+/// There is no `frequency` argument: the inversion depends on frequency only
+/// through β = 2πf/v_p, so the caller passes β and no phase velocity is assumed.
+///
+/// # Arguments
+/// * `z0` - Characteristic impedance (Ω)
+/// * `beta` - Phase constant on the line (rad/m)
+/// * `vmin_position` - Distance from the load to a voltage minimum (m)
+/// * `vswr` - Measured VSWR ≥ 1
+pub fn load_from_probe(z0: f64, beta: f64, vmin_position: f64, vswr: f64) -> Complex64 {
+    let gamma_mag = if vswr.is_infinite() {
+        1.0
+    } else {
+        (vswr - 1.0) / (vswr + 1.0)
+    };
+    let gamma = Complex64::from_polar(gamma_mag, 2.0 * beta * vmin_position - PI);
+    em_core::complex::impedance_from_gamma(gamma, Complex64::new(z0, 0.0))
+}
+
+/// Distances from the load, in wavelengths toward the generator, at which
+/// the line impedance is purely real.
+///
+/// Γ(d) = Γ_L·e^(-j4πd/λ) is real and positive at d = ∠Γ_L/(4π) (voltage
+/// maximum, R = Z₀·VSWR) and real and negative a quarter wave further on
+/// (voltage minimum, R = Z₀/VSWR). Both are reduced to [0, ½); the points
+/// repeat every half wavelength.
+///
+/// # Returns
+/// (d_rmax, d_rmin) in wavelengths; see [`real_impedance_resistances`].
+pub fn distance_to_real_impedance(gamma_load: Complex64) -> (f64, f64) {
+    let angle = gamma_load.arg();
+    let d_rmax = (angle / (4.0 * PI)).rem_euclid(0.5);
+    let d_rmin = ((angle + PI) / (4.0 * PI)).rem_euclid(0.5);
+    (d_rmax, d_rmin)
+}
+
+/// Resistances (R_max, R_min) = (Z₀·VSWR, Z₀/VSWR) seen at the points
+/// returned by [`distance_to_real_impedance`] (Ω).
+pub fn real_impedance_resistances(gamma_load: Complex64, z0: f64) -> (f64, f64) {
+    let vswr = em_core::complex::vswr(gamma_load);
+    (z0 * vswr, z0 / vswr)
+}
+
+/// Upper percentile used as the robust maximum in [`vswr_from_samples`].
+const VSWR_UPPER_PERCENTILE: f64 = 0.95;
+/// Lower percentile used as the robust minimum in [`vswr_from_samples`].
+const VSWR_LOWER_PERCENTILE: f64 = 0.05;
+
+/// Estimate VSWR from sampled |V| values, tolerant of measurement noise.
+///
+/// VSWR ≈ P₉₅/P₅, using the 95th and 5th percentiles instead of the raw
+/// extremes so isolated spikes and dropouts do not inflate the estimate.
+/// On a clean, densely sampled pattern this slightly underestimates the true
+/// VSWR because the flat tops of the pattern are trimmed.
+///
+/// # Returns
+/// VSWR ≥ 1. Returns `f64::INFINITY` if the robust minimum is zero, and 1.0
+/// if there are no finite samples.
+pub fn vswr_from_samples(values: &[f64]) -> f64 {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.is_empty() {
+        return 1.0;
+    }
+    sorted.sort_by(f64::total_cmp);
+    let v_max = percentile(&sorted, VSWR_UPPER_PERCENTILE);
+    let v_min = percentile(&sorted, VSWR_LOWER_PERCENTILE);
+    if v_min <= 0.0 {
+        return f64::INFINITY;
+    }
+    (v_max / v_min).max(1.0)
+}
+
+/// Linearly interpolated percentile of already-sorted, non-empty data.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let pos = p * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,7 +506,77 @@ mod tests {
         // At voltage minimum, current should be at maximum
         let (_, is) = sw.sample_current(10000);
         let i_max: f64 = is.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let i_min: f64 = is.iter().cloned().fold(f64::INFINITY, f64::min);
         assert_relative_eq!(i_at_vmin, i_max, max_relative = 1e-3);
+        assert_relative_eq!(v_at_vmin, 1.0 - sw.gamma_load().norm(), max_relative = 1e-9);
+        let i_quarter = sw.current_magnitude(d_vmin + lambda / 4.0);
+        assert_relative_eq!(i_quarter, i_min, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn current_maxima_coincide_with_voltage_minima() {
+        let sw = StandingWaveParams::in_free_space(50.0, Complex64::new(30.0, 40.0), 1e9, 1.0);
+        let i_max = sw.current_maxima();
+        assert_eq!(i_max.len(), sw.voltage_minima().len());
+        assert!(!i_max.is_empty());
+        let mag = sw.gamma_load().norm();
+        for d in i_max {
+            assert_relative_eq!(sw.current_magnitude(d), 1.0 + mag, max_relative = 1e-9);
+            assert_relative_eq!(sw.voltage_magnitude(d), 1.0 - mag, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn current_minima_coincide_with_voltage_maxima() {
+        let sw = StandingWaveParams::in_free_space(50.0, Complex64::new(30.0, 40.0), 1e9, 1.0);
+        let mag = sw.gamma_load().norm();
+        let i_min = sw.current_minima();
+        assert!(!i_min.is_empty());
+        for d in i_min {
+            assert_relative_eq!(sw.current_magnitude(d), 1.0 - mag, epsilon = 1e-9);
+            assert_relative_eq!(sw.voltage_magnitude(d), 1.0 + mag, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn extrema_are_spaced_by_half_wave_within_line() {
+        let sw = make_test_line();
+        let half = sw.wavelength() / 2.0;
+        let v_max = sw.voltage_maxima();
+        assert!(v_max.iter().all(|&d| (0.0..=sw.length).contains(&d)));
+        for w in v_max.windows(2) {
+            assert_relative_eq!(w[1] - w[0], half, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn reactive_load_extrema_follow_gamma_phase() {
+        // Z_L = j50 on 50 Ω: Γ_L = j, so the minimum sits at 3λ/8 and the maximum at λ/8
+        let sw = StandingWaveParams::in_free_space(50.0, Complex64::new(0.0, 50.0), 1e9, 1.0);
+        let lambda = sw.wavelength();
+        assert_relative_eq!(sw.first_voltage_minimum(), 3.0 * lambda / 8.0, max_relative = 1e-12);
+        assert_relative_eq!(sw.first_voltage_maximum(), lambda / 8.0, max_relative = 1e-12);
+        assert_relative_eq!(sw.voltage_magnitude(3.0 * lambda / 8.0), 0.0, epsilon = 1e-12);
+        // The former d_min = (π - ∠Γ_L)/(2β) = λ/8 landed on the maximum instead
+        assert_relative_eq!(sw.voltage_magnitude(lambda / 8.0), 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn voltage_and_current_maxima_are_quarter_wave_apart() {
+        let loads = [
+            Complex64::new(100.0, 0.0),
+            Complex64::new(10.0, -70.0),
+            Complex64::new(0.0, 0.0),
+        ];
+        for zl in loads {
+            let sw = StandingWaveParams::in_free_space(50.0, zl, 1e9, 1.0);
+            let lambda = sw.wavelength();
+            assert_relative_eq!(
+                sw.verify_voltage_current_orthogonality(),
+                lambda / 4.0,
+                max_relative = 1e-9
+            );
+        }
     }
 
     #[test]
@@ -268,6 +597,27 @@ mod tests {
         assert_relative_eq!(z.im, 0.0, epsilon = 1e-6);
     }
 
+    #[test]
+    fn gamma_phase_at_load_is_load_angle() {
+        let sw = StandingWaveParams::in_free_space(50.0, Complex64::new(30.0, 40.0), 1e9, 1.0);
+        assert_relative_eq!(sw.gamma_phase_at(0.0), sw.gamma_load().arg(), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn gamma_phase_decreases_with_slope_minus_two_beta() {
+        let sw = StandingWaveParams::in_free_space(50.0, Complex64::new(30.0, 40.0), 1e9, 1.0);
+        let (ds, phases) = sw.sample_gamma_phase(101);
+        for i in 1..ds.len() {
+            let slope = (phases[i] - phases[i - 1]) / (ds[i] - ds[i - 1]);
+            assert_relative_eq!(slope, -2.0 * sw.beta, max_relative = 1e-9);
+        }
+        // Wrapped, it matches the phase of Γ(d) = Γ_L·e^(-j2βd)
+        let d = 0.37;
+        let gamma_d = sw.gamma_load() * Complex64::from_polar(1.0, -2.0 * sw.beta * d);
+        let wrapped = em_core::complex::normalize_angle(sw.gamma_phase_at(d));
+        assert_relative_eq!(wrapped, gamma_d.arg(), epsilon = 1e-9);
+    }
+
     #[test]
     fn short_circuit_load_first_vmax_at_quarter_wave() {
         let sw = StandingWaveParams::in_free_space(
@@ -281,6 +631,60 @@ mod tests {
         assert_relative_eq!(d_max, lambda / 4.0, max_relative = 1e-6);
     }
 
+    #[test]
+    fn short_circuit_has_voltage_node_at_load() {
+        let sw = StandingWaveParams::short_circuit(50.0, 1e9, 1.0);
+        assert_relative_eq!(sw.voltage_magnitude(0.0), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(sw.current_magnitude(0.0), 2.0, max_relative = 1e-12);
+        let (_, i) = sw.sample_current(500);
+        assert!(i.iter().all(|&v| v <= sw.current_magnitude(0.0) + 1e-12));
+        assert!(sw.vswr().is_infinite());
+    }
+
+    #[test]
+    fn open_circuit_has_voltage_antinode_at_load() {
+        let sw = StandingWaveParams::open_circuit(50.0, 1e9, 1.0);
+        assert_eq!(sw.gamma_load(), Complex64::new(1.0, 0.0));
+        assert_relative_eq!(sw.voltage_magnitude(0.0), 2.0, max_relative = 1e-12);
+        assert_relative_eq!(sw.current_magnitude(0.0), 0.0, epsilon = 1e-12);
+        let (_, v) = sw.sample_voltage(500);
+        assert!(v.iter().all(|&x| x <= sw.voltage_magnitude(0.0) + 1e-12));
+        assert!(sw.vswr().is_infinite());
+    }
+
+    #[test]
+    fn open_circuit_quarter_wave_looks_like_short() {
+        let sw = StandingWaveParams::open_circuit(50.0, 1e9, 1.0);
+        let z = sw.impedance_at(sw.wavelength() / 4.0);
+        assert!(z.norm() < 1e-6);
+        let z = sw.impedance_at(sw.wavelength() / 8.0);
+        assert_relative_eq!(z.im, -50.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn terminations_round_trip_through_json() {
+        for sw in [
+            StandingWaveParams::open_circuit(50.0, 1e9, 1.0),
+            StandingWaveParams::short_circuit(50.0, 1e9, 1.0),
+            StandingWaveParams::reactive_load(50.0, 30.0, 1e9, 1.0),
+        ] {
+            let json = serde_json::to_string(&sw).unwrap();
+            let back: StandingWaveParams = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, sw);
+            assert_eq!(back.gamma_load(), sw.gamma_load());
+        }
+        let json = serde_json::to_string(&make_test_line()).unwrap();
+        assert!(json.contains(r#""z_load":[100.0,0.0]"#), "{json}");
+    }
+
+    #[test]
+    fn reactive_load_is_total_reflection() {
+        let sw = StandingWaveParams::reactive_load(50.0, 30.0, 1e9, 1.0);
+        assert_relative_eq!(sw.gamma_load().norm(), 1.0, max_relative = 1e-12);
+        assert_relative_eq!(sw.impedance_at(0.0).im, 30.0, max_relative = 1e-12);
+        assert!(sw.vswr() > 1e12);
+    }
+
     #[test]
     fn sample_returns_correct_length() {
         let sw = make_test_line();
@@ -292,4 +696,151 @@ mod tests {
         assert_eq!(r.len(), 150);
         assert_eq!(x.len(), 150);
     }
+
+    // ================================================================
+    // Instantaneous voltage and animation
+    // ================================================================
+
+    #[test]
+    fn instantaneous_envelope_matches_voltage_magnitude() {
+        let line = make_test_line();
+        let (ds, envelope) = line.sample_voltage(41);
+        let frames = line.animation_snapshots(41, 360);
+        assert_eq!(frames.len(), 360);
+        for (i, (&d, &mag)) in ds.iter().zip(&envelope).enumerate() {
+            let peak = frames.iter().map(|f| f[i].abs()).fold(0.0, f64::max);
+            assert_relative_eq!(peak, mag, max_relative = 1e-3);
+            assert_eq!(frames[7][i], line.instantaneous_voltage(d, 7.0 / (360.0 * line.frequency)));
+        }
+    }
+
+    #[test]
+    fn shorted_line_nodes_stay_fixed() {
+        let line = StandingWaveParams::short_circuit(50.0, 1e9, 0.45);
+        let nodes: Vec<f64> = (0..=3).map(|n| n as f64 * line.wavelength() / 2.0).collect();
+        let mut antinode = Vec::new();
+        for k in 0..24 {
+            let t = k as f64 / (24.0 * line.frequency);
+            for &d in &nodes {
+                assert!(line.instantaneous_voltage(d, t).abs() < 1e-12);
+            }
+            antinode.push(line.instantaneous_voltage(line.wavelength() / 4.0, t));
+        }
+        // Antinodes swing through the full ±2 range over the period
+        let max = antinode.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = antinode.iter().copied().fold(f64::INFINITY, f64::min);
+        assert_relative_eq!(max, 2.0, max_relative = 1e-9);
+        assert_relative_eq!(min, -2.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn matched_line_shows_traveling_wave() {
+        let line = StandingWaveParams::in_free_space(50.0, Complex64::new(50.0, 0.0), 1e9, 0.6);
+        let frames = line.animation_snapshots(61, 8);
+        // Every point reaches the same peak: no standing-wave envelope
+        for i in 0..61 {
+            let peak = frames.iter().map(|f| f[i].abs()).fold(0.0, f64::max);
+            assert!(peak > 0.9 && peak <= 1.0 + 1e-12);
+        }
+        // A quarter period later the pattern has moved λ/4 toward the load
+        let lambda = line.wavelength();
+        let t = 1.0 / (4.0 * line.frequency);
+        for d in [0.0, 0.07, 0.2] {
+            let later = line.instantaneous_voltage(d, t);
+            let earlier = line.instantaneous_voltage(d + lambda / 4.0, 0.0);
+            assert_relative_eq!(later, earlier, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn load_from_probe_recovers_known_load() {
+        for zl in [Complex64::new(100.0, 50.0), Complex64::new(20.0, -35.0)] {
+            let sw = StandingWaveParams::in_free_space(50.0, zl, 1e9, 1.0);
+            let d_min = sw.first_voltage_minimum();
+            let z = load_from_probe(50.0, sw.beta, d_min, sw.vswr());
+            assert_relative_eq!(z.re, zl.re, max_relative = 1e-9);
+            assert_relative_eq!(z.im, zl.im, max_relative = 1e-9);
+            // A later minimum gives the same answer
+            let z = load_from_probe(50.0, sw.beta, d_min + sw.wavelength(), sw.vswr());
+            assert_relative_eq!(z.re, zl.re, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn load_from_probe_matched_is_z0() {
+        for d in [0.0, 0.013, 0.21] {
+            let z = load_from_probe(75.0, 20.0, d, 1.0);
+            assert_relative_eq!(z.re, 75.0, max_relative = 1e-12);
+            assert_relative_eq!(z.im, 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn load_from_probe_short_with_infinite_vswr() {
+        let z = load_from_probe(50.0, 20.0, 0.0, f64::INFINITY);
+        assert!(z.norm() < 1e-9);
+    }
+
+    #[test]
+    fn resistive_load_is_real_at_zero_and_quarter_wave() {
+        for z_load in [150.0, 20.0] {
+            let z = Complex64::new(z_load, 0.0);
+            let line = StandingWaveParams::in_free_space(50.0, z, 1e9, 1.0);
+            let (d_rmax, d_rmin) = distance_to_real_impedance(line.gamma_load());
+            assert_relative_eq!(d_rmax.min(d_rmin), 0.0, epsilon = 1e-12);
+            assert_relative_eq!(d_rmax.max(d_rmin), 0.25, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn real_impedance_points_carry_r_max_and_r_min() {
+        let z0 = 50.0;
+        let line = StandingWaveParams::in_free_space(z0, Complex64::new(30.0, 45.0), 1e9, 1.0);
+        let gamma = line.gamma_load();
+        let (d_rmax, d_rmin) = distance_to_real_impedance(gamma);
+        let (r_max, r_min) = real_impedance_resistances(gamma, z0);
+        assert_relative_eq!(r_max, z0 * line.vswr(), max_relative = 1e-12);
+        assert_relative_eq!(r_min, z0 / line.vswr(), max_relative = 1e-12);
+
+        let lambda = line.wavelength();
+        let z_at_max = line.impedance_at(d_rmax * lambda);
+        let z_at_min = line.impedance_at(d_rmin * lambda);
+        assert_relative_eq!(z_at_max.re, r_max, max_relative = 1e-9);
+        assert_relative_eq!(z_at_min.re, r_min, max_relative = 1e-9);
+        assert!(z_at_max.im.abs() < 1e-9 * r_max);
+        assert!(z_at_min.im.abs() < 1e-9 * r_max);
+        // Agrees with the voltage extrema, and the two points are λ/4 apart
+        assert_relative_eq!(d_rmax * lambda, line.first_voltage_maximum(), max_relative = 1e-12);
+        assert_relative_eq!(d_rmin * lambda, line.first_voltage_minimum(), max_relative = 1e-12);
+        assert_relative_eq!((d_rmax - d_rmin).abs(), 0.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn vswr_from_clean_samples_matches_exact() {
+        let sw = make_test_line();
+        let (_, vs) = sw.sample_voltage(2000);
+        assert_relative_eq!(vswr_from_samples(&vs), sw.vswr(), max_relative = 0.02);
+    }
+
+    #[test]
+    fn vswr_from_spiky_samples_resists_outliers() {
+        let sw = make_test_line();
+        let (_, mut vs) = sw.sample_voltage(2000);
+        // A few glitches: two spikes and one dropout
+        vs[137] = 5.0;
+        vs[1402] = 4.0;
+        vs[911] = 0.05;
+
+        let naive = vs.iter().cloned().fold(f64::MIN, f64::max)
+            / vs.iter().cloned().fold(f64::MAX, f64::min);
+        let robust = vswr_from_samples(&vs);
+        assert!(naive > 50.0, "naive max/min should be inflated, got {naive}");
+        assert_relative_eq!(robust, sw.vswr(), max_relative = 0.02);
+    }
+
+    #[test]
+    fn vswr_from_flat_samples_is_one() {
+        assert_relative_eq!(vswr_from_samples(&[0.7; 50]), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(vswr_from_samples(&[]), 1.0, epsilon = 1e-12);
+    }
 }
@@ -28,6 +28,8 @@ pub struct SingleStubResult {
     pub stub_length_wavelengths: f64,
     /// Stub termination type
     pub stub_type: StubType,
+    /// Characteristic impedance of the stub line (Ω)
+    pub stub_z0: f64,
 }
 
 /// Design a single-stub matching network.
@@ -50,6 +52,32 @@ pub fn single_stub(
     phase_velocity: f64,
     stub_type: StubType,
 ) -> [SingleStubResult; 2] {
+    single_stub_with_stub_z0(z0, z_load, frequency, phase_velocity, stub_type, None)
+}
+
+/// Design a single-stub matching network whose stub may use a different Z₀.
+///
+/// Same as [`single_stub`], but the stub can be realized on a line of
+/// characteristic impedance `stub_z0`. The stub length is chosen so its
+/// susceptance, normalized to the main-line Z₀, cancels the line susceptance.
+/// `None` uses the main-line Z₀ for the stub.
+///
+/// # Arguments
+/// * `z0` - Main-line characteristic impedance (Ω)
+/// * `z_load` - Complex load impedance (Ω)
+/// * `frequency` - Operating frequency (Hz)
+/// * `phase_velocity` - Phase velocity on the main line and stub (m/s)
+/// * `stub_type` - Open or short circuit stub
+/// * `stub_z0` - Stub characteristic impedance (Ω), or `None` for `z0`
+pub fn single_stub_with_stub_z0(
+    z0: f64,
+    z_load: Complex64,
+    frequency: f64,
+    phase_velocity: f64,
+    stub_type: StubType,
+    stub_z0: Option<f64>,
+) -> [SingleStubResult; 2] {
+    let stub_z0 = stub_z0.unwrap_or(z0);
     let wavelength = phase_velocity / frequency;
     let beta = 2.0 * PI / wavelength;
 
@@ -112,9 +140,10 @@ pub fn single_stub(
     let b1 = susceptance_at(d1);
     let b2 = susceptance_at(d2);
 
-    // Stub length to produce susceptance -b:
+    // Stub length to produce susceptance -b. A stub of impedance Z_s contributes
+    // (Z₀/Z_s)·b_s to the main-line normalized susceptance, so rescale the target.
     let stub_length_for = |b: f64, stype: StubType| -> f64 {
-        let target_b = -b; // stub must cancel line susceptance
+        let target_b = -b * stub_z0 / z0; // stub must cancel line susceptance
         let l = match stype {
             StubType::Short => {
                 // Short stub: B_stub = -1/tan(βl) (normalized)
@@ -145,6 +174,7 @@ pub fn single_stub(
             stub_distance_wavelengths: d1 / wavelength,
             stub_length_wavelengths: l1 / wavelength,
             stub_type,
+            stub_z0,
         },
         SingleStubResult {
             stub_distance: d2,
@@ -152,10 +182,84 @@ pub fn single_stub(
             stub_distance_wavelengths: d2 / wavelength,
             stub_length_wavelengths: l2 / wavelength,
             stub_type,
+            stub_z0,
         },
     ]
 }
 
+/// |Γ| threshold that defines the matched band in [`stub_bandwidth`] (VSWR 1.5).
+const STUB_BANDWIDTH_GAMMA: f64 = 0.2;
+/// Frequency step of the bandwidth search, as a fraction of the design frequency.
+const STUB_BANDWIDTH_STEP: f64 = 1e-3;
+/// The bandwidth search stops this far (fractionally) either side of the design frequency.
+const STUB_BANDWIDTH_SPAN: f64 = 0.5;
+
+/// Fractional bandwidth (f_hi - f_lo)/f₀ over which a single-stub design keeps
+/// |Γ| ≤ 0.2 (VSWR ≤ 1.5).
+///
+/// The physical lengths are held fixed while the frequency is stepped away from
+/// `frequency` in 0.1% increments, up to ±50%; the load is taken as frequency
+/// independent. Returns 0 if the design is not matched at `frequency` itself.
+pub fn stub_bandwidth(
+    z0: f64,
+    z_load: Complex64,
+    result: &SingleStubResult,
+    frequency: f64,
+    phase_velocity: f64,
+) -> f64 {
+    let matched = |f: f64| {
+        verify_single_stub(z0, z_load, result, f, phase_velocity) <= STUB_BANDWIDTH_GAMMA
+    };
+    if !matched(frequency) {
+        return 0.0;
+    }
+    let max_steps = (STUB_BANDWIDTH_SPAN / STUB_BANDWIDTH_STEP) as usize;
+    let edge = |sign: f64| {
+        (1..=max_steps)
+            .take_while(|&k| matched(frequency * (1.0 + sign * k as f64 * STUB_BANDWIDTH_STEP)))
+            .count()
+    };
+    (edge(1.0) + edge(-1.0)) as f64 * STUB_BANDWIDTH_STEP
+}
+
+/// Both single-stub solutions, ranked best-first by a realizability score.
+///
+/// score = (1 - |Γ|)·B / (1 + d/λ + l/λ), where |Γ| is the residual mismatch at
+/// the design frequency, B the fractional bandwidth from [`stub_bandwidth`] and
+/// d + l the total line length in wavelengths. Higher is better: a good match
+/// on short lines with a broad band.
+pub fn single_stub_ranked(
+    z0: f64,
+    z_load: Complex64,
+    frequency: f64,
+    phase_velocity: f64,
+    stub_type: StubType,
+) -> Vec<(SingleStubResult, f64)> {
+    let mut ranked: Vec<(SingleStubResult, f64)> =
+        single_stub(z0, z_load, frequency, phase_velocity, stub_type)
+            .into_iter()
+            .map(|r| {
+                let gamma = verify_single_stub(z0, z_load, &r, frequency, phase_velocity);
+                let bandwidth = stub_bandwidth(z0, z_load, &r, frequency, phase_velocity);
+                let length = r.stub_distance_wavelengths + r.stub_length_wavelengths;
+                (r, (1.0 - gamma) * bandwidth / (1.0 + length))
+            })
+            .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// Input impedance of a lossless stub of electrical length βl.
+///
+/// Short-circuited: Z = jZ_s·tan(βl). Open-circuited: Z = -jZ_s·cot(βl).
+pub fn stub_input_impedance(stub_type: StubType, stub_z0: f64, beta_l: f64) -> Complex64 {
+    let j = Complex64::new(0.0, 1.0);
+    match stub_type {
+        StubType::Short => j * stub_z0 * beta_l.tan(),
+        StubType::Open => -j * stub_z0 / beta_l.tan(),
+    }
+}
+
 /// Verify a single-stub solution by computing the reflection coefficient at the input.
 pub fn verify_single_stub(
     z0: f64,
@@ -170,19 +274,8 @@ pub fn verify_single_stub(
     // Input impedance of line section from load to stub
     let z_at_stub = em_core::complex::input_impedance_lossless(z0, z_load, beta * result.stub_distance);
 
-    // Stub input impedance
-    let z_stub = match result.stub_type {
-        StubType::Short => {
-            // Short-circuited stub: Z = jZ₀·tan(βl)
-            let j = Complex64::new(0.0, 1.0);
-            j * z0 * (beta * result.stub_length).tan()
-        }
-        StubType::Open => {
-            // Open-circuited stub: Z = -jZ₀/tan(βl) = -jZ₀·cot(βl)
-            let j = Complex64::new(0.0, 1.0);
-            -j * z0 / (beta * result.stub_length).tan()
-        }
-    };
+    // Stub input impedance (on the stub's own characteristic impedance)
+    let z_stub = stub_input_impedance(result.stub_type, result.stub_z0, beta * result.stub_length);
 
     // Parallel combination at stub point (use admittances)
     let y_line = Complex64::new(1.0, 0.0) / z_at_stub;
@@ -292,4 +385,85 @@ mod tests {
             );
         }
     }
+
+    // ================================================================
+    // Ranking by realizability
+    // ================================================================
+
+    #[test]
+    fn ranked_returns_both_solutions_best_first() {
+        let (z0, zl, f, vp) = test_params();
+        for stype in [StubType::Short, StubType::Open] {
+            let ranked = single_stub_ranked(z0, zl, f, vp, stype);
+            assert_eq!(ranked.len(), 2);
+            assert!(ranked[0].1 >= ranked[1].1);
+            let (best, score) = ranked[0];
+            assert!(score > 0.0);
+            let g = verify_single_stub(z0, zl, &best, f, vp);
+            assert!(g < 0.05, "top-ranked |Γ| should be < 0.05, got {g}");
+        }
+    }
+
+    #[test]
+    fn shorter_broader_solution_outranks_longer() {
+        let (z0, zl, f, vp) = test_params();
+        let ranked = single_stub_ranked(z0, zl, f, vp, StubType::Short);
+        let total = |r: &SingleStubResult| r.stub_distance_wavelengths + r.stub_length_wavelengths;
+        let (best, worst) = (&ranked[0].0, &ranked[1].0);
+        assert!(total(best) < total(worst));
+        let bw_best = stub_bandwidth(z0, zl, best, f, vp);
+        let bw_worst = stub_bandwidth(z0, zl, worst, f, vp);
+        assert!(bw_best > bw_worst, "bandwidths {bw_best} vs {bw_worst}");
+    }
+
+    #[test]
+    fn mismatched_design_has_no_bandwidth() {
+        let (z0, zl, f, vp) = test_params();
+        let mut r = single_stub(z0, zl, f, vp, StubType::Short)[0];
+        r.stub_length += 0.1 * vp / f;
+        assert_eq!(stub_bandwidth(z0, zl, &r, f, vp), 0.0);
+    }
+
+    // ================================================================
+    // Stub on a different characteristic impedance
+    // ================================================================
+
+    #[test]
+    fn stub_z0_equal_to_main_line_is_unchanged() {
+        let (z0, zl, f, vp) = test_params();
+        for stype in [StubType::Short, StubType::Open] {
+            let base = single_stub(z0, zl, f, vp, stype);
+            let same = single_stub_with_stub_z0(z0, zl, f, vp, stype, Some(z0));
+            assert_eq!(base, same);
+        }
+    }
+
+    #[test]
+    fn higher_impedance_stub_needs_different_length() {
+        let (z0, zl, f, vp) = test_params();
+        let base = single_stub(z0, zl, f, vp, StubType::Short);
+        let high = single_stub_with_stub_z0(z0, zl, f, vp, StubType::Short, Some(100.0));
+        for (a, b) in base.iter().zip(high.iter()) {
+            assert_relative_eq!(a.stub_distance, b.stub_distance, epsilon = 1e-12);
+            assert!(
+                (a.stub_length - b.stub_length).abs() > 1e-4,
+                "stub length should change with stub Z₀"
+            );
+            assert_eq!(b.stub_z0, 100.0);
+        }
+    }
+
+    #[test]
+    fn different_stub_z0_still_achieves_match() {
+        let (z0, zl, f, vp) = test_params();
+        for stype in [StubType::Short, StubType::Open] {
+            for stub_z0 in [30.0, 75.0, 120.0] {
+                let results = single_stub_with_stub_z0(z0, zl, f, vp, stype, Some(stub_z0));
+                for r in &results {
+                    let g = verify_single_stub(z0, zl, r, f, vp);
+                    assert!(g < 0.05, "|Γ| should be < 0.05 with Z_s = {stub_z0}, got {g}");
+                }
+            }
+        }
+    }
 }
@@ -2,8 +2,9 @@
 //!
 //! Implements the bounce diagram method for computing voltage and current
 //! transient response on a lossless transmission line with resistive
-//! source and load impedances driven by a step or pulse source.
+//! source and load impedances driven by a step, pulse or ramped-step source.
 
+use em_core::{EmCoreError, EmCoreResult};
 use serde::{Deserialize, Serialize};
 
 /// Source waveform for transient analysis.
@@ -13,6 +14,8 @@ pub enum SourceWaveform {
     Step { voltage: f64 },
     /// Pulse: V(t) = V₀ for 0 ≤ t < duration
     Pulse { voltage: f64, duration: f64 },
+    /// Step with a finite rise time: V(t) = V₀·min(t/rise_time, 1) for t ≥ 0
+    Ramp { voltage: f64, rise_time: f64 },
 }
 
 impl SourceWaveform {
@@ -26,6 +29,18 @@ impl SourceWaveform {
             SourceWaveform::Pulse { voltage, duration } => {
                 if t < *duration { *voltage } else { 0.0 }
             }
+            SourceWaveform::Ramp { voltage, rise_time } => {
+                if t >= *rise_time { *voltage } else { voltage * t / rise_time }
+            }
+        }
+    }
+
+    /// Final (step) or peak voltage V₀ of the waveform.
+    pub fn amplitude(&self) -> f64 {
+        match *self {
+            SourceWaveform::Step { voltage }
+            | SourceWaveform::Pulse { voltage, .. }
+            | SourceWaveform::Ramp { voltage, .. } => voltage,
         }
     }
 }
@@ -77,7 +92,193 @@ pub struct TransientResult {
     pub steady_state_voltage: f64,
 }
 
+/// Time steps used by [`TransientResult::energy_audit`].
+const ENERGY_AUDIT_STEPS: usize = 20_000;
+
+impl TransientResult {
+    /// Energy bookkeeping from t = 0 to `t_end` by integrating instantaneous power.
+    ///
+    /// The difference `supplied - load - source` is the energy still stored on
+    /// the line, which stays bounded, so the three terms balance as t → ∞.
+    /// Currents are taken from the line side, I = (V⁺ - V⁻)/Z₀ at each end, so
+    /// an ideal source (R_S = 0) or a shorted load (R_L = 0) is handled.
+    ///
+    /// # Arguments
+    /// * `params` - The parameters this result was solved from
+    /// * `t_end` - End of the integration window (s)
+    ///
+    /// # Returns
+    /// (energy supplied by the source, energy absorbed by R_L,
+    /// energy dissipated in R_S), all in joules.
+    pub fn energy_audit(&self, params: &TransientParams, t_end: f64) -> (f64, f64, f64) {
+        let dt = t_end / ENERGY_AUDIT_STEPS as f64;
+        let launch_ratio = params.z0 / (params.z0 + params.r_source);
+        let (mut supplied, mut load, mut source) = (0.0, 0.0, 0.0);
+
+        for i in 0..ENERGY_AUDIT_STEPS {
+            let t = (i as f64 + 0.5) * dt;
+            let (fwd_in, bwd_in, fwd_load) = self.end_waves(&params.source, t);
+            let (fwd_in, bwd_in) = (fwd_in * launch_ratio, bwd_in * launch_ratio);
+            let fwd_load = fwd_load * launch_ratio;
+
+            let i_in = (fwd_in - bwd_in) / params.z0;
+            let v_load = (1.0 + self.gamma_load) * fwd_load;
+            let i_load = (1.0 - self.gamma_load) * fwd_load / params.z0;
+            supplied += params.source.evaluate(t) * i_in * dt;
+            source += i_in * i_in * params.r_source * dt;
+            load += v_load * i_load * dt;
+        }
+
+        (supplied, load, source)
+    }
+
+    /// Voltages at the line input and at the load per volt launched, for an
+    /// arbitrary source waveform.
+    ///
+    /// Each round trip delays a copy of the launched waveform by 2T_d and
+    /// scales it by Γ_L·Γ_S; the line is linear, so the copies superpose:
+    /// v_load(t) = (1 + Γ_L)·Σ (Γ_LΓ_S)ⁿ·v_s(t - (2n+1)T_d).
+    fn end_voltages(&self, source: &SourceWaveform, t: f64) -> (f64, f64) {
+        let (fwd_in, bwd_in, fwd_load) = self.end_waves(source, t);
+        (fwd_in + bwd_in, (1.0 + self.gamma_load) * fwd_load)
+    }
+
+    /// Traveling-wave components per volt launched: (V⁺ leaving the input,
+    /// V⁻ arriving at the input, V⁺ arriving at the load).
+    fn end_waves(&self, source: &SourceWaveform, t: f64) -> (f64, f64, f64) {
+        let td = self.transit_time;
+        let round_trip = self.gamma_load * self.gamma_source;
+        let (mut bwd_in, mut fwd_load) = (0.0, 0.0);
+        let mut v_fwd = 1.0;
+        let mut n = 0;
+        while (2 * n + 1) as f64 * td <= t {
+            let arrival = source.evaluate(t - (2 * n + 1) as f64 * td);
+            let return_trip = source.evaluate(t - (2 * n + 2) as f64 * td);
+            fwd_load += v_fwd * arrival;
+            bwd_in += v_fwd * self.gamma_load * return_trip;
+            v_fwd *= round_trip;
+            if v_fwd == 0.0 {
+                break;
+            }
+            n += 1;
+        }
+        let fwd_in = source.evaluate(t) + self.gamma_source * bwd_in;
+        (fwd_in, bwd_in, fwd_load)
+    }
+}
+
+/// Fluent builder for [`TransientParams`].
+///
+/// Every setting is required; `build()` reports the first missing or
+/// non-physical value.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TransientParamsBuilder {
+    z0: Option<f64>,
+    r_source: Option<f64>,
+    r_load: Option<f64>,
+    line: Option<(f64, f64)>,
+    source: Option<SourceWaveform>,
+}
+
+impl TransientParamsBuilder {
+    /// Characteristic impedance Z₀ (Ω).
+    pub fn z0(mut self, z0: f64) -> Self {
+        self.z0 = Some(z0);
+        self
+    }
+
+    /// Source resistance (Ω).
+    pub fn source_resistance(mut self, r_source: f64) -> Self {
+        self.r_source = Some(r_source);
+        self
+    }
+
+    /// Load resistance (Ω).
+    pub fn load(mut self, r_load: f64) -> Self {
+        self.r_load = Some(r_load);
+        self
+    }
+
+    /// Line length (m) and phase velocity (m/s).
+    pub fn line(mut self, length: f64, phase_velocity: f64) -> Self {
+        self.line = Some((length, phase_velocity));
+        self
+    }
+
+    /// Drive the line with a step of the given voltage.
+    pub fn step(mut self, voltage: f64) -> Self {
+        self.source = Some(SourceWaveform::Step { voltage });
+        self
+    }
+
+    /// Drive the line with a rectangular pulse.
+    pub fn pulse(mut self, voltage: f64, duration: f64) -> Self {
+        self.source = Some(SourceWaveform::Pulse { voltage, duration });
+        self
+    }
+
+    /// Drive the line with a step that ramps linearly to `voltage` over `rise_time`.
+    pub fn ramp(mut self, voltage: f64, rise_time: f64) -> Self {
+        self.source = Some(SourceWaveform::Ramp { voltage, rise_time });
+        self
+    }
+
+    /// Validate the settings and assemble the parameters.
+    ///
+    /// Z₀, line length and phase velocity must be positive; source and load
+    /// resistances must be non-negative.
+    pub fn build(self) -> EmCoreResult<TransientParams> {
+        let z0 = required("z0", self.z0)?;
+        let r_source = required("r_source", self.r_source)?;
+        let r_load = required("r_load", self.r_load)?;
+        let (length, phase_velocity) = required("line", self.line)?;
+        let source = required("source", self.source)?;
+
+        check_range("z0", z0, z0 > 0.0, "Z₀ > 0")?;
+        check_range("r_source", r_source, r_source >= 0.0, "R_S ≥ 0")?;
+        check_range("r_load", r_load, r_load >= 0.0, "R_L ≥ 0")?;
+        check_range("length", length, length > 0.0, "l > 0")?;
+        check_range("phase_velocity", phase_velocity, phase_velocity > 0.0, "v_p > 0")?;
+        if let SourceWaveform::Ramp { rise_time, .. } = source {
+            check_range("rise_time", rise_time, rise_time >= 0.0, "t_r ≥ 0")?;
+        }
+
+        Ok(TransientParams {
+            z0,
+            r_source,
+            r_load,
+            length,
+            phase_velocity,
+            source,
+        })
+    }
+}
+
+fn required<T>(name: &str, value: Option<T>) -> EmCoreResult<T> {
+    value.ok_or_else(|| EmCoreError::InvalidParameter {
+        name: name.into(),
+        reason: "not set".into(),
+    })
+}
+
+fn check_range(name: &str, value: f64, ok: bool, expected: &str) -> EmCoreResult<()> {
+    if ok {
+        Ok(())
+    } else {
+        Err(EmCoreError::OutOfRange {
+            name: name.into(),
+            value,
+            expected: expected.into(),
+        })
+    }
+}
+
 impl TransientParams {
+    /// Start a fluent [`TransientParamsBuilder`].
+    pub fn builder() -> TransientParamsBuilder {
+        TransientParamsBuilder::default()
+    }
+
     /// One-way transit time T_d = l / v_p.
     pub fn transit_time(&self) -> f64 {
         self.length / self.phase_velocity
@@ -106,10 +307,7 @@ impl TransientParams {
         let gamma_l = self.gamma_load();
 
         // Initial voltage launched: V₁ = V_source(0) · Z₀/(Z₀ + R_S)
-        let source_v = match self.source {
-            SourceWaveform::Step { voltage } => voltage,
-            SourceWaveform::Pulse { voltage, .. } => voltage,
-        };
+        let source_v = self.source.amplitude();
         let v_initial = source_v * self.z0 / (self.z0 + self.r_source);
 
         // Steady state for step source
@@ -164,10 +362,7 @@ impl TransientParams {
         let td = self.transit_time();
         let gamma_s = self.gamma_source();
         let gamma_l = self.gamma_load();
-        let v1 = match self.source {
-            SourceWaveform::Step { voltage } => voltage,
-            SourceWaveform::Pulse { voltage, .. } => voltage,
-        } * self.z0 / (self.z0 + self.r_source);
+        let v1 = self.source.amplitude() * self.z0 / (self.z0 + self.r_source);
 
         let travel_time_to_x = x / self.phase_velocity;
         let travel_time_to_end = (self.length - x) / self.phase_velocity;
@@ -222,6 +417,9 @@ impl TransientParams {
 
     /// Sample voltage at the load vs time.
     ///
+    /// Every bounce carries a delayed, scaled copy of the source waveform, so
+    /// a ramped source rounds each step edge over its rise time.
+    ///
     /// # Returns
     /// (time_values, voltage_values)
     pub fn sample_load_voltage(
@@ -230,48 +428,67 @@ impl TransientParams {
         num_points: usize,
     ) -> (Vec<f64>, Vec<f64>) {
         assert!(num_points >= 2);
-        let td = self.transit_time();
-        let gamma_s = self.gamma_source();
-        let gamma_l = self.gamma_load();
+        let launch_ratio = self.z0 / (self.z0 + self.r_source);
+        let result = self.solve(0);
 
         let dt = t_end / (num_points - 1) as f64;
         let times: Vec<f64> = (0..num_points).map(|i| i as f64 * dt).collect();
-
-        // Use direct bounce summation at load
-        let result = self.solve(100); // enough bounces
-
         let voltages: Vec<f64> = times
             .iter()
-            .map(|&t| {
-                // Sum all bounces that have arrived at load by time t
-                let mut v = 0.0;
-                // The voltage at the load is the sum of all forward waves that arrive
-                // First forward wave arrives at t = T_d
-                let mut v_fwd = result.v_initial;
-                let mut bounce_time = td;
-
-                if t >= bounce_time {
-                    v += v_fwd * (1.0 + gamma_l); // transmitted voltage at load
-                }
-
-                // Subsequent round trips
-                for _n in 0..50 {
-                    v_fwd *= gamma_l * gamma_s; // one full round trip
-                    bounce_time += 2.0 * td;
-                    if t >= bounce_time {
-                        v += v_fwd * (1.0 + gamma_l);
-                    } else {
-                        break;
-                    }
-                }
-                v
-            })
+            .map(|&t| result.end_voltages(&self.source, t).1 * launch_ratio)
             .collect();
 
         (times, voltages)
     }
 }
 
+/// Reconstruct impedance vs distance from a TDR record at the source.
+///
+/// Each sample of the reflected voltage at time t comes from a distance
+/// d = v_p·t/2 down the line, and its local reflection coefficient
+/// Γ = V_r/V_inc maps to Z = Z₀(1 + Γ)/(1 - Γ). This is the first-order
+/// (single-reflection) inversion: exact for one discontinuity, and a good
+/// approximation when later discontinuities are small, since re-reflections
+/// between them are not peeled off.
+///
+/// # Arguments
+/// * `reflected_voltage` - Reflected voltage at the source, V(0, t) - V_inc (V)
+/// * `times` - Sample times after launch (s), one per voltage sample
+/// * `z0` - Characteristic impedance of the reference line (Ω)
+/// * `phase_velocity` - Phase velocity on the line (m/s)
+/// * `v_incident` - Amplitude of the launched step (V)
+///
+/// # Returns
+/// (distance, impedance) pairs in sample order. Γ = 1 gives an infinite
+/// impedance (open).
+pub fn tdr_impedance_profile(
+    reflected_voltage: &[f64],
+    times: &[f64],
+    z0: f64,
+    phase_velocity: f64,
+    v_incident: f64,
+) -> Vec<(f64, f64)> {
+    assert_eq!(
+        reflected_voltage.len(),
+        times.len(),
+        "need one time per voltage sample"
+    );
+    reflected_voltage
+        .iter()
+        .zip(times)
+        .map(|(&v_r, &t)| {
+            let gamma = v_r / v_incident;
+            let distance = phase_velocity * t / 2.0;
+            let impedance = if gamma >= 1.0 {
+                f64::INFINITY
+            } else {
+                z0 * (1.0 + gamma) / (1.0 - gamma)
+            };
+            (distance, impedance)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -424,10 +641,233 @@ mod tests {
     fn sample_load_voltage_reaches_steady_state() {
         let p = make_step_line();
         let td = p.transit_time();
-        let (t, v) = p.sample_load_voltage(20.0 * td, 1000);
+        let (_, v) = p.sample_load_voltage(20.0 * td, 1000);
         // After several transit times, should reach steady state
         let v_ss = p.solve(1).steady_state_voltage;
         let last_v = v.last().unwrap();
         assert_relative_eq!(*last_v, v_ss, max_relative = 0.01);
     }
+
+    #[test]
+    fn builder_matches_hand_constructed_params() {
+        let built = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(50.0)
+            .load(100.0)
+            .line(1.0, em_core::constants::C_0)
+            .step(10.0)
+            .build()
+            .unwrap();
+        assert_eq!(built, make_step_line());
+    }
+
+    #[test]
+    fn builder_without_source_is_rejected() {
+        let err = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(50.0)
+            .load(100.0)
+            .line(1.0, em_core::constants::C_0)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, EmCoreError::InvalidParameter { ref name, .. } if name == "source"));
+    }
+
+    #[test]
+    fn builder_rejects_non_positive_line() {
+        let err = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(0.0)
+            .load(0.0)
+            .line(-1.0, em_core::constants::C_0)
+            .pulse(5.0, 1e-9)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, EmCoreError::OutOfRange { ref name, .. } if name == "length"));
+    }
+
+    #[test]
+    fn matched_step_energy_splits_evenly_and_balances() {
+        let p = TransientParams { r_load: 50.0, ..make_step_line() };
+        let td = p.transit_time();
+        let t_end = 2000.0 * td;
+        let (supplied, load, source) = p.solve(10).energy_audit(&p, t_end);
+
+        // 10 V behind 50 Ω launches 5 V: 1 W supplied, half burnt in R_S
+        assert_relative_eq!(supplied, 1.0 * t_end, max_relative = 1e-9);
+        assert_relative_eq!(source, 0.5 * t_end, max_relative = 1e-9);
+        // The load only starts absorbing after one transit time
+        assert_relative_eq!(load, 0.5 * (t_end - td), max_relative = 1e-3);
+        assert_relative_eq!(supplied - load - source, 0.5 * td, max_relative = 0.05);
+    }
+
+    #[test]
+    fn mismatched_step_energy_balances_at_large_t() {
+        let p = TransientParams { r_source: 10.0, r_load: 200.0, ..make_step_line() };
+        let t_end = 5000.0 * p.transit_time();
+        let (supplied, load, source) = p.solve(10).energy_audit(&p, t_end);
+        assert!(load > 0.0 && source > 0.0);
+        assert_relative_eq!(load + source, supplied, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn ideal_source_energy_balances() {
+        let p = TransientParams { r_source: 0.0, r_load: 200.0, ..make_step_line() };
+        let t_end = 5000.0 * p.transit_time();
+        let (supplied, load, source) = p.solve(10).energy_audit(&p, t_end);
+        assert!(supplied.is_finite() && load > 0.0);
+        assert_eq!(source, 0.0);
+        // 10 V across 200 Ω in steady state: 0.5 W
+        assert_relative_eq!(load, supplied, max_relative = 1e-2);
+        assert_relative_eq!(load, 0.5 * t_end, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn shorted_load_absorbs_no_energy() {
+        let p = TransientParams { r_source: 10.0, r_load: 0.0, ..make_step_line() };
+        let t_end = 5000.0 * p.transit_time();
+        let (supplied, load, source) = p.solve(10).energy_audit(&p, t_end);
+        assert!(supplied.is_finite() && source > 0.0);
+        assert_relative_eq!(load, 0.0, epsilon = 1e-12);
+        // 1 A through R_S in steady state: 10 W, all of it in the source
+        assert_relative_eq!(source, supplied, max_relative = 1e-2);
+        assert_relative_eq!(source, 10.0 * t_end, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn pulse_energy_is_fully_delivered() {
+        let p = TransientParams {
+            r_source: 25.0,
+            source: SourceWaveform::Pulse { voltage: 10.0, duration: 2e-9 },
+            ..make_step_line()
+        };
+        let (supplied, load, source) = p.solve(10).energy_audit(&p, 200.0 * p.transit_time());
+        assert!(supplied > 0.0);
+        assert_relative_eq!(load + source, supplied, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn ramp_source_evaluates_correctly() {
+        let ramp = SourceWaveform::Ramp { voltage: 4.0, rise_time: 2e-9 };
+        assert_relative_eq!(ramp.evaluate(-1e-9), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(ramp.evaluate(0.0), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(ramp.evaluate(0.5e-9), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(ramp.evaluate(2e-9), 4.0, epsilon = 1e-12);
+        assert_relative_eq!(ramp.evaluate(1e-6), 4.0, epsilon = 1e-12);
+        assert_eq!(ramp.amplitude(), 4.0);
+    }
+
+    fn mismatched_line(source: SourceWaveform) -> TransientParams {
+        TransientParams { r_source: 10.0, r_load: 200.0, source, ..make_step_line() }
+    }
+
+    #[test]
+    fn zero_rise_time_ramp_matches_step() {
+        let step = mismatched_line(SourceWaveform::Step { voltage: 10.0 });
+        let ramp = mismatched_line(SourceWaveform::Ramp { voltage: 10.0, rise_time: 0.0 });
+        let t_end = 12.0 * step.transit_time();
+        let (_, v_step) = step.sample_load_voltage(t_end, 777);
+        let (_, v_ramp) = ramp.sample_load_voltage(t_end, 777);
+        assert_eq!(v_step, v_ramp);
+    }
+
+    #[test]
+    fn finite_rise_time_smooths_step_edges() {
+        let step = mismatched_line(SourceWaveform::Step { voltage: 10.0 });
+        let td = step.transit_time();
+        let ramp = mismatched_line(SourceWaveform::Ramp { voltage: 10.0, rise_time: 0.5 * td });
+        let n = 2001;
+        let (t, v_step) = step.sample_load_voltage(10.0 * td, n);
+        let (_, v_ramp) = ramp.sample_load_voltage(10.0 * td, n);
+        let max_jump = |v: &[f64]| v.windows(2).map(|w| (w[1] - w[0]).abs()).fold(0.0, f64::max);
+        assert!(max_jump(&v_ramp) < 0.1 * max_jump(&v_step));
+        // Halfway up the first edge the ramp has delivered half the first arrival
+        let i = t.iter().position(|&ti| ti >= 1.25 * td).unwrap();
+        let first_arrival = v_step[i];
+        let expected = first_arrival * ramp.source.evaluate(t[i] - td) / 10.0;
+        assert_relative_eq!(v_ramp[i], expected, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn ramp_steady_state_is_unchanged() {
+        let ramp = mismatched_line(SourceWaveform::Ramp { voltage: 10.0, rise_time: 3e-9 });
+        let (_, v) = ramp.sample_load_voltage(400.0 * ramp.transit_time(), 1000);
+        let v_ss = ramp.solve(1).steady_state_voltage;
+        assert_relative_eq!(*v.last().unwrap(), v_ss, max_relative = 1e-6);
+        assert_relative_eq!(v_ss, 10.0 * 200.0 / 210.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn builder_rejects_negative_rise_time() {
+        let err = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(50.0)
+            .load(100.0)
+            .line(1.0, em_core::constants::C_0)
+            .ramp(5.0, -1e-9)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, EmCoreError::OutOfRange { ref name, .. } if name == "rise_time"));
+    }
+
+    // ================================================================
+    // TDR impedance profile
+    // ================================================================
+
+    /// Reflected voltage seen at the source end of `line`, with its incident step.
+    fn tdr_record(line: &TransientParams, t_end: f64, n: usize) -> (Vec<f64>, Vec<f64>, f64) {
+        let v_inc = line.source.amplitude() * line.z0 / (line.z0 + line.r_source);
+        let times: Vec<f64> = (0..n).map(|i| i as f64 * t_end / (n - 1) as f64).collect();
+        let reflected = times.iter().map(|&t| line.voltage_at(0.0, t, 4) - v_inc).collect();
+        (times, reflected, v_inc)
+    }
+
+    #[test]
+    fn tdr_profile_shows_step_at_discontinuity() {
+        // Matched source into 0.6 m of 50 Ω terminated by a 75 Ω "line"
+        let vp = 2e8;
+        let line = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(50.0)
+            .load(75.0)
+            .line(0.6, vp)
+            .step(2.0)
+            .build()
+            .unwrap();
+        let (times, reflected, v_inc) = tdr_record(&line, 3.0 * line.transit_time(), 301);
+        let profile = tdr_impedance_profile(&reflected, &times, 50.0, vp, v_inc);
+        assert_eq!(profile.len(), times.len());
+        for &(d, z) in &profile {
+            let expected = if d < 0.6 - 1e-9 { 50.0 } else { 75.0 };
+            assert_relative_eq!(z, expected, max_relative = 1e-9);
+        }
+        // Sample distances span out to 1.5 × the line length
+        assert_relative_eq!(profile.last().unwrap().0, 0.9, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn tdr_profile_of_matched_line_is_flat() {
+        let vp = em_core::constants::C_0;
+        let line = TransientParams::builder()
+            .z0(50.0)
+            .source_resistance(50.0)
+            .load(50.0)
+            .line(1.0, vp)
+            .step(1.0)
+            .build()
+            .unwrap();
+        let (times, reflected, v_inc) = tdr_record(&line, 4.0 * line.transit_time(), 101);
+        for (_, z) in tdr_impedance_profile(&reflected, &times, 50.0, vp, v_inc) {
+            assert_relative_eq!(z, 50.0, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn tdr_profile_maps_open_to_infinity() {
+        let profile = tdr_impedance_profile(&[0.0, 1.0, -1.0], &[0.0, 1e-9, 2e-9], 50.0, 2e8, 1.0);
+        assert_eq!(profile[0], (0.0, 50.0));
+        assert!(profile[1].1.is_infinite());
+        assert_relative_eq!(profile[1].0, 0.1, max_relative = 1e-12);
+        assert_eq!(profile[2].1, 0.0);
+    }
 }
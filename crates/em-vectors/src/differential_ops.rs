@@ -109,6 +109,26 @@ mod tests {
         assert_relative_eq!(g.z, exact.z, max_relative = 1e-5);
     }
 
+    #[test]
+    fn gradient_inverse_r() {
+        let f = |x, y, z| ScalarFieldPreset::InverseR.evaluate(x, y, z);
+        let g = gradient(&f, 0.8, -0.6, 1.2, H);
+        let exact = ScalarFieldPreset::InverseR.gradient_exact(0.8, -0.6, 1.2);
+        assert_relative_eq!(g.x, exact.x, max_relative = 1e-5);
+        assert_relative_eq!(g.y, exact.y, max_relative = 1e-5);
+        assert_relative_eq!(g.z, exact.z, max_relative = 1e-5);
+    }
+
+    #[test]
+    fn gradient_screened_coulomb() {
+        let f = |x, y, z| ScalarFieldPreset::ScreenedCoulomb.evaluate(x, y, z);
+        let g = gradient(&f, 0.4, 0.9, -0.3, H);
+        let exact = ScalarFieldPreset::ScreenedCoulomb.gradient_exact(0.4, 0.9, -0.3);
+        assert_relative_eq!(g.x, exact.x, max_relative = 1e-5);
+        assert_relative_eq!(g.y, exact.y, max_relative = 1e-5);
+        assert_relative_eq!(g.z, exact.z, max_relative = 1e-5);
+    }
+
     // ================================================================
     // Divergence tests
     // ================================================================
@@ -141,6 +161,17 @@ mod tests {
         assert_relative_eq!(div, -3.0, max_relative = 1e-6);
     }
 
+    #[test]
+    fn divergence_singular_presets_vanish_off_singularity() {
+        for preset in [VectorFieldPreset::MagneticDipole, VectorFieldPreset::LineVortex] {
+            let f = |x, y, z| preset.evaluate(x, y, z);
+            for &(x, y, z) in &[(1.0, 0.5, 0.3), (-0.7, 1.1, -0.4)] {
+                let div = divergence(&f, x, y, z, H);
+                assert_relative_eq!(div, preset.divergence_exact(x, y, z), epsilon = 1e-6);
+            }
+        }
+    }
+
     // ================================================================
     // Curl tests
     // ================================================================
@@ -168,6 +199,33 @@ mod tests {
         assert_relative_eq!(c.magnitude(), 0.0, epsilon = 1e-8);
     }
 
+    #[test]
+    fn curl_singular_presets_vanish_off_singularity() {
+        for preset in [VectorFieldPreset::MagneticDipole, VectorFieldPreset::LineVortex] {
+            let f = |x, y, z| preset.evaluate(x, y, z);
+            let c = curl(&f, 0.9, -0.4, 0.6, H);
+            let exact = preset.curl_exact(0.9, -0.4, 0.6);
+            assert_relative_eq!(c.x, exact.x, epsilon = 1e-6);
+            assert_relative_eq!(c.y, exact.y, epsilon = 1e-6);
+            assert_relative_eq!(c.z, exact.z, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn line_vortex_circulation_is_two_pi() {
+        // Irrotational everywhere off the axis, yet ∮F·dl = 2π around it
+        let n = 2000;
+        let circulation: f64 = (0..n)
+            .map(|i| {
+                let phi = 2.0 * std::f64::consts::PI * (i as f64 + 0.5) / n as f64;
+                let (x, y) = (0.5 * phi.cos(), 0.5 * phi.sin());
+                let dl = Vector3::new(-y, x, 0.0) * (2.0 * std::f64::consts::PI / n as f64);
+                VectorFieldPreset::LineVortex.evaluate(x, y, 0.0).dot(&dl)
+            })
+            .sum();
+        assert_relative_eq!(circulation, 2.0 * std::f64::consts::PI, max_relative = 1e-9);
+    }
+
     // ================================================================
     // Laplacian tests
     // ================================================================
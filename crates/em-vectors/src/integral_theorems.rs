@@ -0,0 +1,256 @@
+//! Numerical line, surface, and volume integrals for verifying Stokes' and Gauss' theorems.
+//!
+//! Each `verify_*` function returns both sides of the theorem so they can be
+//! compared directly. Integrals use the midpoint rule; derivatives use the
+//! central differences from [`crate::differential_ops`].
+
+use crate::differential_ops::{curl, divergence};
+use em_core::coordinates::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// An axis-aligned rectangular patch in the plane z = const, with normal +ẑ.
+///
+/// The boundary is traversed counter-clockwise when viewed from +z.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RectPatch {
+    /// x extent (x_min, x_max)
+    pub x_range: (f64, f64),
+    /// y extent (y_min, y_max)
+    pub y_range: (f64, f64),
+    /// Height of the plane; the patch normal is +ẑ
+    pub z: f64,
+}
+
+impl RectPatch {
+    pub fn new(x_range: (f64, f64), y_range: (f64, f64), z: f64) -> Self {
+        Self {
+            x_range,
+            y_range,
+            z,
+        }
+    }
+}
+
+/// An axis-aligned box region with outward-pointing face normals.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoxRegion {
+    /// x extent (x_min, x_max); the x_max face has normal +x̂
+    pub x_range: (f64, f64),
+    /// y extent (y_min, y_max); the y_max face has normal +ŷ
+    pub y_range: (f64, f64),
+    /// z extent (z_min, z_max); the z_max face has normal +ẑ
+    pub z_range: (f64, f64),
+}
+
+impl BoxRegion {
+    pub fn new(x_range: (f64, f64), y_range: (f64, f64), z_range: (f64, f64)) -> Self {
+        Self {
+            x_range,
+            y_range,
+            z_range,
+        }
+    }
+
+    /// Cube of side 2·half_side centered on the origin.
+    pub fn cube(half_side: f64) -> Self {
+        let r = (-half_side, half_side);
+        Self::new(r, r, r)
+    }
+}
+
+/// Midpoints of n equal subintervals of [a, b] and the subinterval width.
+fn midpoints(range: (f64, f64), n: usize) -> (Vec<f64>, f64) {
+    let step = (range.1 - range.0) / n as f64;
+    let pts = (0..n).map(|i| range.0 + (i as f64 + 0.5) * step).collect();
+    (pts, step)
+}
+
+/// Circulation ∮ F·dl around the boundary of a patch (counter-clockwise from +z).
+///
+/// # Arguments
+/// * `field` - Vector field function
+/// * `patch` - Rectangular patch
+/// * `n` - Number of subintervals per edge
+pub fn circulation<F: Fn(f64, f64, f64) -> Vector3>(field: &F, patch: &RectPatch, n: usize) -> f64 {
+    let (xs, dx) = midpoints(patch.x_range, n);
+    let (ys, dy) = midpoints(patch.y_range, n);
+    let (x0, x1) = patch.x_range;
+    let (y0, y1) = patch.y_range;
+    let z = patch.z;
+
+    let bottom: f64 = xs.iter().map(|&x| field(x, y0, z).x * dx).sum();
+    let right: f64 = ys.iter().map(|&y| field(x1, y, z).y * dy).sum();
+    let top: f64 = xs.iter().map(|&x| -field(x, y1, z).x * dx).sum();
+    let left: f64 = ys.iter().map(|&y| -field(x0, y, z).y * dy).sum();
+    bottom + right + top + left
+}
+
+/// Flux of the numerical curl through a patch: ∬ (∇×F)·ẑ dA.
+///
+/// # Arguments
+/// * `field` - Vector field function
+/// * `patch` - Rectangular patch
+/// * `n` - Number of subintervals per side
+/// * `h` - Finite-difference step for the curl
+pub fn curl_flux<F: Fn(f64, f64, f64) -> Vector3>(
+    field: &F,
+    patch: &RectPatch,
+    n: usize,
+    h: f64,
+) -> f64 {
+    let (xs, dx) = midpoints(patch.x_range, n);
+    let (ys, dy) = midpoints(patch.y_range, n);
+    let mut total = 0.0;
+    for &y in &ys {
+        for &x in &xs {
+            total += curl(field, x, y, patch.z, h).z * dx * dy;
+        }
+    }
+    total
+}
+
+/// Outward flux ∯ F·dA through the six faces of a box.
+///
+/// # Arguments
+/// * `field` - Vector field function
+/// * `region` - Box region
+/// * `n` - Number of subintervals per face edge
+pub fn surface_flux<F: Fn(f64, f64, f64) -> Vector3>(
+    field: &F,
+    region: &BoxRegion,
+    n: usize,
+) -> f64 {
+    let (xs, dx) = midpoints(region.x_range, n);
+    let (ys, dy) = midpoints(region.y_range, n);
+    let (zs, dz) = midpoints(region.z_range, n);
+    let (x0, x1) = region.x_range;
+    let (y0, y1) = region.y_range;
+    let (z0, z1) = region.z_range;
+    let mut total = 0.0;
+
+    for &z in &zs {
+        for &y in &ys {
+            total += (field(x1, y, z).x - field(x0, y, z).x) * dy * dz;
+        }
+        for &x in &xs {
+            total += (field(x, y1, z).y - field(x, y0, z).y) * dx * dz;
+        }
+    }
+    for &y in &ys {
+        for &x in &xs {
+            total += (field(x, y, z1).z - field(x, y, z0).z) * dx * dy;
+        }
+    }
+    total
+}
+
+/// Volume integral of the numerical divergence over a box: ∭ ∇·F dV.
+///
+/// # Arguments
+/// * `field` - Vector field function
+/// * `region` - Box region
+/// * `n` - Number of subintervals per axis
+/// * `h` - Finite-difference step for the divergence
+pub fn divergence_volume_integral<F: Fn(f64, f64, f64) -> Vector3>(
+    field: &F,
+    region: &BoxRegion,
+    n: usize,
+    h: f64,
+) -> f64 {
+    let (xs, dx) = midpoints(region.x_range, n);
+    let (ys, dy) = midpoints(region.y_range, n);
+    let (zs, dz) = midpoints(region.z_range, n);
+    let mut total = 0.0;
+    for &z in &zs {
+        for &y in &ys {
+            for &x in &xs {
+                total += divergence(field, x, y, z, h);
+            }
+        }
+    }
+    total * dx * dy * dz
+}
+
+/// Stokes' theorem: ∮ F·dl = ∬ (∇×F)·dA.
+///
+/// # Returns
+/// (circulation, flux_of_curl)
+pub fn verify_stokes<F: Fn(f64, f64, f64) -> Vector3>(
+    field: &F,
+    patch: &RectPatch,
+    n: usize,
+    h: f64,
+) -> (f64, f64) {
+    (circulation(field, patch, n), curl_flux(field, patch, n, h))
+}
+
+/// Gauss' (divergence) theorem: ∯ F·dA = ∭ ∇·F dV.
+///
+/// # Returns
+/// (surface_flux, volume_integral_of_div)
+pub fn verify_gauss<F: Fn(f64, f64, f64) -> Vector3>(
+    field: &F,
+    region: &BoxRegion,
+    n: usize,
+    h: f64,
+) -> (f64, f64) {
+    (
+        surface_flux(field, region, n),
+        divergence_volume_integral(field, region, n, h),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_field::VectorFieldPreset;
+    use approx::assert_relative_eq;
+
+    const H: f64 = 1e-5;
+
+    #[test]
+    fn stokes_rotation_2d_sides_agree() {
+        // ∇×F = 2ẑ → both sides equal 2·area
+        let f = |x, y, z| VectorFieldPreset::Rotation2D.evaluate(x, y, z);
+        let patch = RectPatch::new((-1.0, 1.0), (-0.5, 1.5), 0.0);
+        let (circ, flux) = verify_stokes(&f, &patch, 40, H);
+        assert_relative_eq!(circ, 8.0, max_relative = 1e-10);
+        assert_relative_eq!(circ, flux, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn stokes_radial_outward_both_zero() {
+        let f = |x, y, z| VectorFieldPreset::RadialOutward.evaluate(x, y, z);
+        let patch = RectPatch::new((0.0, 2.0), (1.0, 3.0), 0.5);
+        let (circ, flux) = verify_stokes(&f, &patch, 40, H);
+        assert_relative_eq!(circ, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(flux, 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn stokes_non_uniform_sides_agree() {
+        let f = |x, y, z| VectorFieldPreset::NonUniform.evaluate(x, y, z);
+        let patch = RectPatch::new((0.0, 1.0), (0.0, 2.0), 0.0);
+        let (circ, flux) = verify_stokes(&f, &patch, 200, H);
+        assert_relative_eq!(circ, flux, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn gauss_radial_outward_sides_agree() {
+        // ∇·F = 3 → both sides equal 3·volume
+        let f = |x, y, z| VectorFieldPreset::RadialOutward.evaluate(x, y, z);
+        let region = BoxRegion::cube(1.0);
+        let (flux, vol) = verify_gauss(&f, &region, 10, H);
+        assert_relative_eq!(flux, 24.0, max_relative = 1e-10);
+        assert_relative_eq!(flux, vol, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn gauss_rotation_2d_both_zero() {
+        let f = |x, y, z| VectorFieldPreset::Rotation2D.evaluate(x, y, z);
+        let region = BoxRegion::new((0.0, 1.0), (-1.0, 2.0), (0.0, 0.5));
+        let (flux, vol) = verify_gauss(&f, &region, 10, H);
+        assert_relative_eq!(flux, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(vol, 0.0, epsilon = 1e-6);
+    }
+}
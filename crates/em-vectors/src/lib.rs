@@ -5,8 +5,10 @@
 //! - Module 3.2: Gradient of scalar fields
 //! - Module 3.3: Divergence of vector fields
 //! - Module 3.4: Curl of vector fields
+//! - Stokes' and Gauss' theorem verification
 
 pub mod vector_ops;
 pub mod scalar_field;
 pub mod vector_field;
 pub mod differential_ops;
+pub mod integral_theorems;
@@ -23,6 +23,8 @@ pub enum ScalarFieldPreset {
     Product,
     /// f = e^(-(x²+y²+z²)) (Gaussian)
     Gaussian,
+    /// f = e^(-r)/r (screened Coulomb / Yukawa potential, unit screening length)
+    ScreenedCoulomb,
 }
 
 impl ScalarFieldPreset {
@@ -38,6 +40,10 @@ impl ScalarFieldPreset {
             }
             Self::Product => x * y * z,
             Self::Gaussian => (-(x * x + y * y + z * z)).exp(),
+            Self::ScreenedCoulomb => {
+                let r = (x * x + y * y + z * z).sqrt();
+                if r < 1e-10 { 1e10 } else { (-r).exp() / r }
+            }
         }
     }
 
@@ -62,6 +68,16 @@ impl ScalarFieldPreset {
                 let g = (-(x * x + y * y + z * z)).exp();
                 Vector3::new(-2.0 * x * g, -2.0 * y * g, -2.0 * z * g)
             }
+            Self::ScreenedCoulomb => {
+                // ∇f = -(1 + r)·e^(-r)/r³ · r_vec
+                let r = (x * x + y * y + z * z).sqrt();
+                if r < 1e-10 {
+                    Vector3::zero()
+                } else {
+                    let factor = -(1.0 + r) * (-r).exp() / (r * r * r);
+                    Vector3::new(factor * x, factor * y, factor * z)
+                }
+            }
         }
     }
 }
@@ -149,6 +165,14 @@ mod tests {
         assert!(f.evaluate(1.0, 0.0, 0.0) > f.evaluate(2.0, 0.0, 0.0));
     }
 
+    #[test]
+    fn screened_coulomb_falls_faster_than_inverse_r() {
+        let f = ScalarFieldPreset::ScreenedCoulomb;
+        let g = ScalarFieldPreset::InverseR;
+        assert_relative_eq!(f.evaluate(1.0, 0.0, 0.0), (-1.0_f64).exp(), epsilon = 1e-12);
+        assert!(f.evaluate(3.0, 0.0, 0.0) / g.evaluate(3.0, 0.0, 0.0) < f.evaluate(1.0, 0.0, 0.0));
+    }
+
     #[test]
     fn gradient_paraboloid_at_1_2_3() {
         let g = ScalarFieldPreset::Paraboloid.gradient_exact(1.0, 2.0, 3.0);
@@ -18,6 +18,10 @@ pub enum VectorFieldPreset {
     UniformX,
     /// F = (-x, -y, -z) — radial inward (sink)
     RadialInward,
+    /// F = (3xz, 3yz, 3z² - r²)/r⁵ — dipole field with moment along z (singular at origin)
+    MagneticDipole,
+    /// F = (-y, x, 0)/(x² + y²) — line vortex (singular on the z-axis)
+    LineVortex,
 }
 
 impl VectorFieldPreset {
@@ -30,6 +34,21 @@ impl VectorFieldPreset {
             Self::NonUniform => Vector3::new(x * x, x * y, 0.0),
             Self::UniformX => Vector3::new(1.0, 0.0, 0.0),
             Self::RadialInward => Vector3::new(-x, -y, -z),
+            Self::MagneticDipole => {
+                let r2 = x * x + y * y + z * z;
+                if r2 < 1e-20 {
+                    return Vector3::zero();
+                }
+                let r5 = r2 * r2 * r2.sqrt();
+                Vector3::new(3.0 * x * z / r5, 3.0 * y * z / r5, (3.0 * z * z - r2) / r5)
+            }
+            Self::LineVortex => {
+                let rho2 = x * x + y * y;
+                if rho2 < 1e-20 {
+                    return Vector3::zero();
+                }
+                Vector3::new(-y / rho2, x / rho2, 0.0)
+            }
         }
     }
 
@@ -43,6 +62,8 @@ impl VectorFieldPreset {
             Self::NonUniform => 3.0 * x,            // ∂(x²)/∂x + ∂(xy)/∂y + 0 = 2x + x = 3x
             Self::UniformX => 0.0,                // 0 + 0 + 0
             Self::RadialInward => -3.0,           // -1 + -1 + -1
+            Self::MagneticDipole => 0.0,          // solenoidal away from the origin
+            Self::LineVortex => 0.0,              // solenoidal away from the axis
         }
     }
 
@@ -56,6 +77,8 @@ impl VectorFieldPreset {
             Self::NonUniform => Vector3::new(0.0, 0.0, _y), // ∂(xy)/∂x - ∂(x²)/∂y = y - 0 = y
             Self::UniformX => Vector3::zero(),
             Self::RadialInward => Vector3::zero(),
+            Self::MagneticDipole => Vector3::zero(), // gradient of a potential off the origin
+            Self::LineVortex => Vector3::zero(),     // circulation 2π concentrated on the axis
         }
     }
 }
@@ -51,6 +51,7 @@ pub fn antenna_array(num_elements: usize, spacing: f64, beta_deg: f64, num_point
         "total_pattern": total,
         "beamwidth_deg": arr.first_null_beamwidth().to_degrees(),
         "directivity_approx": arr.directivity_approx(),
+        "directivity": arr.directivity(),
     });
     serde_wasm_bindgen::to_value(&result).unwrap()
 }
@@ -40,6 +40,24 @@ pub fn smith_chart_trace(zl_re: f64, zl_im: f64, electrical_length: f64, num_poi
     serde_wasm_bindgen::to_value(&result).unwrap()
 }
 
+/// Batch Smith chart mapping; `z_ri` holds interleaved `[re0, im0, re1, im1, ...]` ohms.
+///
+/// An odd-length `z_ri` yields `{"error": ...}`.
+#[wasm_bindgen]
+pub fn smith_chart_points(z_ri: &[f64], z0: f64) -> JsValue {
+    if !z_ri.len().is_multiple_of(2) {
+        let result = serde_json::json!({"error": "z_ri must hold interleaved re/im pairs"});
+        return serde_wasm_bindgen::to_value(&result).unwrap();
+    }
+    let zs: Vec<Complex64> = z_ri.chunks_exact(2).map(|c| Complex64::new(c[0], c[1])).collect();
+    let pts = smith_chart::smith_points_from_impedances(&zs, z0);
+    let gamma_re: Vec<f64> = pts.iter().map(|p| p.gamma.re).collect();
+    let gamma_im: Vec<f64> = pts.iter().map(|p| p.gamma.im).collect();
+    let vswr: Vec<f64> = pts.iter().map(|p| p.vswr()).collect();
+    let result = serde_json::json!({ "gamma_re": gamma_re, "gamma_im": gamma_im, "vswr": vswr });
+    serde_wasm_bindgen::to_value(&result).unwrap()
+}
+
 #[wasm_bindgen]
 pub fn coaxial_line_params(inner_radius: f64, outer_radius: f64, epsilon_r: f64, frequency: f64) -> JsValue {
     let coax = line_types::CoaxialLine::lossless(inner_radius, outer_radius, epsilon_r);
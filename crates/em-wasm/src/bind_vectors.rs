@@ -30,6 +30,7 @@ pub fn scalar_field_2d(preset: &str, x_min: f64, x_max: f64, y_min: f64, y_max:
         "sincos" => scalar_field::ScalarFieldPreset::SinCos,
         "inverse_r" => scalar_field::ScalarFieldPreset::InverseR,
         "gaussian" => scalar_field::ScalarFieldPreset::Gaussian,
+        "screened_coulomb" => scalar_field::ScalarFieldPreset::ScreenedCoulomb,
         _ => scalar_field::ScalarFieldPreset::Paraboloid,
     };
     let grid = scalar_field::sample_2d(field, (x_min, x_max), (y_min, y_max), 0.0, nx, ny);
@@ -43,6 +44,8 @@ pub fn vector_field_2d(preset: &str, x_min: f64, x_max: f64, y_min: f64, y_max:
         "rotation" => vector_field::VectorFieldPreset::Rotation2D,
         "uniform_x" => vector_field::VectorFieldPreset::UniformX,
         "radial_inward" => vector_field::VectorFieldPreset::RadialInward,
+        "magnetic_dipole" => vector_field::VectorFieldPreset::MagneticDipole,
+        "line_vortex" => vector_field::VectorFieldPreset::LineVortex,
         _ => vector_field::VectorFieldPreset::RadialOutward,
     };
     let grid = vector_field::sample_2d(field, (x_min, x_max), (y_min, y_max), 0.0, nx, ny);
@@ -42,6 +42,27 @@ impl SinusoidalParams {
         }
     }
 
+    /// Create a damped sinusoid from its quality factor Q.
+    ///
+    /// Q = ω/(2α), so α = πf/Q and the envelope falls to 1/e after Q/(πf)
+    /// seconds, i.e. Q/π cycles.
+    pub fn from_q(amplitude: f64, frequency: f64, phase_rad: f64, q: f64) -> Self {
+        Self::damped(amplitude, frequency, phase_rad, PI * frequency / q)
+    }
+
+    /// Quality factor implied by the damping, Q = πf/α (∞ if undamped).
+    pub fn q_factor(&self) -> f64 {
+        if self.damping == 0.0 {
+            return f64::INFINITY;
+        }
+        PI * self.frequency / self.damping
+    }
+
+    /// Envelope A·e^(-α·t) at time t (seconds).
+    pub fn envelope_at(&self, t: f64) -> f64 {
+        self.amplitude * (-self.damping * t).exp()
+    }
+
     /// Evaluate the waveform at time t (seconds).
     ///
     /// y(t) = A · e^(-α·t) · cos(2πf·t + φ)
@@ -160,6 +181,32 @@ mod tests {
         assert_relative_eq!(y1, 10.0 * (-1.0_f64).exp(), epsilon = 1e-10);
     }
 
+    #[test]
+    fn from_q_envelope_reaches_1_over_e_after_q_over_pi_f() {
+        let s = SinusoidalParams::from_q(2.0, 1e3, 0.4, 50.0);
+        let tau = 50.0 / (PI * 1e3);
+        assert_relative_eq!(s.envelope_at(tau), 2.0 / std::f64::consts::E, max_relative = 1e-12);
+        assert!(s.evaluate(tau).abs() <= s.envelope_at(tau));
+    }
+
+    #[test]
+    fn higher_q_decays_more_slowly() {
+        let low = SinusoidalParams::from_q(1.0, 1e3, 0.0, 5.0);
+        let high = SinusoidalParams::from_q(1.0, 1e3, 0.0, 500.0);
+        let t = 0.01;
+        assert!(high.envelope_at(t) > low.envelope_at(t));
+        assert!(high.envelope_at(t) > 0.9);
+    }
+
+    #[test]
+    fn from_q_round_trips_through_q_factor() {
+        for q in [0.5, 10.0, 1234.5] {
+            let s = SinusoidalParams::from_q(1.0, 2.4e9, 0.0, q);
+            assert_relative_eq!(s.q_factor(), q, max_relative = 1e-12);
+        }
+        assert!(SinusoidalParams::new(1.0, 1e3, 0.0).q_factor().is_infinite());
+    }
+
     #[test]
     fn damping_zero_is_undamped() {
         let undamped = SinusoidalParams::new(5.0, 100.0, 0.3);
@@ -7,6 +7,7 @@
 //! y(x, t) = A · e^(+αx) · cos(ωt + βx + φ)  [-x direction]
 
 use em_core::constants;
+use em_core::{Complex64, EmCoreResult, validate_frequency};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -32,22 +33,84 @@ pub struct TravelingWaveParams {
     pub beta: f64,
     /// Attenuation constant α (Np/m). Zero for lossless.
     pub alpha: f64,
+    /// Relative permittivity of the medium
+    #[serde(default = "unit_relative")]
+    pub epsilon_r: f64,
+    /// Relative permeability of the medium
+    #[serde(default = "unit_relative")]
+    pub mu_r: f64,
+}
+
+fn unit_relative() -> f64 {
+    1.0
+}
+
+/// Wavelength, phase velocity, impedance and skin depth of one wave.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WaveSummary {
+    /// Wavelength λ = 2π/β (m)
+    pub wavelength: f64,
+    /// Phase velocity v_p = ω/β (m/s)
+    pub phase_velocity: f64,
+    /// Intrinsic impedance η (Ω)
+    pub intrinsic_impedance: Complex64,
+    /// Skin depth δ = 1/α (m); infinite for lossless
+    pub skin_depth: f64,
 }
 
 impl TravelingWaveParams {
     /// Create a lossless traveling wave in free space.
     pub fn in_free_space(amplitude: f64, frequency: f64, phase_rad: f64, direction: Direction) -> Self {
-        let beta = 2.0 * PI * frequency / constants::C_0;
+        Self::in_medium(amplitude, frequency, phase_rad, direction, 1.0, 1.0)
+    }
+
+    /// Create a lossless traveling wave in a medium with relative ε_r and μ_r.
+    ///
+    /// β = ω√(μ_r·ε_r)/c; the medium is kept for [`Self::intrinsic_impedance`].
+    pub fn in_medium(
+        amplitude: f64,
+        frequency: f64,
+        phase_rad: f64,
+        direction: Direction,
+        epsilon_r: f64,
+        mu_r: f64,
+    ) -> Self {
+        let v_p = constants::C_0 / (epsilon_r * mu_r).sqrt();
         Self {
             amplitude,
             frequency,
             phase_rad,
             direction,
-            beta,
+            beta: 2.0 * PI * frequency / v_p,
             alpha: 0.0,
+            epsilon_r,
+            mu_r,
         }
     }
 
+    /// Like [`Self::in_free_space`], but rejects a frequency ≤ 0.
+    pub fn try_in_free_space(
+        amplitude: f64,
+        frequency: f64,
+        phase_rad: f64,
+        direction: Direction,
+    ) -> EmCoreResult<Self> {
+        validate_frequency(frequency)?;
+        Ok(Self::in_free_space(amplitude, frequency, phase_rad, direction))
+    }
+
+    /// Like [`Self::in_dielectric`], but rejects a frequency ≤ 0.
+    pub fn try_in_dielectric(
+        amplitude: f64,
+        frequency: f64,
+        phase_rad: f64,
+        direction: Direction,
+        epsilon_r: f64,
+    ) -> EmCoreResult<Self> {
+        validate_frequency(frequency)?;
+        Ok(Self::in_dielectric(amplitude, frequency, phase_rad, direction, epsilon_r))
+    }
+
     /// Create a traveling wave in a lossless dielectric with relative permittivity ε_r.
     pub fn in_dielectric(
         amplitude: f64,
@@ -56,19 +119,12 @@ impl TravelingWaveParams {
         direction: Direction,
         epsilon_r: f64,
     ) -> Self {
-        let v_p = constants::C_0 / epsilon_r.sqrt();
-        let beta = 2.0 * PI * frequency / v_p;
-        Self {
-            amplitude,
-            frequency,
-            phase_rad,
-            direction,
-            beta,
-            alpha: 0.0,
-        }
+        Self::in_medium(amplitude, frequency, phase_rad, direction, epsilon_r, 1.0)
     }
 
     /// Create a traveling wave with explicit propagation parameters.
+    ///
+    /// The medium is taken as ε_r = μ_r = 1; set the fields to change it.
     pub fn with_propagation(
         amplitude: f64,
         frequency: f64,
@@ -84,6 +140,8 @@ impl TravelingWaveParams {
             direction,
             alpha,
             beta,
+            epsilon_r: 1.0,
+            mu_r: 1.0,
         }
     }
 
@@ -120,6 +178,33 @@ impl TravelingWaveParams {
         (xs, ys)
     }
 
+    /// Envelope magnitude |A|·e^(∓αx) at position x (m).
+    pub fn envelope_at(&self, x: f64) -> f64 {
+        let decay = match self.direction {
+            Direction::PositiveX => -self.alpha * x,
+            Direction::NegativeX => self.alpha * x,
+        };
+        self.amplitude.abs() * decay.exp()
+    }
+
+    /// Sample the upper and lower envelopes ±|A|·e^(∓αx) for guide lines.
+    ///
+    /// # Returns
+    /// (x_values, upper_envelope, lower_envelope)
+    pub fn sample_envelope(
+        &self,
+        x_start: f64,
+        x_end: f64,
+        num_points: usize,
+    ) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        assert!(num_points >= 2);
+        let dx = (x_end - x_start) / (num_points - 1) as f64;
+        let xs: Vec<f64> = (0..num_points).map(|i| x_start + i as f64 * dx).collect();
+        let upper: Vec<f64> = xs.iter().map(|&x| self.envelope_at(x)).collect();
+        let lower: Vec<f64> = upper.iter().map(|&u| -u).collect();
+        (xs, upper, lower)
+    }
+
     /// Sample the wave in time at a fixed position.
     ///
     /// # Returns
@@ -156,6 +241,26 @@ impl TravelingWaveParams {
             1.0 / self.alpha
         }
     }
+
+    /// Intrinsic impedance η = jωμ/γ with γ = α + jβ (Ω).
+    ///
+    /// Reduces to η₀·√(μ_r/ε_r) for a lossless wave built by [`Self::in_medium`];
+    /// loss gives η a positive phase angle.
+    pub fn intrinsic_impedance(&self) -> Complex64 {
+        let omega = 2.0 * PI * self.frequency;
+        let j_omega_mu = Complex64::new(0.0, omega * constants::MU_0 * self.mu_r);
+        j_omega_mu / Complex64::new(self.alpha, self.beta)
+    }
+
+    /// λ, v_p, η and skin depth together.
+    pub fn wave_summary(&self) -> WaveSummary {
+        WaveSummary {
+            wavelength: self.wavelength(),
+            phase_velocity: self.phase_velocity(),
+            intrinsic_impedance: self.intrinsic_impedance(),
+            skin_depth: self.skin_depth(),
+        }
+    }
 }
 
 /// Superpose multiple traveling waves at given (x, t) points.
@@ -183,12 +288,73 @@ pub fn superpose_spatial(
     (xs, ys)
 }
 
+/// Split a wave incident on an impedance step at x = 0 into reflected and
+/// transmitted waves.
+///
+/// Γ = (z₂ - z₁)/(z₂ + z₁), τ = 1 + Γ = 2z₂/(z₂ + z₁). Amplitudes are signed,
+/// so a negative reflected amplitude is the 180° phase flip of a low-impedance
+/// step. Assumes non-magnetic media (Z ∝ 1/√ε_r), giving β₂ = β₁·z₁/z₂ on the
+/// far side; attenuation is carried over unchanged.
+///
+/// # Arguments
+/// * `incident` - Wave arriving at the step
+/// * `z1` - Impedance on the incident side (Ω)
+/// * `z2` - Impedance on the far side (Ω)
+///
+/// # Returns
+/// (reflected, transmitted)
+pub fn reflect_at_step(
+    incident: &TravelingWaveParams,
+    z1: f64,
+    z2: f64,
+) -> (TravelingWaveParams, TravelingWaveParams) {
+    let gamma = (z2 - z1) / (z2 + z1);
+    let tau = 1.0 + gamma;
+    let reversed = match incident.direction {
+        Direction::PositiveX => Direction::NegativeX,
+        Direction::NegativeX => Direction::PositiveX,
+    };
+
+    let reflected = TravelingWaveParams {
+        amplitude: gamma * incident.amplitude,
+        direction: reversed,
+        ..*incident
+    };
+    let transmitted = TravelingWaveParams {
+        amplitude: tau * incident.amplitude,
+        beta: incident.beta * z1 / z2,
+        epsilon_r: incident.epsilon_r * (z1 / z2).powi(2),
+        ..*incident
+    };
+    (reflected, transmitted)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
     use std::f64::consts::PI;
 
+    #[test]
+    fn try_in_free_space_rejects_zero_frequency() {
+        use em_core::EmCoreError::InvalidFrequency;
+        let err = TravelingWaveParams::try_in_free_space(1.0, 0.0, 0.0, Direction::PositiveX)
+            .unwrap_err();
+        assert!(matches!(err, InvalidFrequency { frequency } if frequency == 0.0));
+        let err = TravelingWaveParams::try_in_dielectric(1.0, -2e6, 0.0, Direction::NegativeX, 4.0)
+            .unwrap_err();
+        assert!(matches!(err, InvalidFrequency { frequency } if frequency == -2e6));
+    }
+
+    #[test]
+    fn try_in_free_space_accepts_positive_frequency() {
+        let dir = Direction::PositiveX;
+        let w = TravelingWaveParams::try_in_free_space(5.0, 1e9, 0.0, dir).unwrap();
+        assert_eq!(w, TravelingWaveParams::in_free_space(5.0, 1e9, 0.0, dir));
+        assert!(TravelingWaveParams::try_in_dielectric(1.0, 1e6, 0.0, Direction::PositiveX, 4.0)
+            .is_ok());
+    }
+
     #[test]
     fn free_space_wave_at_origin_and_t0() {
         let w = TravelingWaveParams::in_free_space(5.0, 1e9, 0.0, Direction::PositiveX);
@@ -277,6 +443,42 @@ mod tests {
         assert_relative_eq!(w.skin_depth(), 0.5, epsilon = 1e-12);
     }
 
+    #[test]
+    fn free_space_impedance_is_eta_0() {
+        let w = TravelingWaveParams::in_free_space(1.0, 1e9, 0.0, Direction::PositiveX);
+        let eta = w.intrinsic_impedance();
+        assert_relative_eq!(eta.re, constants::ETA_0, max_relative = 1e-8);
+        assert_relative_eq!(eta.im, 0.0, epsilon = 1e-9);
+        let summary = w.wave_summary();
+        assert_relative_eq!(summary.wavelength, constants::C_0 / 1e9, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn dielectric_impedance_and_velocity_drop_by_sqrt_epsilon_r() {
+        let w = TravelingWaveParams::in_dielectric(1.0, 1e9, 0.0, Direction::PositiveX, 4.0);
+        let eta_0 = constants::ETA_0;
+        assert_relative_eq!(w.intrinsic_impedance().re, eta_0 / 2.0, max_relative = 1e-8);
+        assert_relative_eq!(w.phase_velocity(), constants::C_0 / 2.0, max_relative = 1e-12);
+        // A magnetic medium with μ_r = ε_r keeps η₀
+        let m = TravelingWaveParams::in_medium(1.0, 1e9, 0.0, Direction::PositiveX, 3.0, 3.0);
+        assert_relative_eq!(m.intrinsic_impedance().re, eta_0, max_relative = 1e-8);
+        assert_relative_eq!(m.phase_velocity(), constants::C_0 / 3.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn wave_summary_fields_are_consistent() {
+        let w = TravelingWaveParams::with_propagation(
+            1.0, 1e9, 0.0, Direction::PositiveX, 2.0, 40.0,
+        );
+        let s = w.wave_summary();
+        assert_relative_eq!(s.phase_velocity, s.wavelength * 1e9, max_relative = 1e-12);
+        assert_relative_eq!(s.skin_depth, 0.5, max_relative = 1e-12);
+        assert_eq!(s.intrinsic_impedance, w.intrinsic_impedance());
+        // Loss tilts η inductive by atan(α/β)
+        let tilt = (2.0_f64 / 40.0).atan();
+        assert_relative_eq!(s.intrinsic_impedance.arg(), tilt, max_relative = 1e-12);
+    }
+
     #[test]
     fn sample_space_length_correct() {
         let w = TravelingWaveParams::in_free_space(1.0, 1e9, 0.0, Direction::PositiveX);
@@ -322,6 +524,81 @@ mod tests {
 
         // Check node near x = λ/4
         let idx_quarter = (0.25 * 1000.0) as usize;
+        assert_relative_eq!(xs[idx_quarter], lambda / 4.0, max_relative = 1e-12);
         assert_relative_eq!(ys[idx_quarter], 0.0, epsilon = 1e-4);
     }
+
+    #[test]
+    fn lossless_envelope_is_flat() {
+        let w = TravelingWaveParams::in_free_space(2.5, 1e9, 0.0, Direction::PositiveX);
+        let (_, upper, lower) = w.sample_envelope(0.0, 2.0, 50);
+        for (u, l) in upper.iter().zip(&lower) {
+            assert_relative_eq!(*u, 2.5, epsilon = 1e-15);
+            assert_relative_eq!(*l, -2.5, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn lossy_forward_envelope_decays_exponentially() {
+        let alpha = 0.3;
+        let w = TravelingWaveParams::with_propagation(1.5, 1e9, 0.0, Direction::PositiveX, alpha, 20.0);
+        let (xs, upper, lower) = w.sample_envelope(0.0, 5.0, 21);
+        for ((x, u), l) in xs.iter().zip(&upper).zip(&lower) {
+            assert_relative_eq!(*u, 1.5 * (-alpha * x).exp(), max_relative = 1e-12);
+            assert_relative_eq!(*l, -*u, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn sampled_wave_stays_within_envelope() {
+        for direction in [Direction::PositiveX, Direction::NegativeX] {
+            let w = TravelingWaveParams::with_propagation(1.0, 1e9, 0.4, direction, 0.2, 15.0);
+            let (xs, ys) = w.sample_space(-3.0, 3.0, 500, 0.37e-9);
+            let (_, upper, lower) = w.sample_envelope(-3.0, 3.0, 500);
+            for i in 0..xs.len() {
+                assert!(ys[i] <= upper[i] + 1e-12 && ys[i] >= lower[i] - 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn matched_step_has_no_reflection() {
+        let w = TravelingWaveParams::in_free_space(2.0, 1e9, 0.3, Direction::PositiveX);
+        let (r, t) = reflect_at_step(&w, 50.0, 50.0);
+        assert_relative_eq!(r.amplitude, 0.0, epsilon = 1e-15);
+        assert_relative_eq!(t.amplitude, 2.0, epsilon = 1e-15);
+        assert_relative_eq!(t.beta, w.beta, epsilon = 1e-15);
+        assert_eq!(t.direction, Direction::PositiveX);
+    }
+
+    #[test]
+    fn near_short_step_reflects_with_sign_flip() {
+        let w = TravelingWaveParams::in_free_space(1.0, 1e9, 0.0, Direction::PositiveX);
+        let (r, t) = reflect_at_step(&w, 50.0, 1e-6);
+        assert_relative_eq!(r.amplitude, -1.0, epsilon = 1e-6);
+        assert_eq!(r.direction, Direction::NegativeX);
+        assert_relative_eq!(r.beta, w.beta, epsilon = 1e-15);
+        assert!(t.amplitude.abs() < 1e-6);
+    }
+
+    #[test]
+    fn step_amplitudes_satisfy_one_plus_gamma_equals_tau() {
+        let w = TravelingWaveParams::in_free_space(1.5, 1e9, 0.0, Direction::PositiveX);
+        for &(z1, z2) in &[(50.0, 75.0), (377.0, 188.5), (75.0, 25.0)] {
+            let (r, t) = reflect_at_step(&w, z1, z2);
+            assert_relative_eq!(w.amplitude + r.amplitude, t.amplitude, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn step_into_dielectric_shortens_wavelength() {
+        // η₀ → η₀/2 corresponds to ε_r = 4 on the far side
+        let w = TravelingWaveParams::in_free_space(1.0, 1e9, 0.0, Direction::PositiveX);
+        let eta_0 = constants::ETA_0;
+        let (_, t) = reflect_at_step(&w, eta_0, eta_0 / 2.0);
+        let expected = TravelingWaveParams::in_dielectric(1.0, 1e9, 0.0, Direction::PositiveX, 4.0);
+        assert_relative_eq!(t.beta, expected.beta, max_relative = 1e-10);
+        assert_relative_eq!(t.epsilon_r, 4.0, max_relative = 1e-12);
+        assert_relative_eq!(t.intrinsic_impedance().re, eta_0 / 2.0, max_relative = 1e-8);
+    }
 }